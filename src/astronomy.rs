@@ -4,17 +4,20 @@
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-use std::f64::consts::{     // Using std lib constants
+use core::f64::consts::{     // Using std lib constants
     PI                      // Pi
 };
 
-use std::fmt::{             // Formatter display
+use core::fmt::{             // Formatter display
     Display,                // The display itself
     Result as DRes          // The associated result
 };
 
 use super::constant;
 
+#[cfg(not(feature = "std"))]
+use crate::float::Float;   // Float functions from libm
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// # Radec coordinate system
@@ -45,13 +48,12 @@ pub struct Radec {
 ///
 /// Shows both ra and dec in degrees, which are more easily readable.
 impl Display for Radec {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> DRes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> DRes {
         write!(f, "ra={}° :: dec={}°", self.ra.to_degrees(), self.dec.to_degrees())?;
 
         // If there is a distance, we print it as well
-        match self.dist_earth {
-            Some(d) => write!(f, " :: distance={}ly", d / constant::LY)?,
-            None => {}
+        if let Some(d) = self.dist_earth {
+            write!(f, " :: distance={}ly", d / constant::LY)?;
         }
         Ok(())
     }
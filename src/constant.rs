@@ -164,7 +164,7 @@ pub const F_0: f64 = 2.518_021_002e-8;
 /// - Value: $-18.997~351~629~757~571~863~584~819~458~253~886~318~389~141~306~029~739~058...$
 /// - Unit: Dimensionless
 /// - Source: Computed based on [IAU](https://www.iau.org/static/resolutions/IAU2015_English.pdf)
-pub const APP_MAG_SHIFT: f64 = -18.997_351_629_757_571_863_584_819_458_253_886_318_389_141_306_029_739_058_11;
+pub const APP_MAG_SHIFT: f64 = -18.997_351_629_757_57;
 
 /// # $\mathrm{AU}$ - Astronomical unit
 /// Value is defined
@@ -225,7 +225,7 @@ pub const H: f64 = 6.626_070_15e-34;
 /// - Value: $1.054~571~817\times10^{-34}$
 /// - Unit: $\mathrm{J.s = kg.m^2.s^{-1}}$
 /// - Source: Computed from definition [NIST](https://physics.nist.gov/cgi-bin/cuu/Value?hbar)
-pub const H_BAR: f64 = H / std::f64::consts::TAU;
+pub const H_BAR: f64 = H / core::f64::consts::TAU;
 
 /// # $l_\mathrm{P}$ - Planck length
 ///
@@ -292,6 +292,34 @@ pub const Z_0: f64 = 376.730_313_668;
 /// - Unit: Dimensionless
 pub const EULER_MASCHERONI: f64 = 0.577_215_664_901_532_860_606_512_090_082_402_431_042_159_335_939_92;
 
+/// # $G$ - Catalan's constant
+/// Value of the Dirichlet beta function $\beta(2)$.
+/// 
+/// - Value: $0.915~965~594~177~219~015~054~603~514~932~384~110~774...$
+/// - Unit: Dimensionless
+pub const CATALAN: f64 = 0.915_965_594_177_219_015_054_603_514_932_384_110_774;
+
+/// # $\zeta(3)$ - Apéry's constant
+/// Value of the Riemann zeta function at 3.
+/// 
+/// - Value: $1.202~056~903~159~594~285~399~738~161~511~449~990~765...$
+/// - Unit: Dimensionless
+pub const APERY: f64 = 1.202_056_903_159_594_285_399_738_161_511_449_990_765;
+
+/// # $\varphi$ - Golden ratio
+/// Computed as $(1 + \sqrt{5})/2$.
+/// 
+/// - Value: $1.618~033~988~749~894~848~204~586~834~365~638~117~720...$
+/// - Unit: Dimensionless
+pub const GOLDEN_RATIO: f64 = 1.618_033_988_749_894_848_204_586_834_365_638_117_720;
+
+/// # $A$ - Glaisher-Kinkelin constant
+/// Appears in the asymptotic expansion of the hyperfactorial, and in $\zeta'(-1) = 1/12 - \ln(A)$.
+/// 
+/// - Value: $1.282~427~129~100~622~636~875~342~568~869~791~727~768...$
+/// - Unit: Dimensionless
+pub const GLAISHER_KINKELIN: f64 = 1.282_427_129_100_622_636_875_342_568_869_791_727_768;
+
 /// # $N_\mathrm{A}$ - Avogadro constant
 /// Value is defined.
 ///
@@ -7,11 +7,11 @@
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-use std::f64::consts::{         // Using std lib constants
+use core::f64::consts::{         // Using std lib constants
     PI                          // Pi
 };
 
-use std::ops::{                 // Implementing basic operations
+use core::ops::{                 // Implementing basic operations
     Add,                        // Addition
     AddAssign,                  // Assigning addition
     Sub,                        // Subtraction
@@ -23,7 +23,7 @@ use std::ops::{                 // Implementing basic operations
     Neg                         // Negation
 };
 
-use std::fmt::{                 // Formatter display
+use core::fmt::{                 // Formatter display
     Display,                    // The display itself
     Result as DRes              // The associated result
 };
@@ -33,6 +33,9 @@ use super::{                    // Using parts from the crate
     spherical::Spherical        // Spherical coordinates
 };
 
+#[cfg(not(feature = "std"))]
+use crate::float::Float;   // Float functions from libm
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// # Cartesian coordinates
@@ -52,7 +55,7 @@ pub struct Cartesian {
 /// 
 /// Simply shows each value associated to an axis.
 impl Display for Cartesian {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> DRes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> DRes {
         write!(f, "x={} :: y={} :: z={}", self.x, self.y, self.z)?;
         Ok(())
     }
@@ -124,6 +127,77 @@ impl Cartesian {
         c.into()
     }
 
+    /// # From spherical coordinates
+    /// 
+    /// Explicit form of `from_coord`, converting from Spherical coordinates.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// # use scilib::coordinate::spherical::Spherical;
+    /// let c = Spherical::from_degree(2.4, 65, 15);
+    /// 
+    /// assert_eq!(Cartesian::from_spherical(c), c.into());
+    /// ```
+    pub fn from_spherical(c: Spherical) -> Self {
+        c.into()
+    }
+
+    /// # From cylindrical coordinates
+    /// 
+    /// Explicit form of `from_coord`, converting from Cylindrical coordinates.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// # use scilib::coordinate::cylindrical::Cylindrical;
+    /// let c = Cylindrical::from_degree(1.2, 32, -3);
+    /// 
+    /// assert_eq!(Cartesian::from_cylindrical(c), c.into());
+    /// ```
+    pub fn from_cylindrical(c: Cylindrical) -> Self {
+        c.into()
+    }
+
+    /// # From a single precision array
+    /// 
+    /// Bridge for `f32` pipelines: each component is widened to `f64`, which is exact.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// let arr: [f32; 3] = [1.5, -0.1, 3.0e20];
+    /// let c = Cartesian::from_f32_array(arr);
+    /// 
+    /// assert_eq!(c.x, 1.5);
+    /// assert_eq!(c.y, -0.1_f32 as f64);
+    /// assert_eq!(c.to_f32_array(), arr);
+    /// ```
+    pub fn from_f32_array(arr: [f32; 3]) -> Self {
+        Self {
+            x: arr[0] as f64,
+            y: arr[1] as f64,
+            z: arr[2] as f64
+        }
+    }
+
+    /// # To a single precision array
+    /// 
+    /// Bridge for `f32` pipelines: each component is rounded to the nearest `f32`. Values beyond the
+    /// `f32` range become infinite, and the relative error is otherwise bounded by `f32::EPSILON / 2`.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// let c = Cartesian::from(0.1, -2.5, 1.0e-3);
+    /// let arr: [f32; 3] = c.to_f32_array();
+    /// 
+    /// assert_eq!(arr, [0.1_f32, -2.5_f32, 1.0e-3_f32]);
+    /// assert!(((arr[0] as f64 - c.x) / c.x).abs() <= f32::EPSILON as f64 / 2.0);
+    /// 
+    /// let big = Cartesian::from(1.0e300, 0, 0);
+    /// assert!(big.to_f32_array()[0].is_infinite());
+    /// ```
+    pub fn to_f32_array(&self) -> [f32; 3] {
+        [self.x as f32, self.y as f32, self.z as f32]
+    }
+
     /// # Computes the vector norm
     /// 
     /// We follow the convention of the l2 norm in this implementation.
@@ -205,6 +279,328 @@ impl Cartesian {
             z: a31 * self.x + a32 * self.y + a33 * self.z
         }
     }
+
+    /// # Dot product
+    /// 
+    /// Computes the scalar product of two vectors.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// let u = Cartesian::from(1, -2, 3);
+    /// let v = Cartesian::from(4, 0.5, -1);
+    /// 
+    /// assert_eq!(u.dot(v), 0.0);
+    /// assert_eq!(u.dot(u), u.norm().powi(2));
+    /// ```
+    pub fn dot(&self, other: Self) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// # Normalized vector
+    /// 
+    /// Returns the vector with the same direction and a norm of one. The zero vector is returned unchanged.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// let v = Cartesian::from(3, 0, -4).normalize();
+    /// 
+    /// assert!((v.x - 0.6).abs() < 1.0e-15);
+    /// assert_eq!(v.y, 0.0);
+    /// assert!((v.z + 0.8).abs() < 1.0e-15);
+    /// assert!((v.norm() - 1.0).abs() < 1.0e-15);
+    /// assert_eq!(Cartesian::new().normalize(), Cartesian::new());
+    /// ```
+    pub fn normalize(&self) -> Self {
+        let n: f64 = self.norm();
+        if n == 0.0 {
+            return *self;
+        }
+        *self / n
+    }
+
+    /// # Reflection across a plane
+    /// 
+    /// Reflects the vector across the plane of given normal, which is normalized internally:
+    /// $$
+    /// \vec{v}' = \vec{v} - 2(\vec{v} \cdot \hat{n})\hat{n}
+    /// $$
+    /// 
+    /// A zero normal leaves the vector unchanged.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// let n = Cartesian::from(0, 3, 0);
+    /// 
+    /// // Bouncing off the y=0 plane
+    /// assert_eq!(Cartesian::from(1, -1, 0).reflect(n), Cartesian::from(1, 1, 0));
+    /// 
+    /// // Parallel to the plane, the vector is unchanged
+    /// let p = Cartesian::from(2, 0, -5);
+    /// assert_eq!(p.reflect(n), p);
+    /// 
+    /// // Head-on, the vector is negated
+    /// let h = Cartesian::from(0, -2.5, 0);
+    /// assert_eq!(h.reflect(n), -h);
+    /// ```
+    pub fn reflect(&self, normal: Self) -> Self {
+        let n: Self = normal.normalize();
+        *self - n * (2.0 * self.dot(n))
+    }
+
+    /// # Projection onto another vector
+    /// 
+    /// Computes the component of the vector along `other`:
+    /// $$
+    /// \text{proj}_{\vec{b}}(\vec{a}) = \frac{\vec{a} \cdot \vec{b}}{\vec{b} \cdot \vec{b}}\vec{b}
+    /// $$
+    /// 
+    /// Projecting onto the zero vector returns the zero vector.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// let a = Cartesian::from(3, 4, -1);
+    /// let b = Cartesian::from(2, 0, 0);
+    /// 
+    /// assert_eq!(a.project_onto(b), Cartesian::from(3, 0, 0));
+    /// assert_eq!(a.project_onto(Cartesian::new()), Cartesian::new());
+    /// ```
+    pub fn project_onto(&self, other: Self) -> Self {
+        let d: f64 = other.dot(other);
+        if d == 0.0 {
+            return Self::new();
+        }
+        other * (self.dot(other) / d)
+    }
+
+    /// # Rejection from another vector
+    /// 
+    /// Computes the component of the vector orthogonal to `other`, that is `self - self.project_onto(other)`.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// let a = Cartesian::from(1.5, -2, 0.7);
+    /// let b = Cartesian::from(0.3, 1.1, -4);
+    /// let proj = a.project_onto(b);
+    /// let rej = a.reject_from(b);
+    /// 
+    /// // Both parts sum back to the vector, and the rejection is orthogonal
+    /// assert!((proj + rej).distance(a) < 1.0e-15);
+    /// assert!(rej.dot(b).abs() < 1.0e-15);
+    /// 
+    /// assert_eq!(a.reject_from(Cartesian::new()), a);
+    /// ```
+    pub fn reject_from(&self, other: Self) -> Self {
+        *self - self.project_onto(other)
+    }
+
+    /// # Cross product
+    /// 
+    /// Computes the vector product of two vectors.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// let x = Cartesian::from(1, 0, 0);
+    /// let y = Cartesian::from(0, 1, 0);
+    /// 
+    /// assert_eq!(x.cross(y), Cartesian::from(0, 0, 1));
+    /// assert_eq!(y.cross(x), Cartesian::from(0, 0, -1));
+    /// ```
+    pub fn cross(&self, other: Self) -> Self {
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x
+        }
+    }
+
+    /// # Scalar triple product
+    /// 
+    /// Computes $\vec{a} \cdot (\vec{b} \times \vec{c})$, the signed volume of the parallelepiped built on the
+    /// three vectors.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// let x = Cartesian::from(1, 0, 0);
+    /// let y = Cartesian::from(0, 1, 0);
+    /// let z = Cartesian::from(0, 0, 1);
+    /// 
+    /// assert_eq!(x.triple_product(y, z), 1.0);
+    /// assert_eq!(x.triple_product(z, y), -1.0);
+    /// 
+    /// // Volume of a tetrahedron is a sixth of it
+    /// let v = Cartesian::from(2, 0, 0).triple_product(Cartesian::from(0, 3, 0), Cartesian::from(1, 1, 4)) / 6.0;
+    /// assert_eq!(v, 4.0);
+    /// ```
+    pub fn triple_product(&self, b: Self, c: Self) -> f64 {
+        self.dot(b.cross(c))
+    }
+
+    /// # Coplanarity check
+    /// 
+    /// Returns `true` if the magnitude of the triple product of the three vectors is within `tol`.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// let a = Cartesian::from(1, 2, 0);
+    /// let b = Cartesian::from(-3, 0.5, 0);
+    /// 
+    /// assert!(a.is_coplanar(b, a + b * 2, 1.0e-12));
+    /// assert!(!a.is_coplanar(b, Cartesian::from(0, 0, 1), 1.0e-12));
+    /// ```
+    pub fn is_coplanar<T>(&self, b: Self, c: Self, tol: T) -> bool
+    where T: Into<f64> {
+        self.triple_product(b, c).abs() <= tol.into()
+    }
+
+    /// # Componentwise map
+    /// 
+    /// Applies the function to each coordinate.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// let c = Cartesian::from(-1.5, 2, -0.25);
+    /// 
+    /// assert_eq!(c.map(f64::abs), Cartesian::from(1.5, 2, 0.25));
+    /// assert_eq!(c.map(|v| 2.0 * v + 1.0), Cartesian::from(-2, 5, 0.5));
+    /// ```
+    pub fn map<F>(&self, f: F) -> Self
+    where F: Fn(f64) -> f64 {
+        Self {
+            x: f(self.x),
+            y: f(self.y),
+            z: f(self.z)
+        }
+    }
+
+    /// # Componentwise combination
+    /// 
+    /// Combines the coordinates of both points with the function, axis by axis.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// let a = Cartesian::from(1, -2, 3);
+    /// let b = Cartesian::from(4, 0.5, -1);
+    /// 
+    /// // Elementwise product
+    /// assert_eq!(a.zip_with(b, |u, v| u * v), Cartesian::from(4, -1, -3));
+    /// assert_eq!(a.zip_with(b, f64::max), Cartesian::from(4, 0.5, 3));
+    /// ```
+    pub fn zip_with<F>(&self, other: Self, f: F) -> Self
+    where F: Fn(f64, f64) -> f64 {
+        Self {
+            x: f(self.x, other.x),
+            y: f(self.y, other.y),
+            z: f(self.z, other.z)
+        }
+    }
+
+    /// # Hadamard product
+    /// 
+    /// Multiplies the coordinates axis by axis, unlike the dot product which sums them into a scalar:
+    /// $$
+    /// \vec{a} \circ \vec{b} = (a_xb_x, a_yb_y, a_zb_z)
+    /// $$
+    /// 
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// let p = Cartesian::from(1, -2, 3);
+    /// let scale = Cartesian::from(2, 0.5, 10);
+    /// let h = p.hadamard(scale);
+    /// 
+    /// assert_eq!(h, Cartesian::from(2, -1, 30));
+    /// // The dot product is the sum of the components
+    /// assert_eq!(p.dot(scale), h.x + h.y + h.z);
+    /// ```
+    pub fn hadamard(&self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a * b)
+    }
+
+    /// # Componentwise division
+    /// 
+    /// Divides the coordinates axis by axis, the inverse of `hadamard`. A zero component of `other` gives an
+    /// infinite or `NaN` coordinate, following the IEEE rules of `f64`.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// let p = Cartesian::from(2, -1, 30);
+    /// let scale = Cartesian::from(2, 0.5, 10);
+    /// 
+    /// assert_eq!(p.component_div(scale), Cartesian::from(1, -2, 3));
+    /// assert_eq!(p.component_div(scale).hadamard(scale), p);
+    /// 
+    /// let d = Cartesian::from(1, -1, 0).component_div(Cartesian::new());
+    /// assert!(d.x == f64::INFINITY && d.y == f64::NEG_INFINITY && d.z.is_nan());
+    /// ```
+    pub fn component_div(&self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a / b)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Bounding box
+/// 
+/// Computes the axis-aligned box enclosing all the points, as its componentwise minimum and maximum corners.
+/// `NaN` coordinates are skipped, following `f64::min` and `f64::max`, unless all the points have one on the
+/// same axis.
+/// 
+/// ## Inputs
+/// - `points`: the point cloud
+/// 
+/// Returns the `(min, max)` corners, or `None` for an empty slice.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::coordinate::cartesian::{ Cartesian, bounding_box };
+/// let points = [
+///     Cartesian::from(1, -2, 0.5),
+///     Cartesian::from(-3, 4, 0),
+///     Cartesian::from(0.5, 1, f64::NAN),
+///     Cartesian::from(2, 0, -1)
+/// ];
+/// 
+/// let (min, max) = bounding_box(&points).unwrap();
+/// assert_eq!(min, Cartesian::from(-3, -2, -1));
+/// assert_eq!(max, Cartesian::from(2, 4, 0.5));
+/// 
+/// assert_eq!(bounding_box(&[]), None);
+/// ```
+pub fn bounding_box(points: &[Cartesian]) -> Option<(Cartesian, Cartesian)> {
+    let first: Cartesian = *points.first()?;
+
+    Some(points[1..].iter().fold((first, first), |(min, max), p| {
+        (min.zip_with(*p, f64::min), max.zip_with(*p, f64::max))
+    }))
+}
+
+/// # Centroid
+/// 
+/// Computes the arithmetic mean of the points.
+/// 
+/// ## Inputs
+/// - `points`: the point cloud
+/// 
+/// Returns the centroid, or `None` for an empty slice.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::coordinate::cartesian::{ Cartesian, centroid };
+/// let points = [
+///     Cartesian::from(0, 0, 0),
+///     Cartesian::from(2, 0, 0),
+///     Cartesian::from(0, 4, 0),
+///     Cartesian::from(2, 4, 8)
+/// ];
+/// 
+/// assert_eq!(centroid(&points), Some(Cartesian::from(1, 2, 2)));
+/// assert_eq!(centroid(&[]), None);
+/// ```
+pub fn centroid(points: &[Cartesian]) -> Option<Cartesian> {
+    if points.is_empty() {
+        return None;
+    }
+
+    Some(points.iter().fold(Cartesian::new(), |sum, p| sum + *p) / points.len() as f64)
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -230,20 +626,20 @@ impl Cartesian {
 /// assert_eq!(conv.theta, expected.theta);
 /// assert!((conv.phi - expected.phi).abs() < 1.0e-15);
 /// ```
-impl Into<Spherical> for Cartesian {
-    fn into(self) -> Spherical {
-        let rho: f64 = self.norm();
-        let mut nt: f64 = (self.y / self.x).atan();
+impl From<Cartesian> for Spherical {
+    fn from(c: Cartesian) -> Self {
+        let rho: f64 = c.norm();
+        let mut nt: f64 = (c.y / c.x).atan();
 
         // If we were in the wrong quadrants, the atan range doesn't work
-        if self.x.is_sign_negative() {
+        if c.x.is_sign_negative() {
             nt += PI;
         }
 
         Spherical {
             r: rho,
             theta: nt,
-            phi: (self.z / rho).acos()
+            phi: (c.z / rho).acos()
         }
     }
 }
@@ -267,20 +663,20 @@ impl Into<Spherical> for Cartesian {
 /// 
 /// assert_eq!(conv, expected);
 /// ```
-impl Into<Cylindrical> for Cartesian {
-    fn into(self) -> Cylindrical {
-        let rho: f64 = (self.x.powi(2) + self.y.powi(2)).sqrt();
-        let mut nt: f64 = (self.y / self.x).atan();
+impl From<Cartesian> for Cylindrical {
+    fn from(c: Cartesian) -> Self {
+        let rho: f64 = (c.x.powi(2) + c.y.powi(2)).sqrt();
+        let mut nt: f64 = (c.y / c.x).atan();
 
         // If we were in the wrong quadrants, the atan range doesn't work
-        if self.x.is_sign_negative() {
+        if c.x.is_sign_negative() {
             nt += PI;
         }
 
         Cylindrical {
             r: rho,
             theta: nt,
-            z: self.z
+            z: c.z
         }
     }
 }
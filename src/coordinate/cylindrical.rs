@@ -11,12 +11,12 @@
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-use std::f64::consts::{         // Using std lib constants
+use core::f64::consts::{         // Using std lib constants
     PI,                         // Pi
     TAU                         // Tau
 };
 
-use std::ops::{                 // Implementing basic operations
+use core::ops::{                 // Implementing basic operations
     Add,                        // Addition
     Sub,                        // Subtraction
     Mul,                        // Multiplication
@@ -26,7 +26,7 @@ use std::ops::{                 // Implementing basic operations
     Neg                         // Negation
 };
 
-use std::fmt::{                 // Formatter display
+use core::fmt::{                 // Formatter display
     Display,                    // The display itself
     Result as DRes              // The associated result
 };
@@ -36,6 +36,9 @@ use super::{                    // Using parts from the crate
     spherical::Spherical        // Spherical coordinates
 };
 
+#[cfg(not(feature = "std"))]
+use crate::float::Float;   // Float functions from libm
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// # Cylindrical coordinates
@@ -55,7 +58,7 @@ pub struct Cylindrical {
 /// 
 /// Simply shows each value associated to an axis.
 impl Display for Cylindrical {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> DRes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> DRes {
         write!(f, "r={} :: theta={}° :: z={}", self.r, self.theta.to_degrees(), self.z)?;
         Ok(())
     }
@@ -146,6 +149,36 @@ impl Cylindrical {
         c.into()
     }
 
+    /// # From cartesian coordinates
+    /// 
+    /// Explicit form of `from_coord`, converting from Cartesian coordinates.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::cylindrical::Cylindrical;
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// let c = Cartesian::from(0, 12, 3.2);
+    /// 
+    /// assert_eq!(Cylindrical::from_cartesian(c), c.into());
+    /// ```
+    pub fn from_cartesian(c: Cartesian) -> Self {
+        c.into()
+    }
+
+    /// # From spherical coordinates
+    /// 
+    /// Explicit form of `from_coord`, converting from Spherical coordinates.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::cylindrical::Cylindrical;
+    /// # use scilib::coordinate::spherical::Spherical;
+    /// let c = Spherical::from_degree(1.2, 32, 60);
+    /// 
+    /// assert_eq!(Cylindrical::from_spherical(c), c.into());
+    /// ```
+    pub fn from_spherical(c: Spherical) -> Self {
+        c.into()
+    }
+
     /// # Distance between two points
     /// 
     /// ```
@@ -163,6 +196,71 @@ impl Cylindrical {
 
         (t1 - t2 + t3).sqrt()
     }
+
+    /// # Jacobian determinant
+    /// 
+    /// Volume element factor of the transform from $(r, \theta, z)$ to cartesian coordinates:
+    /// $$
+    /// dx\,dy\,dz = r\,dr\,d\theta\,dz
+    /// $$
+    /// 
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// # use scilib::coordinate::cylindrical::Cylindrical;
+    /// // Finite difference estimate of the determinant of d(x, y, z) / d(r, theta, z)
+    /// let h: f64 = 1.0e-5;
+    /// for (r, t, z) in [(1.0, 0.3, 0.4), (2.5, 4.1, -1.9), (0.7, 2.0, 3.0)] {
+    ///     let f = |r: f64, t: f64, z: f64| Cartesian::from_coord(Cylindrical { r, theta: t, z });
+    ///     let dr = (f(r + h, t, z) - f(r - h, t, z)) / (2.0 * h);
+    ///     let dt = (f(r, t + h, z) - f(r, t - h, z)) / (2.0 * h);
+    ///     let dz = (f(r, t, z + h) - f(r, t, z - h)) / (2.0 * h);
+    ///     let det = dr.x * (dt.y * dz.z - dt.z * dz.y)
+    ///             - dt.x * (dr.y * dz.z - dr.z * dz.y)
+    ///             + dz.x * (dr.y * dt.z - dr.z * dt.y);
+    /// 
+    ///     let c = Cylindrical { r, theta: t, z };
+    ///     assert!((det - c.jacobian()).abs() < 1.0e-8);
+    /// }
+    /// ```
+    pub fn jacobian(&self) -> f64 {
+        self.r
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Centroid
+/// 
+/// Computes the centroid of the points, by averaging them in cartesian coordinates. This avoids the wrap-around
+/// of the angles, which would skew a naive average of the coordinates.
+/// 
+/// ## Inputs
+/// - `points`: the point cloud
+/// 
+/// Returns the centroid, or `None` for an empty slice.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::coordinate::cylindrical::{ self, Cylindrical };
+/// let points = [Cylindrical::from_degree(2, 300, -1), Cylindrical::from_degree(2, 60, 3)];
+/// let c = cylindrical::centroid(&points).unwrap();
+/// 
+/// assert!(c.theta.abs() < 1.0e-15);
+/// assert!((c.r - 1.0).abs() < 1.0e-15);
+/// assert_eq!(c.z, 1.0);
+/// 
+/// assert_eq!(cylindrical::centroid(&[]), None);
+/// ```
+pub fn centroid(points: &[Cylindrical]) -> Option<Cylindrical> {
+    if points.is_empty() {
+        return None;
+    }
+
+    let sum: Cartesian = points.iter().fold(Cartesian::new(), |sum, p| {
+        let cart: Cartesian = (*p).into();
+        sum + cart
+    });
+    Some((sum / points.len() as f64).into())
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -188,12 +286,12 @@ impl Cylindrical {
 /// assert!((conv.y - expected.y).abs() < 1.0e-9);
 /// assert_eq!(conv.z, expected.z);
 /// ```
-impl Into<Cartesian> for Cylindrical {
-    fn into(self) -> Cartesian {
+impl From<Cylindrical> for Cartesian {
+    fn from(c: Cylindrical) -> Self {
         Cartesian {
-            x: self.r * self.theta.cos(),
-            y: self.r * self.theta.sin(),
-            z: self.z
+            x: c.r * c.theta.cos(),
+            y: c.r * c.theta.sin(),
+            z: c.z
         }
     }
 }
@@ -220,18 +318,18 @@ impl Into<Cartesian> for Cylindrical {
 /// assert_eq!(conv.theta, expected.theta);
 /// assert!((conv.phi - expected.phi).abs() < 1.0e-9);
 /// ```
-impl Into<Spherical> for Cylindrical {
-    fn into(self) -> Spherical {
-        let rho: f64 = (self.r.powi(2) + self.z.powi(2)).sqrt();
-        let mut np: f64 = (self.r / self.z).atan();
+impl From<Cylindrical> for Spherical {
+    fn from(c: Cylindrical) -> Self {
+        let rho: f64 = (c.r.powi(2) + c.z.powi(2)).sqrt();
+        let mut np: f64 = (c.r / c.z).atan();
 
-        if self.z.is_sign_negative() {
+        if c.z.is_sign_negative() {
             np += PI;
         }
 
         Spherical {
             r: rho,
-            theta: self.theta,
+            theta: c.theta,
             phi: np
         }
     }
@@ -240,6 +338,23 @@ impl Into<Spherical> for Cylindrical {
 /// # Addition
 /// 
 /// Converts the coordinate in cartesian for addition, then returns them as Cylindrical.
+/// 
+/// ```
+/// # use scilib::coordinate::cartesian::Cartesian;
+/// # use scilib::coordinate::cylindrical::Cylindrical;
+/// let c1 = Cylindrical::from_degree(2, 30, 1.5);
+/// let c2 = Cylindrical::from_degree(1, 120, -0.5);
+/// let res: Cartesian = (c1 + c2).into();
+/// let expected = Cartesian::from_coord(c1) + Cartesian::from_coord(c2);
+/// 
+/// assert!((res.x - expected.x).abs() < 1.0e-12);
+/// assert!((res.y - expected.y).abs() < 1.0e-12);
+/// assert!((res.z - expected.z).abs() < 1.0e-12);
+/// 
+/// // Any type that converts to cartesian can be added
+/// let res_c: Cylindrical = c1 + Cartesian::from(0, 0, 1);
+/// assert!((res_c.z - 2.5).abs() < 1.0e-12 && (res_c.r - c1.r).abs() < 1.0e-12);
+/// ```
 impl<T: Into<Cartesian>> Add<T> for Cylindrical {
     type Output = Self;
     fn add(self, rhs: T) -> Self::Output {
@@ -252,6 +367,22 @@ impl<T: Into<Cartesian>> Add<T> for Cylindrical {
 /// # Subtraction
 /// 
 /// Converts the coordinate in cartesian for subtraction, then returns them as Cylindrical.
+/// 
+/// ```
+/// # use scilib::coordinate::cartesian::Cartesian;
+/// # use scilib::coordinate::cylindrical::Cylindrical;
+/// let c1 = Cylindrical::from_degree(2, 30, 1.5);
+/// let c2 = Cylindrical::from_degree(1, 120, -0.5);
+/// let res: Cartesian = (c1 - c2).into();
+/// let expected = Cartesian::from_coord(c1) - Cartesian::from_coord(c2);
+/// 
+/// assert!((res.x - expected.x).abs() < 1.0e-12);
+/// assert!((res.y - expected.y).abs() < 1.0e-12);
+/// assert!((res.z - expected.z).abs() < 1.0e-12);
+/// 
+/// // A point minus itself is the origin
+/// assert!((c1 - c1).r < 1.0e-12 && (c1 - c1).z.abs() < 1.0e-12);
+/// ```
 impl<T: Into<Cartesian>> Sub<T> for Cylindrical {
     type Output = Self;
     fn sub(self, rhs: T) -> Self::Output {
@@ -378,12 +509,20 @@ impl<T: Into<f64>> DivAssign<T> for Cylindrical {
 /// Going to the opposite point.
 /// 
 /// ```
+/// # use scilib::coordinate::cartesian::Cartesian;
 /// # use scilib::coordinate::cylindrical::Cylindrical;
 /// let c1 = Cylindrical::from_degree(2, 35, 6.0);
 /// let c2 = -c1;
 /// let expected = Cylindrical::from_degree(2, 215, -6);
 /// 
 /// assert_eq!(c2, expected);
+/// 
+/// // The negation matches the one of cartesian coordinates
+/// let res: Cartesian = c2.into();
+/// let expected_c: Cartesian = -Cartesian::from_coord(c1);
+/// assert!((res.x - expected_c.x).abs() < 1.0e-12);
+/// assert!((res.y - expected_c.y).abs() < 1.0e-12);
+/// assert_eq!(res.z, expected_c.z);
 /// ```
 impl Neg for Cylindrical {
     type Output = Self;
@@ -0,0 +1,225 @@
+//!
+//! # Geographic coordinates
+//! 
+//! Latitude, longitude and altitude, as used for positions on a planetary body. Unlike the `phi` angle of
+//! spherical coordinates, the latitude is measured from the equator and not from the pole.
+
+use core::f64::consts::{         // Using std lib constants
+    PI,                         // Pi
+    FRAC_PI_2,                  // Pi / 2
+    TAU                         // Tau
+};
+
+use core::fmt::{                 // Formatter display
+    Display,                    // The display itself
+    Result as DRes              // The associated result
+};
+
+use super::spherical::Spherical;
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;   // Float functions from libm
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Geographic coordinates
+/// 
+/// Angles are stored in radians, with the latitude in $[-\pi/2, \pi/2]$ and the longitude in $]-\pi, \pi]$.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Geographic {
+    /// Latitude angle, from the equator
+    pub lat: f64,
+    /// Longitude angle
+    pub lon: f64,
+    /// Altitude
+    pub altitude: f64
+}
+
+/// # Display for Geographic
+/// 
+/// Simply shows each value.
+impl Display for Geographic {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> DRes {
+        write!(f, "lat={}° :: lon={}° :: altitude={}", self.lat.to_degrees(), self.lon.to_degrees(), self.altitude)?;
+        Ok(())
+    }
+}
+
+impl Geographic {
+    /// # Creates a new entity
+    /// 
+    /// Returns the same value as `Self::default()`, all elements are equal to zero.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::geographic::Geographic;
+    /// let m = Geographic { lat: 0.0, lon: 0.0, altitude: 0.0 };
+    /// let n = Geographic::new();
+    /// 
+    /// assert_eq!(m, n);
+    /// assert_eq!(n, Geographic::default());
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            lat: 0.0,
+            lon: 0.0,
+            altitude: 0.0
+        }
+    }
+
+    /// # From the point
+    /// 
+    /// Creates a Geographic struct from a latitude, a longitude (both in radians) and an altitude.
+    /// The longitude is wrapped into $]-\pi, \pi]$.
+    /// 
+    /// ```
+    /// # use std::f64::consts::PI;
+    /// # use scilib::coordinate::geographic::Geographic;
+    /// let m = Geographic { lat: 0.5, lon: -1.2, altitude: 10.0 };
+    /// let f = Geographic::from(0.5, -1.2 + 2.0 * PI, 10);
+    /// 
+    /// assert!((m.lon - f.lon).abs() < 1.0e-15);
+    /// ```
+    pub fn from<T, U, V>(lat: T, lon: U, altitude: V) -> Self
+    where T: Into<f64>, U: Into<f64>, V: Into<f64> {
+        Self {
+            lat: lat.into(),
+            lon: wrap_longitude(lon.into()),
+            altitude: altitude.into()
+        }
+    }
+
+    /// # From the point (degrees)
+    /// 
+    /// Creates a Geographic struct with the angles given in degrees.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::geographic::Geographic;
+    /// let m = Geographic { lat: 45.0_f64.to_radians(), lon: -60.0_f64.to_radians(), altitude: 2.0 };
+    /// let f = Geographic::from_degree(45, 300, 2);
+    /// 
+    /// assert!((m.lat - f.lat).abs() < 1.0e-15);
+    /// assert!((m.lon - f.lon).abs() < 1.0e-15);
+    /// ```
+    pub fn from_degree<T, U, V>(lat: T, lon: U, altitude: V) -> Self
+    where T: Into<f64>, U: Into<f64>, V: Into<f64> {
+        Self::from(lat.into().to_radians(), lon.into().to_radians(), altitude)
+    }
+
+    /// # From spherical coordinates
+    /// 
+    /// Explicit form of `from_coord`, converting from Spherical coordinates.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::geographic::Geographic;
+    /// # use scilib::coordinate::spherical::Spherical;
+    /// let c = Spherical::from_degree(6371, 2.35, 41.15);
+    /// 
+    /// assert_eq!(Geographic::from_spherical(c), c.into());
+    /// ```
+    pub fn from_spherical(c: Spherical) -> Self {
+        c.into()
+    }
+
+    /// # Haversine distance
+    /// 
+    /// Computes the great-circle distance between two points on a sphere of the given `radius`, the altitudes
+    /// are ignored. Uses the [haversine formula](https://en.wikipedia.org/wiki/Haversine_formula):
+    /// $$
+    /// d = 2r\arcsin\left(\sqrt{\sin^2\left(\frac{\Delta\varphi}{2}\right) + \cos\varphi_1\cos\varphi_2
+    /// \sin^2\left(\frac{\Delta\lambda}{2}\right)}\right)
+    /// $$
+    /// 
+    /// ```
+    /// # use scilib::coordinate::geographic::Geographic;
+    /// let paris = Geographic::from_degree(48.8566, 2.3522, 0);
+    /// let london = Geographic::from_degree(51.5074, -0.1278, 0);
+    /// let new_york = Geographic::from_degree(40.7128, -74.0060, 0);
+    /// 
+    /// // Mean Earth radius, in kilometers
+    /// let r: f64 = 6371.0;
+    /// 
+    /// assert!((paris.distance(london, r) - 344.0).abs() < 1.0);
+    /// assert!((london.distance(new_york, r) - 5570.0).abs() < 1.0);
+    /// ```
+    pub fn distance<T>(&self, other: Self, radius: T) -> f64
+    where T: Into<f64> {
+        let dlat: f64 = (other.lat - self.lat) / 2.0;
+        let dlon: f64 = (other.lon - self.lon) / 2.0;
+        let h: f64 = dlat.sin().powi(2) + self.lat.cos() * other.lat.cos() * dlon.sin().powi(2);
+
+        // Rounding may push h slightly above one for antipodal points
+        2.0 * radius.into() * h.min(1.0).sqrt().asin()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Wrapping the longitude
+/// 
+/// Brings any angle into $]-\pi, \pi]$.
+fn wrap_longitude(lon: f64) -> f64 {
+    let w: f64 = lon.rem_euclid(TAU);
+    if w > PI { w - TAU } else { w }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Conversion to spherical coordinates
+/// 
+/// The altitude is used as the radial distance, so the radius of the body should be added beforehand if the
+/// altitude is measured from its surface. The polar angle is obtained with $\phi = \pi/2 - \text{lat}$.
+/// 
+/// ```
+/// # use std::f64::consts::PI;
+/// # use scilib::coordinate::geographic::Geographic;
+/// # use scilib::coordinate::spherical::Spherical;
+/// let g = Geographic::from_degree(30, -90, 2);
+/// let s: Spherical = g.into();
+/// 
+/// assert_eq!(s.r, 2.0);
+/// assert!((s.theta - 1.5 * PI).abs() < 1.0e-15);
+/// assert!((s.phi - PI / 3.0).abs() < 1.0e-15);
+/// 
+/// // The south pole is kept
+/// let s: Spherical = Geographic::from_degree(-90, 0, 1).into();
+/// assert_eq!(s.phi, PI);
+/// ```
+impl From<Geographic> for Spherical {
+    fn from(g: Geographic) -> Self {
+        Spherical {
+            r: g.altitude,
+            theta: g.lon.rem_euclid(TAU),
+            phi: FRAC_PI_2 - g.lat
+        }
+    }
+}
+
+/// # Conversion to geographic coordinates
+/// 
+/// The radial distance becomes the altitude, and $\text{lat} = \pi/2 - \phi$.
+/// 
+/// ```
+/// # use scilib::coordinate::geographic::Geographic;
+/// # use scilib::coordinate::spherical::Spherical;
+/// let s = Spherical::from_degree(3, 270, 120);
+/// let g: Geographic = s.into();
+/// 
+/// assert_eq!(g.altitude, 3.0);
+/// assert!((g.lat.to_degrees() + 30.0).abs() < 1.0e-12);
+/// assert!((g.lon.to_degrees() + 90.0).abs() < 1.0e-12);
+/// 
+/// let back: Spherical = g.into();
+/// assert!((back.theta - s.theta).abs() < 1.0e-15);
+/// assert!((back.phi - s.phi).abs() < 1.0e-15);
+/// ```
+impl From<Spherical> for Geographic {
+    fn from(s: Spherical) -> Self {
+        Geographic {
+            lat: FRAC_PI_2 - s.phi,
+            lon: wrap_longitude(s.theta),
+            altitude: s.r
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -6,9 +6,10 @@
 //! Currently available:
 //! - Cartesian
 //! - Spherical
+//! - Cylindrical
+//! - Geographic
 //! 
 //! Planned:
-//! - Cylindrical
 //! - Orbital (might be added in the astrophysics crate)
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -19,4 +20,6 @@ pub mod spherical;
 
 pub mod cylindrical;
 
+pub mod geographic;
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -11,12 +11,12 @@
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-use std::f64::consts::{         // Using std lib constants
+use core::f64::consts::{         // Using std lib constants
     PI,                         // Pi
     TAU                         // Tau
 };
 
-use std::ops::{                 // Implementing basic operations
+use core::ops::{                 // Implementing basic operations
     Add,                        // Addition
     Sub,                        // Subtraction
     Mul,                        // Multiplication
@@ -26,16 +26,20 @@ use std::ops::{                 // Implementing basic operations
     Neg                         // Negation
 };
 
-use std::fmt::{                 // Formatter display
+use core::fmt::{                 // Formatter display
     Display,                    // The display itself
     Result as DRes              // The associated result
 };
 
 use super::{                    // Using parts from the crate
     cartesian::Cartesian,       // Cartesian coordinates
-    cylindrical::Cylindrical    // Cylindrical coordinates
+    cylindrical::Cylindrical,   // Cylindrical coordinates
+    geographic::Geographic      // Geographic coordinates
 };
 
+#[cfg(not(feature = "std"))]
+use crate::float::Float;   // Float functions from libm
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// # Spherical coordinates
@@ -55,7 +59,7 @@ pub struct Spherical {
 /// 
 /// Simply shows each value.
 impl Display for Spherical {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> DRes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> DRes {
         write!(f, "r={} :: theta={}° :: phi={}°", self.r, self.theta.to_degrees(), self.phi.to_degrees())?;
         Ok(())
     }
@@ -147,6 +151,51 @@ impl Spherical {
         c.into()
     }
 
+    /// # From cartesian coordinates
+    /// 
+    /// Explicit form of `from_coord`, converting from Cartesian coordinates.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::spherical::Spherical;
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// let c = Cartesian::from(0, 12, 3.2);
+    /// 
+    /// assert_eq!(Spherical::from_cartesian(c), c.into());
+    /// ```
+    pub fn from_cartesian(c: Cartesian) -> Self {
+        c.into()
+    }
+
+    /// # From cylindrical coordinates
+    /// 
+    /// Explicit form of `from_coord`, converting from Cylindrical coordinates.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::spherical::Spherical;
+    /// # use scilib::coordinate::cylindrical::Cylindrical;
+    /// let c = Cylindrical::from_degree(1.2, 32, -3);
+    /// 
+    /// assert_eq!(Spherical::from_cylindrical(c), c.into());
+    /// ```
+    pub fn from_cylindrical(c: Cylindrical) -> Self {
+        c.into()
+    }
+
+    /// # From geographic coordinates
+    /// 
+    /// Explicit form of `from_coord`, converting from Geographic coordinates.
+    /// 
+    /// ```
+    /// # use scilib::coordinate::spherical::Spherical;
+    /// # use scilib::coordinate::geographic::Geographic;
+    /// let c = Geographic::from_degree(48.85, 2.35, 6371);
+    /// 
+    /// assert_eq!(Spherical::from_geographic(c), c.into());
+    /// ```
+    pub fn from_geographic(c: Geographic) -> Self {
+        c.into()
+    }
+
     /// # Distance between two points
     /// 
     /// ```
@@ -165,6 +214,115 @@ impl Spherical {
 
         (r1 + r2 - 2.0 * r1 * r2 * (a1 + a2)).sqrt()
     }
+
+    /// # Great-circle distance between two points
+    /// 
+    /// Computes the length of the shortest arc between the directions of the two points, on the sphere
+    /// of radius `self.r`. The central angle $\Delta\sigma$ is obtained with the
+    /// [Vincenty formula](https://en.wikipedia.org/wiki/Great-circle_distance#Computational_formulae),
+    /// which stays accurate for both nearby and antipodal points:
+    /// $$
+    /// \Delta\sigma = \arctan\left( \frac{|\hat{u}_1 \times \hat{u}_2|}{\hat{u}_1 \cdot \hat{u}_2} \right)
+    /// $$
+    /// 
+    /// Returns the arc length $r\Delta\sigma$.
+    /// 
+    /// ```
+    /// # use std::f64::consts::PI;
+    /// # use scilib::coordinate::spherical::Spherical;
+    /// let s1 = Spherical::from_degree(2, 0, 90);
+    /// let s2 = Spherical::from_degree(2, 90, 90);
+    /// let s3 = Spherical::from_degree(2, 180, 90);
+    /// let s4 = Spherical::from_degree(2, 0, 0);
+    /// 
+    /// assert!((s1.arc_distance(s2) - PI).abs() < 1.0e-15);
+    /// assert!((s1.arc_distance(s3) - 2.0 * PI).abs() < 1.0e-15);
+    /// assert!((s2.arc_distance(s4) - PI).abs() < 1.0e-15);
+    /// 
+    /// // Nearby points keep their precision
+    /// let s5 = Spherical::from(1, 0.3, 1.2);
+    /// let s6 = Spherical::from(1, 0.3, 1.2 + 1.0e-10);
+    /// assert!((s5.arc_distance(s6) - 1.0e-10).abs() < 1.0e-16);
+    /// ```
+    pub fn arc_distance(&self, other: Self) -> f64 {
+        let (s1, c1): (f64, f64) = self.phi.sin_cos();
+        let (s2, c2): (f64, f64) = other.phi.sin_cos();
+        let (sd, cd): (f64, f64) = (other.theta - self.theta).sin_cos();
+
+        let num: f64 = ((s2 * sd).powi(2) + (s1 * c2 - c1 * s2 * cd).powi(2)).sqrt();
+        let den: f64 = c1 * c2 + s1 * s2 * cd;
+
+        self.r * num.atan2(den)
+    }
+
+    /// # Jacobian determinant
+    /// 
+    /// Volume element factor of the transform from $(r, \theta, \phi)$ to cartesian coordinates, where $\theta$ is
+    /// the azimuth and $\phi$ the polar angle measured from the `z` axis:
+    /// $$
+    /// dx\,dy\,dz = r^2\sin(\phi)\,dr\,d\theta\,d\phi
+    /// $$
+    /// 
+    /// ```
+    /// # use scilib::coordinate::cartesian::Cartesian;
+    /// # use scilib::coordinate::spherical::Spherical;
+    /// // Finite difference estimate of the determinant of d(x, y, z) / d(r, theta, phi)
+    /// let h: f64 = 1.0e-5;
+    /// for (r, t, p) in [(1.0, 0.3, 0.4), (2.5, 4.1, 1.9), (0.7, 2.0, 3.0)] {
+    ///     let f = |r: f64, t: f64, p: f64| Cartesian::from_coord(Spherical { r, theta: t, phi: p });
+    ///     let dr = (f(r + h, t, p) - f(r - h, t, p)) / (2.0 * h);
+    ///     let dt = (f(r, t + h, p) - f(r, t - h, p)) / (2.0 * h);
+    ///     let dp = (f(r, t, p + h) - f(r, t, p - h)) / (2.0 * h);
+    ///     let det = dr.x * (dt.y * dp.z - dt.z * dp.y)
+    ///             - dt.x * (dr.y * dp.z - dr.z * dp.y)
+    ///             + dp.x * (dr.y * dt.z - dr.z * dt.y);
+    /// 
+    ///     // The orientation of (theta, phi) gives a negative determinant
+    ///     let s = Spherical { r, theta: t, phi: p };
+    ///     assert!((det.abs() - s.jacobian()).abs() < 1.0e-8);
+    /// }
+    /// ```
+    pub fn jacobian(&self) -> f64 {
+        self.r.powi(2) * self.phi.sin()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Centroid
+/// 
+/// Computes the centroid of the points, by averaging them in cartesian coordinates. This avoids the wrap-around
+/// of the angles, which would skew a naive average of the coordinates.
+/// 
+/// ## Inputs
+/// - `points`: the point cloud
+/// 
+/// Returns the centroid, or `None` for an empty slice.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::coordinate::spherical::{ self, Spherical };
+/// // On the equator, at 350° and 10° of longitude
+/// let points = [Spherical::from_degree(1, 350, 90), Spherical::from_degree(1, 10, 90)];
+/// let c = spherical::centroid(&points).unwrap();
+/// 
+/// // Centered on the 0° longitude, rather than on the 180° of the naive average
+/// assert!(c.theta.abs() < 1.0e-15);
+/// assert!((c.phi - 90.0_f64.to_radians()).abs() < 1.0e-15);
+/// assert!((c.r - 10.0_f64.to_radians().cos()).abs() < 1.0e-15);
+/// 
+/// assert_eq!(spherical::centroid(&[]), None);
+/// ```
+pub fn centroid(points: &[Spherical]) -> Option<Spherical> {
+    if points.is_empty() {
+        return None;
+    }
+
+    let sum: Cartesian = points.iter().fold(Cartesian::new(), |sum, p| {
+        let cart: Cartesian = (*p).into();
+        sum + cart
+    });
+    Some((sum / points.len() as f64).into())
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -190,12 +348,12 @@ impl Spherical {
 /// assert_eq!(conv.y, expected.y);
 /// assert!((conv.z - expected.z).abs() < 1.0e-15);
 /// ```
-impl Into<Cartesian> for Spherical {
-    fn into(self) -> Cartesian {
+impl From<Spherical> for Cartesian {
+    fn from(c: Spherical) -> Self {
         Cartesian {
-            x: self.r * self.theta.cos() * self.phi.sin(),
-            y: self.r * self.theta.sin() * self.phi.sin(),
-            z: self.r * self.phi.cos()
+            x: c.r * c.theta.cos() * c.phi.sin(),
+            y: c.r * c.theta.sin() * c.phi.sin(),
+            z: c.r * c.phi.cos()
         }
     }
 }
@@ -220,12 +378,12 @@ impl Into<Cartesian> for Spherical {
 /// assert_eq!(conv.theta, expected.theta);
 /// assert!((conv.z - expected.z).abs() < 1.0e-9);
 /// ```
-impl Into<Cylindrical> for Spherical {
-    fn into(self) -> Cylindrical {
+impl From<Spherical> for Cylindrical {
+    fn from(c: Spherical) -> Self {
         Cylindrical {
-            r: self.r * self.phi.sin(),
-            theta: self.theta,
-            z: self.r * self.phi.cos()
+            r: c.r * c.phi.sin(),
+            theta: c.theta,
+            z: c.r * c.phi.cos()
         }
     }
 }
@@ -0,0 +1,78 @@
+//!
+//! # Float functions without the standard library
+//! 
+//! The `f64` methods such as `sin` or `exp` are provided by `std`. Without it, this trait gives the same
+//! methods, computed by `libm`, so that the rest of the crate is written the same way in both cases.
+//! 
+//! The methods that `core` already provides, `abs`, `signum` and `copysign`, are not repeated; `core` has
+//! them since Rust 1.85, which `no_std` builds therefore need. The `log` method, only used by the modules
+//! that allocate, is compiled with the `alloc` feature.
+//! 
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// The `f64` methods of the standard library used in the crate, implemented with `libm`
+pub(crate) trait Float {
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn fract(self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn ln_1p(self) -> Self;
+    #[cfg(feature = "alloc")]
+    fn log(self, base: Self) -> Self;
+    fn log10(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn sin_cos(self) -> (Self, Self) where Self: Sized;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn tanh(self) -> Self;
+    fn atanh(self) -> Self;
+}
+
+impl Float for f64 {
+    fn floor(self) -> Self { libm::floor(self) }
+    fn ceil(self) -> Self { libm::ceil(self) }
+    fn round(self) -> Self { libm::round(self) }
+    fn fract(self) -> Self { self - libm::trunc(self) }
+    fn rem_euclid(self, rhs: Self) -> Self {
+        let r: f64 = libm::fmod(self, rhs);
+        if r < 0.0 { r + libm::fabs(rhs) } else { r }
+    }
+    fn sqrt(self) -> Self { libm::sqrt(self) }
+    fn cbrt(self) -> Self { libm::cbrt(self) }
+    fn hypot(self, other: Self) -> Self { libm::hypot(self, other) }
+    fn powi(self, n: i32) -> Self { libm::pow(self, n as f64) }
+    fn powf(self, n: Self) -> Self { libm::pow(self, n) }
+    fn exp(self) -> Self { libm::exp(self) }
+    fn ln(self) -> Self { libm::log(self) }
+    fn ln_1p(self) -> Self { libm::log1p(self) }
+    #[cfg(feature = "alloc")]
+    fn log(self, base: Self) -> Self { libm::log(self) / libm::log(base) }
+    fn log10(self) -> Self { libm::log10(self) }
+    fn sin(self) -> Self { libm::sin(self) }
+    fn cos(self) -> Self { libm::cos(self) }
+    fn sin_cos(self) -> (Self, Self) { libm::sincos(self) }
+    fn asin(self) -> Self { libm::asin(self) }
+    fn acos(self) -> Self { libm::acos(self) }
+    fn atan(self) -> Self { libm::atan(self) }
+    fn atan2(self, other: Self) -> Self { libm::atan2(self, other) }
+    fn sinh(self) -> Self { libm::sinh(self) }
+    fn cosh(self) -> Self { libm::cosh(self) }
+    fn tanh(self) -> Self { libm::tanh(self) }
+    fn atanh(self) -> Self { libm::atanh(self) }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
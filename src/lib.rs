@@ -10,8 +10,8 @@
 //! that will hopefully help many science developers.
 //!
 //! > **Before you use**: this crate is currently a work in progress and is thus missing many features. I will do my best to ensure
-//! both fast and correct computation, but it is evident that improvements could be found in the future. I will
-//! first implement concept I am familiar with, and work towards other domains later on.
+//! > both fast and correct computation, but it is evident that improvements could be found in the future. I will
+//! > first implement concept I am familiar with, and work towards other domains later on.
 //!
 //! ## Contents
 //!
@@ -30,6 +30,47 @@
 //! - **Astronomy**: Astronomical toolbox (***wip***)
 //! - **Quantum**: Quantum mechanics toolbox (***wip***)
 //!
+//! ## Features
+//!
+//! - `std` (default): takes the floating point functions (`sin`, `exp`, `ln`, `sqrt`, ...) from the standard library.
+//! - `alloc` (enabled by `std`): compiles the functions that return a `Vec` or build a `String`.
+//! - `libm`: takes the floating point functions from the [libm](https://crates.io/crates/libm) crate instead, for `no_std` builds.
+//!
+//! ## Platform support
+//!
+//! The crate is `no_std` when the `std` feature is disabled, in which case `libm` must be enabled. These builds
+//! need Rust 1.85, for the `abs`, `signum` and `copysign` methods of `f64` in `core`.
+//!
+//! ```toml
+//! scilib = { version = "0.5", default-features = false, features = ["libm", "alloc"] }
+//! ```
+//!
+//! With `alloc`, the whole crate is available. Without it, only the modules and functions that do not allocate remain:
+//!
+//! - `constant` and `astronomy`
+//! - `coordinate`, all of it
+//! - `math::complex`, except its `Display` and `LowerExp` implementations and `Complex::to_polar_string`
+//! - `math::basic`, except `softmax`, `gaussian_multivariate`, `pascal_triangle`, `pascal_rows`, `bell`,
+//!   `stirling_first`, `stirling_second`, `levi_civita` and `permutation_sign`
+//! - `math::calculus`, except `cumulative_trapezoid`
+//! - `math::stats`
+//! - `planck`, except the `_vec` functions
+//!
+//! The other modules need `alloc`.
+//!
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("scilib needs either the `std` or the `libm` feature for its float functions");
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+mod float;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -43,8 +84,11 @@ pub mod math;
 
 pub mod planck;
 
+#[cfg(feature = "alloc")]
 pub mod quantum;
 
+#[cfg(feature = "alloc")]
 pub mod range;
 
+#[cfg(feature = "alloc")]
 pub mod signal;
@@ -6,25 +6,298 @@
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-use std::f64::consts::{     // Using std lib constants
-    //FRAC_PI_2,              // Pi / 2
+use core::f64::consts::{     // Using std lib constants
+    E,                      // Euler's number
+    FRAC_PI_2,              // Pi / 2
+    FRAC_PI_4,              // Pi / 4
     FRAC_2_SQRT_PI,         // 2 / sqrt(Pi)
+    PI,                     // Pi
+    SQRT_2,                 // sqrt(2)
     TAU                     // Tau constant
 };
 
 use super::{                // Using parts from the crate
     super::constant,        // Calling scilib constants
-    complex::Complex,       // Using Complex numbers
-    polynomial::Bernoulli   // Bernoulli polynomials
+    complex::Complex        // Using Complex numbers
 };
 
+#[cfg(feature = "alloc")]
+use alloc::{               // Allocated types, also available without std
+    vec,
+    vec::Vec
+};
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;   // Float functions from libm
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// Precision used for convergence
 const PRECISION: f64 = 1.0e-12;
 
-/// Stieltjes gamma computation precision
-const STIELTJES_M: usize = 1_000_000;
+/// Maximum number of terms of the continued fractions
+const FRACTION_MAX_ITER: usize = 10_000;
+
+/// Relative change at which the continued fractions converged to the double precision stop
+const FRACTION_TOL: f64 = 4.0 * f64::EPSILON;
+
+/// Maximum number of terms of the power series
+const SERIES_MAX_ITER: usize = 10_000;
+
+/// Maximum number of terms of the hypergeometric series, which converge slowly close to their singularities
+const HYPERGEOMETRIC_MAX_ITER: usize = 100_000;
+
+/// Magnitude above which the confluent hypergeometric series is rescaled, to keep its partial sums finite
+const HYPERGEOMETRIC_RESCALE: f64 = 1.0e200;
+
+/// Maximum number of terms of the Lerch transcendent series, which converges slowly close to the unit circle
+const LERCH_MAX_ITER: usize = 1_000_000;
+
+/// Number of terms summed for the Stieltjes gamma computation, before the Euler-Maclaurin expansion of the tail,
+/// which is kept small as the terms grow like $\ln(m)^{n+1}$ and cancel each other for the higher orders
+const STIELTJES_M: usize = 10;
+
+/// First Stieltjes constants $\gamma_n = \gamma_n(1)$
+const STIELTJES_ONE: [f64; 16] = [
+    0.577_215_664_901_532_9,
+    -0.072_815_845_483_676_73,
+    -0.009_690_363_192_872_32,
+    0.002_053_834_420_303_346,
+    0.002_325_370_065_467_3,
+    0.000_793_323_817_301_062_7,
+    -0.000_238_769_345_430_199_6,
+    -0.000_527_289_567_057_751,
+    -0.000_352_123_353_803_039_5,
+    -3.439_477_441_808_805e-5,
+    0.000_205_332_814_909_064_8,
+    0.000_270_184_439_543_903_5,
+    0.000_167_272_912_105_140_2,
+    -2.746_380_660_376_016e-5,
+    -0.000_209_209_262_059_3,
+    -0.000_283_468_655_320_241_4
+];
+
+/// Even Bernoulli numbers $B_{2k}$, used in the asymptotic expansions
+const BERNOULLI_EVEN: [f64; 8] = [
+    1.0 / 6.0,
+    -1.0 / 30.0,
+    1.0 / 42.0,
+    -1.0 / 30.0,
+    5.0 / 66.0,
+    -691.0 / 2730.0,
+    7.0 / 6.0,
+    -3617.0 / 510.0
+];
+
+/// Number of terms of the accelerated Dirichlet eta series
+const ZETA_TERMS: usize = 30;
+
+/// Largest number of terms of the Hurwitz zeta function summed before its asymptotic expansion
+const ZETA_MAX_SHIFT: f64 = 1.0e6;
+
+/// Modulus below which the error function uses its Taylor series
+const ERF_SERIES_LIM: f64 = 0.5;
+
+/// Modulus above which the polylogarithm is expanded around $z = 1$
+const LI_LOG_LIM: f64 = 0.9;
+
+/// Maximum number of terms of the polylogarithm expansion around $z = 1$
+const LI_LOG_TERMS: usize = 100;
+
+/// Threshold above which the asymptotic expansions are used
+const ASYMPTOTIC_LIM: f64 = 10.0;
+
+/// Largest integer for which the gamma function is read from the factorial, $\Gamma(35) = 34!$ fitting in `u128`
+const GAMMA_INT_MAX: f64 = 35.0;
+
+/// Lanczos approximation parameter $g$
+const LANCZOS_G: f64 = 7.0;
+
+/// Lanczos approximation coefficients, for $g = 7$ and $n = 9$
+const LANCZOS_COEF: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7
+];
+
+/// Limit of the central region of the real error function, approximated directly
+const ERF_CENTRAL_LIM: f64 = 0.468_75;
+
+/// Limit between the middle and tail regions of the real error function, approximated by its complement
+const ERF_TAIL_LIM: f64 = 4.0;
+
+/// Rational approximation coefficients of the error function (central region, numerator)
+const ERF_A: [f64; 5] = [
+    0.185_777_706_184_603_15,
+    3.161_123_743_870_565_5,
+    113.864_154_151_050_16,
+    377.485_237_685_302,
+    3_209.377_589_138_469_4
+];
+
+/// Rational approximation coefficients of the error function (central region, denominator)
+const ERF_B: [f64; 5] = [
+    1.0,
+    23.601_290_952_344_122,
+    244.024_637_934_444_17,
+    1_282.616_526_077_372_3,
+    2_844.236_833_439_171
+];
+
+/// Rational approximation coefficients of the complementary error function (middle region, numerator)
+const ERF_C: [f64; 9] = [
+    2.153_115_354_744_038_3e-8,
+    0.564_188_496_988_670_1,
+    8.883_149_794_388_377,
+    66.119_190_637_141_63,
+    298.635_138_197_400_1,
+    881.952_221_241_769,
+    1_712.047_612_634_070_7,
+    2_051.078_377_826_071_6,
+    1_230.339_354_797_997_2
+];
+
+/// Rational approximation coefficients of the complementary error function (middle region, denominator)
+const ERF_D: [f64; 9] = [
+    1.0,
+    15.744_926_110_709_835,
+    117.693_950_891_312_5,
+    537.181_101_862_009_9,
+    1_621.389_574_566_690_3,
+    3_290.799_235_733_459_7,
+    4_362.619_090_143_247,
+    3_439.367_674_143_721_6,
+    1_230.339_354_803_749_5
+];
+
+/// Rational approximation coefficients of the complementary error function (tail region, numerator)
+const ERF_E: [f64; 6] = [
+    0.016_315_387_137_302_097,
+    0.305_326_634_961_232_36,
+    0.360_344_899_949_804_45,
+    0.125_781_726_111_229_26,
+    0.016_083_785_148_742_275,
+    0.000_658_749_161_529_837_8
+];
+
+/// Rational approximation coefficients of the complementary error function (tail region, denominator)
+const ERF_F: [f64; 6] = [
+    1.0,
+    2.568_520_192_289_822,
+    1.872_952_849_923_467_3,
+    0.527_905_102_951_428_5,
+    0.060_518_341_312_441_32,
+    0.002_335_204_976_268_691_8
+];
+
+/// Rational approximation coefficients of the inverse normal distribution (central region, numerator)
+const ERFINV_A: [f64; 6] = [
+    -3.969_683_028_665_376e1,
+    2.209_460_984_245_205e2,
+    -2.759_285_104_469_687e2,
+    1.383_577_518_672_69e2,
+    -3.066_479_806_614_716e1,
+    2.506_628_277_459_239
+];
+
+/// Rational approximation coefficients of the inverse normal distribution (central region, denominator)
+const ERFINV_B: [f64; 5] = [
+    -5.447_609_879_822_406e1,
+    1.615_858_368_580_409e2,
+    -1.556_989_798_598_866e2,
+    6.680_131_188_771_972e1,
+    -1.328_068_155_288_572e1
+];
+
+/// Rational approximation coefficients of the inverse normal distribution (tail region, numerator)
+const ERFINV_C: [f64; 6] = [
+    -7.784_894_002_430_293e-3,
+    -3.223_964_580_411_365e-1,
+    -2.400_758_277_161_838,
+    -2.549_732_539_343_734,
+    4.374_664_141_464_968,
+    2.938_163_982_698_783
+];
+
+/// Rational approximation coefficients of the inverse normal distribution (tail region, denominator)
+const ERFINV_D: [f64; 4] = [
+    7.784_695_709_041_462e-3,
+    3.224_671_290_700_398e-1,
+    2.445_134_137_142_996,
+    3.754_408_661_907_416
+];
+
+/// Limit between the central and tail regions of the inverse normal distribution
+const ERFINV_LOW: f64 = 0.024_25;
+
+/// Limit under which the Dawson function uses its Taylor series
+const DAWSON_SMALL: f64 = 0.2;
+
+/// Number of terms in the Taylor series of the Dawson function
+const DAWSON_TERMS: usize = 10;
+
+/// Limit above which the Dawson function uses its asymptotic expansion
+const DAWSON_LARGE: f64 = 100.0;
+
+/// Step used in Rybicki's method for the Dawson function
+const DAWSON_H: f64 = 0.2;
+
+/// Distance after which the terms of Rybicki's method are negligible
+const DAWSON_SPAN: f64 = 7.0;
+
+/// Limit between the series and the continued fraction for the Fresnel integrals
+const FRESNEL_LIM: f64 = 1.5;
+
+/// Limit between the series and the asymptotic expansion of the exponential integral
+const EXPINT_LIM: f64 = 40.0;
+
+/// Limit between the series and the continued fraction of the trigonometric integrals
+const SICI_LIM: f64 = 2.0;
+
+/// Maximum number of Halley iterations for the Lambert W function
+const LAMBERT_ITER: usize = 20;
+
+/// Tolerance of the duplication steps of the Carlson symmetric forms
+const CARLSON_TOL: f64 = 8.0e-4;
+
+/// Maximum number of steps of the descending Landen transformation
+const LANDEN_STEPS: usize = 13;
+
+/// Tolerance of the descending Landen transformation
+const LANDEN_TOL: f64 = 1.0e-8;
+
+/// Value of the Airy function $\mathrm{Ai}(0)$
+const AIRY_AI0: f64 = 0.355_028_053_887_817_2;
+
+/// Value of the opposite of the derivative of the Airy function $-\mathrm{Ai}'(0)$
+const AIRY_AIP0: f64 = 0.258_819_403_792_806_8;
+
+/// Limit above which the Airy function $\mathrm{Ai}$ is computed from its integral representation
+const AIRY_SERIES_LIM: f64 = 1.0;
+
+/// Step of the trapezoidal rule for the integral representation of the Airy function
+const AIRY_STEP: f64 = 0.1;
+
+/// Limit below which the Airy functions are computed from their asymptotic expansions
+const AIRY_ASYMPTOTIC_LIM: f64 = 7.0;
+
+/// Smallest value allowed in the continued fractions, to avoid divisions by zero
+const FPMIN: f64 = 1.0e-300;
+
+/// Largest index of the Bernoulli numbers computed from the tangent numbers, which overflow beyond it
+const BERNOULLI_TANGENT_LIM: usize = 186;
+
+/// Length of the rows of the Seidel-Entringer-Arnold triangle, the zigzag numbers overflowing beyond the index 198
+const ZIGZAG_LEN: usize = 199;
+
+/// Largest order $n$ of the closed forms of $Li_{-n}$, whose factorials and Stirling numbers overflow beyond it
+const LI_NEGATIVE_LIM: usize = 169;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -60,6 +333,224 @@ pub fn sinc(x: f64) -> f64 {
     }
 }
 
+/// # Cotangent
+/// 
+/// ## Definition
+/// The [cotangent](https://en.wikipedia.org/wiki/Trigonometric_functions) is defined as:
+/// $$
+/// \cot(x) = \frac{\cos(x)}{\sin(x)}
+/// $$
+/// The pole at $x = 0$ returns an infinite value, signed as the zero. The other poles at $x = k\pi$ only give
+/// large values, as $\pi$ is not exactly representable.
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the function ($x$).
+/// 
+/// Returns the cot of `x`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::cot;
+/// assert!((cot(0.7) * 0.7_f64.tan() - 1.0).abs() < 1.0e-15);
+/// assert!(cot(std::f64::consts::FRAC_PI_2).abs() < 1.0e-16);
+/// 
+/// // Poles at the multiples of pi
+/// assert_eq!(cot(0.0), f64::INFINITY);
+/// assert_eq!(cot(-0.0), f64::NEG_INFINITY);
+/// ```
+pub fn cot(x: f64) -> f64 {
+    x.cos() / x.sin()
+}
+
+/// # Secant
+/// 
+/// ## Definition
+/// The [secant](https://en.wikipedia.org/wiki/Trigonometric_functions) is defined as:
+/// $$
+/// \sec(x) = \frac{1}{\cos(x)}
+/// $$
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the function ($x$).
+/// 
+/// Returns the sec of `x`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::sec;
+/// assert!((sec(1.2) * 1.2_f64.cos() - 1.0).abs() < 1.0e-15);
+/// assert_eq!(sec(0.0), 1.0);
+/// ```
+pub fn sec(x: f64) -> f64 {
+    1.0 / x.cos()
+}
+
+/// # Cosecant
+/// 
+/// ## Definition
+/// The [cosecant](https://en.wikipedia.org/wiki/Trigonometric_functions) is defined as:
+/// $$
+/// \csc(x) = \frac{1}{\sin(x)}
+/// $$
+/// The pole at $x = 0$ returns an infinite value, signed as the zero. The other poles at $x = k\pi$ only give
+/// large values, as $\pi$ is not exactly representable.
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the function ($x$).
+/// 
+/// Returns the csc of `x`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::csc;
+/// assert!((csc(-2.3) * (-2.3_f64).sin() - 1.0).abs() < 1.0e-15);
+/// 
+/// // Poles at the multiples of pi
+/// assert_eq!(csc(0.0), f64::INFINITY);
+/// ```
+pub fn csc(x: f64) -> f64 {
+    1.0 / x.sin()
+}
+
+/// # Hyperbolic cotangent
+/// 
+/// ## Definition
+/// The [hyperbolic cotangent](https://en.wikipedia.org/wiki/Hyperbolic_functions) is defined as:
+/// $$
+/// \coth(x) = \frac{\cosh(x)}{\sinh(x)}
+/// $$
+/// The pole at $x = 0$ returns an infinite value, signed as the zero.
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the function ($x$).
+/// 
+/// Returns the coth of `x`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::coth;
+/// assert!((coth(0.4) * 0.4_f64.tanh() - 1.0).abs() < 1.0e-15);
+/// assert_eq!(coth(800.0), 1.0);
+/// 
+/// // Pole at zero
+/// assert_eq!(coth(0.0), f64::INFINITY);
+/// ```
+pub fn coth(x: f64) -> f64 {
+    1.0 / x.tanh()
+}
+
+/// # Hyperbolic secant
+/// 
+/// ## Definition
+/// The [hyperbolic secant](https://en.wikipedia.org/wiki/Hyperbolic_functions) is defined as:
+/// $$
+/// \mathrm{sech}(x) = \frac{1}{\cosh(x)}
+/// $$
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the function ($x$).
+/// 
+/// Returns the sech of `x`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::sech;
+/// assert!((sech(1.5) * 1.5_f64.cosh() - 1.0).abs() < 1.0e-15);
+/// assert_eq!(sech(0.0), 1.0);
+/// assert_eq!(sech(800.0), 0.0);
+/// ```
+pub fn sech(x: f64) -> f64 {
+    1.0 / x.cosh()
+}
+
+/// # Hyperbolic cosecant
+/// 
+/// ## Definition
+/// The [hyperbolic cosecant](https://en.wikipedia.org/wiki/Hyperbolic_functions) is defined as:
+/// $$
+/// \mathrm{csch}(x) = \frac{1}{\sinh(x)}
+/// $$
+/// The pole at $x = 0$ returns an infinite value, signed as the zero.
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the function ($x$).
+/// 
+/// Returns the csch of `x`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::csch;
+/// assert!((csch(-0.8) * (-0.8_f64).sinh() - 1.0).abs() < 1.0e-15);
+/// 
+/// // Pole at zero
+/// assert_eq!(csch(0.0), f64::INFINITY);
+/// ```
+pub fn csch(x: f64) -> f64 {
+    1.0 / x.sinh()
+}
+
+/// # Gudermannian function
+/// 
+/// ## Definition
+/// The [Gudermannian function](https://en.wikipedia.org/wiki/Gudermannian_function) relates the circular and
+/// hyperbolic functions, and is defined as:
+/// $$
+/// \mathrm{gd}(x) = 2\arctan\left( \tanh\left( \frac{x}{2} \right) \right)
+/// $$
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the function ($x$).
+/// 
+/// Returns the Gudermannian of `x`, in $]-\pi/2, \pi/2[$.
+/// 
+/// ## Example
+/// ```
+/// # use std::f64::consts::FRAC_PI_2;
+/// # use scilib::math::basic::gudermannian;
+/// assert_eq!(gudermannian(0.0), 0.0);
+/// assert!((gudermannian(1.0) - 0.8657694832396586).abs() < 1.0e-15);
+/// assert!((gudermannian(40.0) - FRAC_PI_2).abs() < 1.0e-15);
+/// ```
+pub fn gudermannian(x: f64) -> f64 {
+    2.0 * (x / 2.0).tanh().atan()
+}
+
+/// # Inverse Gudermannian function
+/// 
+/// ## Definition
+/// The inverse of the [Gudermannian function](https://en.wikipedia.org/wiki/Gudermannian_function) is defined
+/// for $|x| < \pi/2$ as:
+/// $$
+/// \mathrm{gd}^{-1}(x) = \ln\left| \tan\left( \frac{x}{2} + \frac{\pi}{4} \right) \right| = \mathrm{artanh}(\sin(x))
+/// $$
+/// It is singular at $x = \pm\pi/2$, where it returns an infinite value. We use the second form, which
+/// is better conditioned.
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the function ($x$).
+/// 
+/// Returns the inverse Gudermannian of `x`.
+/// 
+/// ## Example
+/// ```
+/// # use std::f64::consts::FRAC_PI_2;
+/// # use scilib::math::basic::{ gudermannian, inverse_gudermannian };
+/// assert_eq!(inverse_gudermannian(0.0), 0.0);
+/// assert_eq!(inverse_gudermannian(FRAC_PI_2), f64::INFINITY);
+/// assert_eq!(inverse_gudermannian(-FRAC_PI_2), f64::NEG_INFINITY);
+/// 
+/// // Both functions invert each other, gd flattening for large values
+/// for x in [-3.2, -0.4, 0.01, 1.0, 5.5] {
+///     assert!((inverse_gudermannian(gudermannian(x)) - x).abs() < 1.0e-11);
+/// }
+/// for x in [-1.5, -0.7, 0.2, 1.2] {
+///     assert!((gudermannian(inverse_gudermannian(x)) - x).abs() < 1.0e-15);
+/// }
+/// ```
+pub fn inverse_gudermannian(x: f64) -> f64 {
+    x.sin().atanh()
+}
+
 /// # Binomial theorem
 /// 
 /// ## Definition
@@ -84,6 +575,10 @@ pub fn sinc(x: f64) -> f64 {
 /// # use scilib::math::basic::binomial;
 /// let res: usize = binomial(4_usize, 2_usize);
 /// assert_eq!(res, 6);
+/// 
+/// // Only the smallest of k and n-k is looped over
+/// assert_eq!(binomial(100, 98), binomial(100, 2));
+/// assert_eq!(binomial(100, 98), 4950);
 /// ```
 pub fn binomial(n: usize, k: usize) -> usize {
 
@@ -92,6 +587,7 @@ pub fn binomial(n: usize, k: usize) -> usize {
         return 0;
     }
 
+    let k: usize = k.min(n - k);        // Using the symmetry of the coefficients
     let mut res: usize = 1;             // We initialize the result at 1
     let mut n_d: usize = n;             // We need a mutable value for n
 
@@ -106,484 +602,4449 @@ pub fn binomial(n: usize, k: usize) -> usize {
     res
 }
 
-/// # Factorial function
+/// # Checked binomial coefficient
 /// 
 /// ## Definition
-/// The [factorial function](https://en.wikipedia.org/wiki/Factorial) is defined as:
+/// Computes the [binomial coefficient](https://en.wikipedia.org/wiki/Binomial_coefficient) with the same
+/// multiplicative loop as `binomial`, checking for overflow:
 /// $$
-/// n! = \prod_{i=1}^{n}i
+/// \binom{n}{k} = \frac{n!}{k!(n - k)!}
 /// $$
 /// 
+/// The result is stored as a `u128`. The intermediate products are about $k$ times larger than the result,
+/// so `None` can be returned for a few values close to the limit.
+/// 
 /// ## Inputs
-/// - `n`: the integer at which to evaluate the factorial ($n$).
+/// - `n`: the number of options ($n$) and `k` is the selection ($k$).
 /// 
-/// Returns `n!`, the product of positive integers less or equal to `n`.
+/// Returns `Some(k among n)`, or `None` if the computation overflows.
 /// 
 /// ## Example
 /// ```
-/// # use scilib::math::basic::factorial;
-/// let res: usize = factorial(5_usize);
-/// assert_eq!(res, 120);
+/// # use scilib::math::basic::checked_binomial;
+/// // The intermediate products overflow 64 bits
+/// let res: Option<u128> = checked_binomial(67, 33);
+/// assert_eq!(res, Some(14_226_520_737_620_288_370));
+/// 
+/// assert_eq!(checked_binomial(4, 2), Some(6));
+/// assert_eq!(checked_binomial(2, 4), Some(0));
+/// assert_eq!(checked_binomial(200, 100), None);
 /// ```
-pub fn factorial<T>(n: T) -> usize
-where T: Into<usize> {
-    (1..=n.into()).fold(1, |res, val| res * val)
+pub fn checked_binomial(n: usize, k: usize) -> Option<u128> {
+
+    // n must be greater than k to produce a value
+    if k > n {
+        return Some(0);
+    }
+
+    let k: usize = k.min(n - k);        // Using the symmetry of the coefficients
+    let mut res: u128 = 1;              // We initialize the result at 1
+    let mut n_d: u128 = n as u128;      // We need a mutable value for n
+
+    // We loop the counter up to k possible values
+    for val in 1..=k as u128 {
+        res = res.checked_mul(n_d)?.checked_div(val)?;
+        n_d -= 1;
+    }
+
+    Some(res)
 }
 
-/// # Stieltjes Gamma function
+/// # Generalized binomial coefficient
 /// 
 /// ## Definition
-/// Based on the Stieltjes coefficients, the [Stieltjes gamma function](https://en.wikipedia.org/wiki/Stieltjes_constants)
-/// computes associated values, based on the formula:
+/// The [binomial coefficient](https://en.wikipedia.org/wiki/Binomial_coefficient#Generalization_and_connection_to_the_binomial_series)
+/// can be generalized to any real $\alpha$, using the falling factorial:
 /// $$
-/// \gamma_n(x) = \lim_{m \to \infty} \sum_{k=0}^m \left( \frac{\ln(k+x)^n}{k+x} - \frac{\ln(m+x)^{n+1}}{n+1} \right)
+/// \binom{\alpha}{k} = \frac{\alpha(\alpha-1)\cdots(\alpha-k+1)}{k!}
 /// $$
+/// Which appear in the binomial series $(1+x)^\alpha = \sum_k \binom{\alpha}{k}x^k$.
 /// 
 /// ## Inputs
-/// - `n`: the order of the Stieltjes function to use.
-/// - `a`: the value at which to compute the function.
+/// - `alpha`: the real upper parameter ($\alpha$)
+/// - `k`: the selection ($k$)
 /// 
-/// Returns the value of Gamma_n(a). At the moment, the results are only valid for the first few
-/// orders, as the computation is very expansive. To get the basic Stieltjes coefficient, set `a=1`.
+/// Returns the generalized binomial coefficient.
 /// 
 /// ## Example
 /// ```
-/// # use scilib::math::basic::stieltjes;
-/// let res1 = stieltjes(0, 1.0.into());
-/// assert!((res1.re - 0.577215664).abs() <= 1e-6);
+/// # use scilib::math::basic::{ binomial_real, binomial };
+/// let res: f64 = binomial_real(-0.5, 2);
+/// let int: f64 = binomial_real(10.0, 4);
+/// assert!((res - 0.375).abs() < 1.0e-15);
+/// assert_eq!(int, binomial(10, 4) as f64);
 /// ```
-pub fn stieltjes(n: usize, a: Complex) -> Complex {
-
-    let mut res: Complex = - (a + STIELTJES_M as f64).ln().powi(n as i32 + 1) / (n as f64 + 1.0);
-
-    for k in 0..STIELTJES_M {
-        res += (a + k as f64).ln().powi(n as i32) / (a + k as f64);
-    }
-
-    res
-}
-
-/// # Hurwitz Zeta function
-/// 
-/// WARNING: still under development, results cannot be guarantee.
-pub fn zeta<T, U>(s: T, a: U) -> Complex
-where T: Into<f64>, U: Into<Complex> {
-
-    // Conversions
-    let a_c: Complex = a.into();
-    let s_f: f64 = s.into();
-
-    // If a is negative and even, we use Bernoulli
-    if s_f == 0.0 || (s_f.is_sign_negative() && s_f % 2.0 == 0.0) {
-        let ber: Bernoulli = Bernoulli::new(-s_f as usize + 1);
-        return -ber.compute_complex(a_c) / (-s_f + 1.0);
-    }
-
-    let mut res: Complex = Complex::from(1.0 / (s_f - 1.0), 0.0);
-
-    let mut n: usize = 0;
-    let mut sign: f64 = -1.0;
-    let mut div: f64;
-    let mut term: Complex;
-
-    'convergence: loop {
-
-        if n >= 15 {
-            break 'convergence;
-        }
+pub fn binomial_real(alpha: f64, k: usize) -> f64 {
 
-        sign *= -1.0;
-        div = factorial(n) as f64;
-        term = stieltjes(n, a_c);
-
-        res += sign * term * (s_f - 1.0).powi(n as i32) / div;
+    let mut res: f64 = 1.0;
 
-        n += 1;
+    // Building the falling factorial and the factorial together
+    for val in 0..k {
+        res *= (alpha - val as f64) / (val + 1) as f64;
     }
 
     res
 }
 
-/// # Polylogarithm
+/// # Factorial function
 /// 
 /// ## Definition
-/// The [polylogarithm](https://en.wikipedia.org/wiki/Polylogarithm) is defined by the infinite series:
+/// The [factorial function](https://en.wikipedia.org/wiki/Factorial) is defined as:
 /// $$
-/// Li_s(z) = \sum_{k=1}^\infty \frac{z^k}{k^s}
+/// n! = \prod_{i=1}^{n}i
 /// $$
-/// Because of these properties, the polylogarithm can be computed only for $|z|<= 1$.
 /// 
 /// ## Inputs
-/// - `s`: the power of the divisor ($s$)
-/// - `z` is the computed value ($z$).
+/// - `n`: the integer at which to evaluate the factorial ($n$).
+/// 
+/// Returns `n!`, the product of positive integers less or equal to `n`.
 /// 
-/// Returns the value of the polylogarithm $Li_s(z)$.
+/// The result overflows `usize` for $n > 20$ on 64-bit targets, see `checked_factorial`
+/// for larger values.
 /// 
 /// ## Example
 /// ```
-/// # use scilib::math::complex::Complex;
-/// # use scilib::math::basic::li;
-/// let val: Complex = Complex::from(0.52, -0.55);
-/// let res = li(1.35, val);
-/// assert!((res.re - 0.38167313).abs() <= 1.0e-8);
-/// assert!((res.im - -0.794472094).abs() <= 1.0e-8);
+/// # use scilib::math::basic::factorial;
+/// let res: usize = factorial(5_usize);
+/// assert_eq!(res, 120);
 /// ```
-pub fn li(s: f64, z: Complex) -> Complex {
+pub fn factorial<T>(n: T) -> usize
+where T: Into<usize> {
+    (1..=n.into()).product()
+}
 
-    let mut n: usize = 1;
-    let mut res_z: Complex = z;
-    let mut div: Complex = (1.0_f64).powf(s).into();
+/// # Checked factorial function
+/// 
+/// ## Definition
+/// Computes the [factorial function](https://en.wikipedia.org/wiki/Factorial), checking for overflow:
+/// $$
+/// n! = \prod_{i=1}^{n}i
+/// $$
+/// 
+/// The result is stored as a `u128`, the largest factorial that fits is $34!$.
+/// 
+/// ## Inputs
+/// - `n`: the integer at which to evaluate the factorial ($n$).
+/// 
+/// Returns `Some(n!)`, or `None` if the result overflows.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::checked_factorial;
+/// let res: Option<u128> = checked_factorial(5_usize);
+/// let max: Option<u128> = checked_factorial(34_usize);
+/// let over: Option<u128> = checked_factorial(35_usize);
+/// assert_eq!(res, Some(120));
+/// assert_eq!(max, Some(295_232_799_039_604_140_847_618_609_643_520_000_000));
+/// assert_eq!(over, None);
+/// ```
+pub fn checked_factorial<T>(n: T) -> Option<u128>
+where T: Into<usize> {
+    (1..=n.into() as u128).try_fold(1_u128, |res, val| res.checked_mul(val))
+}
+
+/// # Double factorial function
+/// 
+/// ## Definition
+/// The [double factorial](https://en.wikipedia.org/wiki/Double_factorial) is the product of all the integers
+/// up to $n$ that have the same parity as $n$:
+/// $$
+/// n!! = \prod_{k=0}^{\lceil n/2 \rceil - 1}(n - 2k)
+/// $$
+/// By convention, $0!! = 1$.
+/// 
+/// ## Inputs
+/// - `n`: the integer at which to evaluate the double factorial ($n$).
+/// 
+/// Returns `n!!`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::double_factorial;
+/// assert_eq!(double_factorial(0), 1);
+/// assert_eq!(double_factorial(1), 1);
+/// assert_eq!(double_factorial(7), 105);
+/// assert_eq!(double_factorial(8), 384);
+/// ```
+pub fn double_factorial(n: usize) -> usize {
+    (1..=n).rev().step_by(2).product()
+}
+
+/// # Rising factorial
+/// 
+/// ## Definition
+/// The [rising factorial](https://en.wikipedia.org/wiki/Falling_and_rising_factorials), or Pochhammer symbol,
+/// is defined as:
+/// $$
+/// (x)_n = x(x+1)(x+2)\cdots(x+n-1) = \frac{\Gamma(x+n)}{\Gamma(x)}
+/// $$
+/// By convention, $(x)_0 = 1$.
+/// 
+/// ## Inputs
+/// - `x`: the value to evaluate ($x$)
+/// - `n`: the number of terms in the product ($n$)
+/// 
+/// Returns the rising factorial $(x)_n$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::{ rising_factorial, factorial };
+/// let res: f64 = rising_factorial(1.0, 6);
+/// let half: f64 = rising_factorial(0.5, 3);
+/// assert_eq!(res, factorial(6_usize) as f64);
+/// assert_eq!(half, 1.875);
+/// ```
+pub fn rising_factorial(x: f64, n: usize) -> f64 {
+    (0..n).fold(1.0, |res, k| res * (x + k as f64))
+}
+
+/// # Falling factorial
+/// 
+/// ## Definition
+/// The [falling factorial](https://en.wikipedia.org/wiki/Falling_and_rising_factorials) is defined as:
+/// $$
+/// (x)^{\underline{n}} = x(x-1)(x-2)\cdots(x-n+1) = \frac{\Gamma(x+1)}{\Gamma(x-n+1)}
+/// $$
+/// By convention, $(x)^{\underline{0}} = 1$.
+/// 
+/// ## Inputs
+/// - `x`: the value to evaluate ($x$)
+/// - `n`: the number of terms in the product ($n$)
+/// 
+/// Returns the falling factorial $(x)^{\underline{n}}$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::falling_factorial;
+/// let res: f64 = falling_factorial(5.0, 3);
+/// let half: f64 = falling_factorial(0.5, 3);
+/// assert_eq!(res, 60.0);
+/// assert_eq!(half, 0.375);
+/// ```
+pub fn falling_factorial(x: f64, n: usize) -> f64 {
+    (0..n).fold(1.0, |res, k| res * (x - k as f64))
+}
+
+/// # Greatest common divisor
+/// 
+/// ## Definition
+/// The [greatest common divisor](https://en.wikipedia.org/wiki/Greatest_common_divisor) of two integers is the
+/// largest positive integer dividing both of them. It is computed with the Euclidean algorithm:
+/// $$
+/// \gcd(a, b) = \gcd(b, a \bmod b)
+/// $$
+/// By convention, $\gcd(0, n) = n$.
+/// 
+/// ## Inputs
+/// - `a` and `b`: the integers to evaluate ($a$, $b$)
+/// 
+/// Returns the greatest common divisor of `a` and `b`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::gcd;
+/// assert_eq!(gcd(48, 18), 6);
+/// assert_eq!(gcd(0, 7), 7);
+/// assert_eq!(gcd(17, 5), 1);
+/// ```
+pub fn gcd(a: usize, b: usize) -> usize {
+
+    let mut a_d: usize = a;
+    let mut b_d: usize = b;
+
+    // Euclidean algorithm
+    while b_d != 0 {
+        (a_d, b_d) = (b_d, a_d % b_d);
+    }
+
+    a_d
+}
+
+/// # Least common multiple
+/// 
+/// ## Definition
+/// The [least common multiple](https://en.wikipedia.org/wiki/Least_common_multiple) of two integers is the
+/// smallest positive integer divisible by both of them:
+/// $$
+/// \mathrm{lcm}(a, b) = \frac{a}{\gcd(a, b)}b
+/// $$
+/// The division is performed first to avoid overflowing. By convention, the result is 0 if either number is 0.
+/// 
+/// ## Inputs
+/// - `a` and `b`: the integers to evaluate ($a$, $b$)
+/// 
+/// Returns the least common multiple of `a` and `b`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::lcm;
+/// assert_eq!(lcm(4, 6), 12);
+/// assert_eq!(lcm(0, 6), 0);
+/// assert_eq!(lcm(21, 6), 42);
+/// ```
+pub fn lcm(a: usize, b: usize) -> usize {
+
+    if a == 0 || b == 0 {
+        return 0;
+    }
+
+    a / gcd(a, b) * b
+}
+
+/// # Stieltjes Gamma function
+/// 
+/// ## Definition
+/// Based on the Stieltjes coefficients, the [Stieltjes gamma function](https://en.wikipedia.org/wiki/Stieltjes_constants)
+/// computes associated values, based on the formula:
+/// $$
+/// \gamma_n(x) = \lim_{m \to \infty} \sum_{k=0}^m \left( \frac{\ln(k+x)^n}{k+x} - \frac{\ln(m+x)^{n+1}}{n+1} \right)
+/// $$
+/// 
+/// ## Inputs
+/// - `n`: the order of the Stieltjes function to use.
+/// - `a`: the value at which to compute the function.
+/// 
+/// Returns the value of Gamma_n(a). To get the basic Stieltjes coefficient, set `a=1`: the first 16 of them
+/// are tabulated and returned directly. Otherwise the first ten terms are summed, the rest of the sum being
+/// approximated with the Euler-Maclaurin formula. The precision decreases with the order, from about $10^{-16}$
+/// for $n = 0$ to about $10^{-10}$ for $n = 15$, as the terms of the sum grow like $\ln(m)^n$ and cancel each other.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::stieltjes;
+/// let res1 = stieltjes(0, 1.0.into());
+/// assert!((res1.re - 0.577215664).abs() <= 1e-6);
+/// 
+/// // The tabulated values agree with the computed ones, using gamma_n(1) = gamma_n(2) + ln(1)^n
+/// for n in 0..16 {
+///     let cached = stieltjes(n, 1.0.into());
+///     let computed = stieltjes(n, 2.0.into()) + 0.0_f64.powi(n as i32);
+///     assert!((cached - computed).modulus() < 1.0e-15 * 3.0_f64.powi(n as i32));
+/// }
+/// 
+/// // Computed values
+/// assert!((stieltjes(0, 0.5.into()).re - 1.9635100260214235).abs() < 1.0e-14);
+/// assert!((stieltjes(1, 0.5.into()).re - -1.3534596808049415).abs() < 1.0e-14);
+/// ```
+pub fn stieltjes(n: usize, a: Complex) -> Complex {
+
+    // Tabulated values for the Stieltjes constants
+    if a == Complex::unity() && n < STIELTJES_ONE.len() {
+        return STIELTJES_ONE[n].into();
+    }
+
+    let sum: Complex = (0..STIELTJES_M).fold(Complex::new(), |acc, k| {
+        acc + (a + k as f64).ln().powi(n as i32) / (a + k as f64)
+    });
+
+    sum + stieltjes_tail(n, a)
+}
+
+/// Computes the remainder of the Stieltjes sum from the term `STIELTJES_M` on, minus the $\ln(m+a)^{n+1} / (n+1)$
+/// limit term, with the Euler-Maclaurin formula for $f(x) = \ln(x+a)^n / (x+a)$. The derivatives of $f$ are those
+/// of $(x+a)^{s-1}$, taken $n$ times in $s$ at $s = 0$.
+fn stieltjes_tail(n: usize, a: Complex) -> Complex {
+
+    let y: Complex = a + STIELTJES_M as f64;
+    let ln_y: Complex = y.ln();
+    let mut res: Complex = ln_y.powi(n as i32) / y / 2.0 - ln_y.powi(n as i32 + 1) / (n as f64 + 1.0);
+
+    // Coefficients in s of the falling factorial (s-1)...(s-m), from the m-th derivative of y^(s-1)
+    let mut poly: [f64; 2 * BERNOULLI_EVEN.len() + 1] = [0.0; 2 * BERNOULLI_EVEN.len() + 1];
+    poly[0] = 1.0;
+    let mut y_pow: Complex = y;
+    let mut fact: f64 = 1.0;
+
+    for m in 1..=2 * BERNOULLI_EVEN.len() {
+        for j in (1..=m).rev() {
+            poly[j] = poly[j - 1] - m as f64 * poly[j];
+        }
+        poly[0] *= -(m as f64);
+        y_pow *= y;
+        fact *= (m + 1) as f64;
+
+        // Only the odd derivatives appear in the expansion
+        if m % 2 == 1 {
+            let mut deriv: Complex = Complex::new();
+            let mut falling: f64 = 1.0;
+
+            for (j, c) in poly.iter().enumerate().take(n.min(m) + 1) {
+                deriv += falling * c * ln_y.powi((n - j) as i32);
+                falling *= (n - j) as f64;
+            }
+
+            res -= BERNOULLI_EVEN[m / 2] / fact * deriv / y_pow;
+        }
+    }
+
+    res
+}
+
+/// # Hurwitz Zeta function
+/// 
+/// ## Definition
+/// The [Hurwitz zeta function](https://en.wikipedia.org/wiki/Hurwitz_zeta_function) is defined as:
+/// $$
+/// \zeta(s, a) = \sum_{k=0}^{\infty}\frac{1}{(k+a)^s}
+/// $$
+/// And by analytic continuation for $s < 1$. With $a = 1$, it gives the
+/// [Riemann zeta function](https://en.wikipedia.org/wiki/Riemann_zeta_function).
+/// 
+/// For non-positive integers, we use the Bernoulli polynomials:
+/// $$
+/// \zeta(-n, a) = -\frac{B_{n+1}(a)}{n+1}
+/// $$
+/// Which reduces to the Bernoulli numbers for $a = 1$, $\zeta(-n) = (-1)^n\frac{B_{n+1}}{n+1}$.
+/// For the Riemann zeta function with $s > 0$, we use the alternating Dirichlet eta series, accelerated
+/// with the algorithm of Borwein:
+/// $$
+/// \zeta(s) = \frac{1}{1-2^{1-s}}\sum_{k=1}^{\infty}\frac{(-1)^{k+1}}{k^s}
+/// $$
+/// And the functional equation for $s < 0$:
+/// $$
+/// \zeta(s) = 2^s\pi^{s-1}\sin\left( \frac{\pi s}{2} \right)\Gamma(1-s)\zeta(1-s)
+/// $$
+/// Other values of $a$ are computed with the Euler-Maclaurin formula, after summing directly the terms up to
+/// $\Re(a + k) > 10 + |s|$. For large $s$, this sum stops as soon as the terms are negligible.
+/// 
+/// ## Inputs
+/// - `s`: the power of the divisor ($s$)
+/// - `a`: the shift of the sum ($a$)
+/// 
+/// Returns the value of $\zeta(s, a)$, infinite at the pole $s = 1$. The result is `NaN` for non-finite inputs,
+/// apart from the limit $\zeta(+\infty, 1) = 1$, and when more than a million terms would be summed directly,
+/// for $\Re(a) < -10^6$ or $s < -10^6$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::zeta;
+/// # use std::f64::consts::PI;
+/// assert!((zeta(2.0, 1.0).re - PI.powi(2) / 6.0).abs() < 1.0e-10);
+/// assert!((zeta(4.0, 1.0).re - PI.powi(4) / 90.0).abs() < 1.0e-10);
+/// assert!((zeta(-1.0, 1.0).re - -1.0 / 12.0).abs() < 1.0e-10);
+/// assert!((zeta(-21.0, 1.0).re - -281.46014492753625).abs() < 1.0e-10);
+/// assert!((zeta(0.5, 1.0).re - -1.4603545088095868).abs() < 1.0e-10);
+/// assert!((zeta(-2.5, 1.0).re - 0.008516928777850333).abs() < 1.0e-10);
+/// assert!((zeta(3.0, 0.5).re - 8.41439832211716).abs() < 1.0e-10);
+/// 
+/// // Large and non-finite values
+/// assert!((zeta(60.0, 1.5).re / 2.719721638936565e-11 - 1.0).abs() < 1.0e-12);
+/// assert_eq!(zeta(1.0e15, 0.5).re, f64::INFINITY);
+/// assert_eq!(zeta(f64::INFINITY, 1.0).re, 1.0);
+/// assert!(zeta(f64::INFINITY, 0.5).re.is_nan());
+/// assert!(zeta(2.0, -1.0e15).re.is_nan());
+/// ```
+pub fn zeta<T, U>(s: T, a: U) -> Complex
+where T: Into<f64>, U: Into<Complex> {
+
+    // Conversions
+    let a_c: Complex = a.into();
+    let s_f: f64 = s.into();
+
+    // Non-finite values, only the limit of the Riemann function being defined
+    if !(s_f.is_finite() && a_c.re.is_finite() && a_c.im.is_finite()) {
+        if s_f == f64::INFINITY && a_c == Complex::ONE {
+            return Complex::ONE;
+        }
+        return Complex::from(f64::NAN, f64::NAN);
+    }
+
+    // The pole of the function
+    if s_f == 1.0 {
+        return Complex::from(f64::INFINITY, 0.0);
+    }
+
+    // If s is a non-positive integer, we use Bernoulli
+    if s_f <= 0.0 && s_f.fract() == 0.0 {
+        // The Bernoulli numbers avoid the cancellations of the polynomial
+        if a_c.re == 1.0 && a_c.im == 0.0 {
+            let n: usize = -s_f as usize;
+            return Complex::from((-1.0_f64).powi(n as i32) * bernoulli_number(n + 1) / (n as f64 + 1.0), 0.0);
+        }
+        return -bernoulli_polynomial(-s_f as usize + 1, a_c) / (-s_f + 1.0);
+    }
+
+    // Riemann zeta function
+    if a_c.re == 1.0 && a_c.im == 0.0 {
+        if s_f < 0.0 {
+            let fact: f64 = 2.0_f64.powf(s_f) * PI.powf(s_f - 1.0) * (FRAC_PI_2 * s_f).sin() * gamma(1.0 - s_f);
+            return fact * zeta(1.0 - s_f, 1.0);
+        }
+
+        return riemann_eta(s_f) / (1.0 - 2.0_f64.powf(1.0 - s_f));
+    }
+
+    // The shift would take too many terms
+    if a_c.re < -ZETA_MAX_SHIFT || s_f < -ZETA_MAX_SHIFT {
+        return Complex::from(f64::NAN, f64::NAN);
+    }
+
+    // Shifting the value to ensure the convergence of the series
+    let mut shift: Complex = a_c;
+    let mut res: Complex = Complex::new();
+
+    while shift.re < ASYMPTOTIC_LIM + s_f.abs() {
+        let term: Complex = shift.powf(-s_f);
+
+        // Once the modulus of the shift grows, the remainder is about the size of the term for large s
+        if s_f > 1.0 && shift.re > 0.0 && term.modulus() <= f64::EPSILON * res.modulus() {
+            return res;
+        }
+
+        res += term;
+        shift += 1.0;
+    }
+
+    res + hurwitz_asymptotic(s_f.into(), shift)
+}
+
+/// # Hurwitz Zeta function for complex powers
+/// 
+/// ## Definition
+/// Extends the [Hurwitz zeta function](https://en.wikipedia.org/wiki/Hurwitz_zeta_function) to complex $s$:
+/// $$
+/// \zeta(s, a) = \sum_{k=0}^{\infty}\frac{1}{(k+a)^s}
+/// $$
+/// Real values of $s$ are handed to `zeta`, keeping its Bernoulli closed forms. Otherwise, the first terms
+/// of the sum are computed directly, and the tail with the Euler-Maclaurin formula.
+/// 
+/// The direct sum runs until $\Re(a + k) > 10 + |s|$, so its cost grows linearly with $|s|$: far up the
+/// critical line, $s = 1/2 + 10^6 i$ already needs a million complex powers.
+/// 
+/// ## Inputs
+/// - `s`: the power of the divisor ($s$)
+/// - `a`: the shift of the sum ($a$)
+/// 
+/// Returns the value of $\zeta(s, a)$, infinite at the pole $s = 1$, and `NaN` for a non-finite complex $s$ or $a$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::basic::{ zeta, zeta_complex };
+/// // First non-trivial zero of the Riemann zeta function
+/// let res = zeta_complex(Complex::from(0.5, 14.134725141734693), 1.0);
+/// assert!(res.modulus() < 1.0e-12);
+/// 
+/// let res = zeta_complex(Complex::from(2.0, 1.0), 1.0);
+/// assert!((res - Complex::from(1.1503557032549027, -0.4375308659196079)).modulus() < 1.0e-13);
+/// let res = zeta_complex(Complex::from(-1.5, 3.0), Complex::from(0.3, 0.2));
+/// assert!((res - Complex::from(-0.4477329558661981, -1.3924063810500369)).modulus() < 1.0e-12);
+/// 
+/// // Same as the real function on the real axis
+/// assert_eq!(zeta_complex(-3.0, 0.5), zeta(-3.0, 0.5));
+/// 
+/// assert!(zeta_complex(Complex::from(0.5, f64::INFINITY), 1.0).re.is_nan());
+/// ```
+pub fn zeta_complex<T, U>(s: T, a: U) -> Complex
+where T: Into<Complex>, U: Into<Complex> {
+
+    // Conversions
+    let s_c: Complex = s.into();
+    let a_c: Complex = a.into();
+
+    // The real function handles the pole and the closed forms
+    if s_c.im == 0.0 {
+        return zeta(s_c.re, a_c);
+    }
+
+    // The direct sum would never reach the asymptotic region
+    if !(s_c.re.is_finite() && s_c.im.is_finite() && a_c.re.is_finite() && a_c.im.is_finite()) {
+        return Complex::from(f64::NAN, f64::NAN);
+    }
+
+    // Shifting the value to ensure the convergence of the series
+    let mut shift: Complex = a_c;
+    let mut res: Complex = Complex::new();
+
+    while shift.re < ASYMPTOTIC_LIM + s_c.modulus() {
+        res += shift.powc(-s_c);
+        shift += 1.0;
+    }
+
+    res + hurwitz_asymptotic(s_c, shift)
+}
+
+/// Computes the Dirichlet eta function $\eta(s)$ for $s > 0$, using the acceleration of Borwein.
+fn riemann_eta(s: f64) -> Complex {
+
+    let n: f64 = ZETA_TERMS as f64;
+
+    // Partial sums d_k of the Borwein coefficients
+    let mut d: [f64; ZETA_TERMS + 1] = [0.0; ZETA_TERMS + 1];
+    let mut term: f64 = 1.0;
+    d[0] = term;
+
+    for i in 1..=ZETA_TERMS {
+        let fi: f64 = i as f64;
+        term *= 4.0 * (n + fi - 1.0) * (n - fi + 1.0) / ((2.0 * fi - 1.0) * 2.0 * fi);
+        d[i] = d[i - 1] + term;
+    }
+
+    let res: f64 = (0..ZETA_TERMS).fold(0.0, |res, k| {
+        res + (-1.0_f64).powi(k as i32) * (d[k] - d[ZETA_TERMS]) / ((k + 1) as f64).powf(s)
+    });
+
+    Complex::from(-res / d[ZETA_TERMS], 0.0)
+}
+
+/// Computes the tail of the Hurwitz zeta function $\zeta(s, a)$ for complex $s$ and $a$ using the Euler-Maclaurin formula.
+fn hurwitz_asymptotic(s: Complex, a: Complex) -> Complex {
+
+    let mut res: Complex = a.powc(1.0 - s) / (s - 1.0) + 0.5 * a.powc(-s);
+
+    let a2: Complex = 1.0 / a.powi(2);
+    let mut ap: Complex = a.powc(-s - 1.0); // Power of a for the current term
+    let mut poch: Complex = s;              // Rising factorial of s
+    let mut fact: f64 = 2.0;                // Factorial of 2k
+
+    for (k, b) in BERNOULLI_EVEN.iter().enumerate() {
+        res += b / fact * poch * ap;
+
+        // Preparing the next term
+        let k2: f64 = (2 * k + 2) as f64;
+        poch *= (s + k2 - 1.0) * (s + k2);
+        fact *= (k2 + 1.0) * (k2 + 2.0);
+        ap *= a2;
+    }
+
+    res
+}
+
+/// # Polylogarithm
+/// 
+/// ## Definition
+/// The [polylogarithm](https://en.wikipedia.org/wiki/Polylogarithm) is defined by the infinite series:
+/// $$
+/// Li_s(z) = \sum_{k=1}^\infty \frac{z^k}{k^s}
+/// $$
+/// The series is used for $|z| \leq 1$, through the Lerch transcendent $Li_s(z) = z\Phi(z, s, 1)$, and is
+/// summed until the relative contribution of the terms falls below `f64::EPSILON`.
+/// For non-positive integers, the closed rational forms are used everywhere, with $S$ the Stirling numbers
+/// of the second kind:
+/// $$
+/// Li_{-n}(z) = \sum_{k=0}^{n} k! S(n+1, k+1) \left( \frac{z}{1-z} \right)^{k+1}
+/// $$
+/// For $|z| > 1$, the function is continued with the inversion formula, with $u = \frac{1}{2} + \frac{\ln(-z)}{2\pi i}$.
+/// For positive integers $n$:
+/// $$
+/// Li_n(z) = -\frac{(2\pi i)^n}{n!}B_n(u) - (-1)^n Li_n(1/z)
+/// $$
+/// And otherwise:
+/// $$
+/// Li_s(z) = \frac{\Gamma(1-s)}{(2\pi)^{1-s}}\left( i^{1-s}\zeta(1-s, u) + i^{s-1}\zeta(1-s, 1-u) \right)
+/// $$
+/// On the branch cut $z > 1$, the value is the limit from below the real axis.
+/// 
+/// Close to the unit circle, for $|z| > 0.9$, the series converges too slowly and is replaced by the
+/// expansion in $\mu = \ln(z)$, valid for $|\mu| < 2\pi$:
+/// $$
+/// Li_s(z) = \Gamma(1-s)(-\mu)^{s-1} + \sum_{k=0}^{\infty}\frac{\zeta(s-k)}{k!}\mu^k
+/// $$
+/// Where for a positive integer $n$ the singular terms are replaced by $\frac{\mu^{n-1}}{(n-1)!}\left( H_{n-1} - \ln(-\mu) \right)$.
+/// 
+/// ## Inputs
+/// - `s`: the power of the divisor ($s$)
+/// - `z` is the computed value ($z$).
+/// 
+/// Returns the value of the polylogarithm $Li_s(z)$, and `NaN` for the integers $s < -169$, where the closed
+/// forms overflow.
+/// 
+/// ## Example
+/// ```
+/// # use std::f64::consts::PI;
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::basic::li;
+/// let val: Complex = Complex::from(0.52, -0.55);
+/// let res = li(1.35, val);
+/// assert!((res.re - 0.38167313).abs() <= 1.0e-8);
+/// assert!((res.im - -0.794472094).abs() <= 1.0e-8);
+/// 
+/// // Outside of the unit disk
+/// let z: Complex = Complex::from(2.5, -1.5);
+/// assert!((li(0.0, z) - z / (1.0 - z)).modulus() < 1.0e-14);
+/// assert!((li(-2.0, z) - z * (1.0 + z) / (1.0 - z).powi(3)).modulus() < 1.0e-14);
+/// 
+/// let res = li(2.0, Complex::from(-3.0, 0.5));
+/// assert!((res - Complex::from(-1.94817179165385, 0.230504603210785)).modulus() < 1.0e-8);
+/// let res = li(2.5, Complex::from(-3.0, 0.5));
+/// assert!((res - Complex::from(-2.17111477770742, 0.279394422045974)).modulus() < 1.0e-8);
+/// 
+/// // On the branch cut
+/// let res = li(2.0, 2.0.into());
+/// assert!((res - Complex::from(PI.powi(2) / 4.0, -PI * 2.0_f64.ln())).modulus() < 1.0e-8);
+/// 
+/// // Close to the unit circle
+/// assert!((li(2.0, 1.0.into()).re - PI.powi(2) / 6.0).abs() < 1.0e-12);
+/// assert!((li(2.0, (-1.0).into()).re + PI.powi(2) / 12.0).abs() < 1.0e-12);
+/// assert!((li(1.0, 0.99.into()).re + 0.01_f64.ln()).abs() < 1.0e-12);
+/// let res = li(2.5, Complex::from(0.3, 0.93));
+/// assert!((res - Complex::from(0.13645234583812334, 0.9752313817571241)).modulus() < 1.0e-12);
+/// let res = li(3.0, Complex::from(-0.95, 0.1));
+/// assert!((res - Complex::from(-0.860919384441601, 0.0828901896636414)).modulus() < 1.0e-12);
+/// ```
+pub fn li(s: f64, z: Complex) -> Complex {
+
+    // Closed forms for the non-positive integers
+    if s <= 0.0 && s.fract() == 0.0 {
+        return li_negative_integer(-s as usize, z);
+    }
+
+    // Inversion outside of the unit disk, the branch cut being reached from below
+    if z.modulus() > 1.0 {
+        if z.im == 0.0 {
+            return li_inversion(s, Complex::from(z.re, 0.0)).conjugate();
+        }
+        return li_inversion(s, z);
+    }
+
+    // Expansion around z = 1 close to the unit circle
+    if z.modulus() > LI_LOG_LIM {
+        return li_log_series(s, z);
+    }
+
+    // Direct series, as a special case of the Lerch transcendent
+    z * lerch_phi(z, s, 1.0)
+}
+
+/// Computes the polylogarithm $Li_{-n}(z)$ from its closed rational form.
+fn li_negative_integer(n: usize, z: Complex) -> Complex {
+
+    if n > LI_NEGATIVE_LIM {
+        return Complex::from(f64::NAN, f64::NAN);
+    }
+
+    // Stirling numbers of the second kind S(n+1, k)
+    let mut stirling: [f64; LI_NEGATIVE_LIM + 2] = [0.0; LI_NEGATIVE_LIM + 2];
+    stirling[0] = 1.0;
+    for m in 1..=n + 1 {
+        for k in (1..=m).rev() {
+            stirling[k] = stirling[k - 1] + k as f64 * stirling[k];
+        }
+        stirling[0] = 0.0;
+    }
+
+    let w: Complex = z / (1.0 - z);
+    let mut wk: Complex = w;
+    let mut fact: f64 = 1.0;
+    let mut res: Complex = Complex::new();
+
+    for k in 0..=n {
+        res += fact * stirling[k + 1] * wk;
+        wk *= w;
+        fact *= (k + 1) as f64;
+    }
+
+    res
+}
+
+/// Computes the polylogarithm $Li_s(z)$ close to the unit circle, with the expansion in powers of $\ln(z)$.
+fn li_log_series(s: f64, z: Complex) -> Complex {
+
+    let mu: Complex = z.ln();
+
+    // At z = 1, we directly have the zeta function
+    if mu.modulus() == 0.0 {
+        return zeta(s, 1.0);
+    }
+
+    // Index of the pole of the zeta function, for positive integers
+    let pole: Option<usize> = if s.fract() == 0.0 { Some(s as usize - 1) } else { None };
+
+    let mut res: Complex = match pole {
+        Some(p) => {
+            let fact: f64 = (1..=p).fold(1.0, |acc, k| acc * k as f64);
+            mu.powi(p as i32) / fact * (harmonic(p) - (-mu).ln())
+        },
+        None => gamma(1.0 - s) * (-mu).powf(s - 1.0)
+    };
+
+    let mut mu_k: Complex = Complex::unity();
+    let mut fact: f64 = 1.0;
+    let mut term: Complex;
+
+    for k in 0..LI_LOG_TERMS {
+        if pole != Some(k) {
+            term = zeta(s - k as f64, 1.0) / fact * mu_k;
+            res += term;
+
+            // We exit when convergence reaches the precision, skipping the trivial zeros
+            if term.modulus() != 0.0 && term.modulus() <= f64::EPSILON * res.modulus() {
+                break;
+            }
+        }
+
+        mu_k *= mu;
+        fact *= (k + 1) as f64;
+    }
+
+    res
+}
+
+/// Computes the polylogarithm $Li_s(z)$ for $|z| > 1$ using the inversion formulas.
+fn li_inversion(s: f64, z: Complex) -> Complex {
+
+    let u: Complex = 0.5 + (-z).ln() / Complex::from(0.0, TAU);
+
+    // Positive integers, with the Bernoulli polynomials
+    if s.fract() == 0.0 {
+        let n: usize = s as usize;
+        let fact: f64 = (1..=n).fold(1.0, |acc, k| acc * k as f64);
+        let sign: f64 = (-1.0_f64).powi(n as i32);
+        return -Complex::from(0.0, TAU).powi(n as i32) / fact * bernoulli_polynomial(n, u) - sign * li(s, 1.0 / z);
+    }
+
+    let ip: Complex = Complex::from_polar(FRAC_PI_2 * (1.0 - s), 1.0);
+    gamma(1.0 - s) / TAU.powf(1.0 - s) * (ip * zeta(1.0 - s, u) + ip.conjugate() * zeta(1.0 - s, 1.0 - u))
+}
+
+/// # Lerch transcendent
+/// 
+/// ## Definition
+/// The [Lerch transcendent](https://en.wikipedia.org/wiki/Lerch_zeta_function) generalizes both the polylogarithm
+/// and the Hurwitz zeta function:
+/// $$
+/// \Phi(z, s, a) = \sum_{k=0}^{\infty}\frac{z^k}{(k+a)^s}
+/// $$
+/// With $Li_s(z) = z\Phi(z, s, 1)$ and $\zeta(s, a) = \Phi(1, s, a)$.
+/// 
+/// The series is summed until the relative contribution of the terms is below the `f64` precision, and is only
+/// convergent for $|z| < 1$. The point $z = 1$ is handed to `zeta`. The sum stops after a million terms, which
+/// limits the precision for $|z|$ within about $10^{-5}$ of $1$.
+/// 
+/// ## Inputs
+/// - `z`: the base of the power ($z$), with $|z| < 1$ or $z = 1$
+/// - `s`: the power of the divisor ($s$)
+/// - `a`: the shift of the divisor ($a$), with $a > 0$
+/// 
+/// Returns the value of $\Phi(z, s, a)$, or `NaN` outside of the disk of convergence or for `NaN` inputs.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::basic::{ lerch_phi, li, zeta };
+/// let res = lerch_phi(Complex::from(0.3, -0.4), 2.5, 0.7);
+/// assert!((res - Complex::from(2.507588842436404, -0.12669962901741319)).modulus() < 1.0e-12);
+/// 
+/// // ln(1 + x) / x
+/// assert!((lerch_phi(Complex::from(-0.5, 0.0), 1.0, 1.0).re - 2.0 * 1.5_f64.ln()).abs() < 1.0e-12);
+/// 
+/// // Special cases
+/// let z = Complex::from(0.6, 0.2);
+/// assert!((lerch_phi(z, 1.35, 1.0) - li(1.35, z) / z).modulus() < 1.0e-15);
+/// assert_eq!(lerch_phi(Complex::unity(), 3.0, 0.5), zeta(3.0, 0.5));
+/// 
+/// // Out of the domain
+/// assert!(lerch_phi(Complex::from(1.5, 0.0), 2.0, 1.0).re.is_nan());
+/// assert!(lerch_phi(Complex::from(0.5, 0.0), f64::NAN, 1.0).re.is_nan());
+/// assert!(li(2.0, Complex::from(f64::NAN, 0.0)).re.is_nan());
+/// assert!(li(f64::NAN, Complex::from(0.5, 0.0)).re.is_nan());
+/// ```
+pub fn lerch_phi(z: Complex, s: f64, a: f64) -> Complex {
+
+    if s.is_nan() || a.is_nan() || z.modulus().is_nan() {
+        return Complex::from(f64::NAN, f64::NAN);
+    } else if z == Complex::unity() {
+        return zeta(s, a);
+    } else if z.modulus() >= 1.0 {
+        // Divergent series
+        return Complex::from(f64::NAN, f64::NAN);
+    }
+
+    let mut zk: Complex = Complex::unity();     // Power of z
+    let mut term: Complex = a.powf(-s).into();  // Term at each iter
+    let mut res: Complex = Complex::new();      // Result
+
+    for k in 1..=LERCH_MAX_ITER {
+        res += term;
+
+        // We exit when the terms no longer change the result
+        if term.modulus() <= f64::EPSILON * res.modulus() {
+            break;
+        }
+
+        zk *= z;
+        term = zk * (k as f64 + a).powf(-s);
+    }
+
+    res
+}
+
+/// # Clausen function
+/// 
+/// ## Definition
+/// The [Clausen function](https://en.wikipedia.org/wiki/Clausen_function) is defined by the series:
+/// $$
+/// \mathrm{Cl}_2(\theta) = \sum_{k=1}^{\infty}\frac{\sin(k\theta)}{k^2} = \Im\left( Li_2(e^{i\theta}) \right)
+/// $$
+/// It is odd and $2\pi$-periodic, and reaches its maximum at $\theta = \pi/3$. The angle is reduced to
+/// $]-\pi, \pi]$, and the dilogarithm evaluated on the unit circle with its logarithmic expansion.
+/// 
+/// ## Inputs
+/// - `theta`: the angle ($\theta$)
+/// 
+/// Returns the value of $\mathrm{Cl}_2(\theta)$.
+/// 
+/// ## Example
+/// ```
+/// # use std::f64::consts::{ PI, TAU };
+/// # use scilib::math::basic::clausen;
+/// // Maximum value, the Gieseking constant times 3/2
+/// assert!((clausen(PI / 3.0) - 1.0149416064096536).abs() < 1.0e-12);
+/// assert!((clausen(2.5) - 0.4335982032355328).abs() < 1.0e-12);
+/// assert!((clausen(0.01) - 0.05605171574877675).abs() < 1.0e-12);
+/// 
+/// // Odd and periodic
+/// assert_eq!(clausen(0.0), 0.0);
+/// assert_eq!(clausen(PI), 0.0);
+/// assert!((clausen(-1.0) + clausen(1.0)).abs() < 1.0e-15);
+/// assert!((clausen(1.0 + TAU) - clausen(1.0)).abs() < 1.0e-12);
+/// assert!(clausen(f64::NAN).is_nan());
+/// ```
+pub fn clausen(theta: f64) -> f64 {
+
+    // Reduction to ]-pi, pi]
+    let t: f64 = PI - (PI - theta).rem_euclid(TAU);
+
+    // The function cancels on the multiples of pi
+    if t == 0.0 || t == PI {
+        return 0.0;
+    }
+
+    li(2.0, Complex::from_polar(t, 1.0)).im
+}
+
+/// # Gamma function
+/// 
+/// ## Definition
+/// The [gamma function](https://en.wikipedia.org/wiki/Gamma_function) is a generalization of the factorial, and is defined as:
+/// $$
+/// \Gamma(z) = \int_{0}^{\infty}x^{z-1}\exp(-x)dx
+/// $$
+/// 
+/// This function provides result for any real number, and returns the same result for integer as a factorial:
+/// $$
+/// \Gamma(n) = (n-1)!
+/// $$
+/// 
+/// We use the [Lanczos approximation](https://en.wikipedia.org/wiki/Lanczos_approximation), which reaches
+/// a relative precision better than `1.0e-13`:
+/// $$
+/// \Gamma(x+1) = \sqrt{2\pi}\left( x+g+\frac{1}{2} \right)^{x+1/2}\exp\left( -x-g-\frac{1}{2} \right)A_g(x)
+/// $$
+/// And the reflection formula for $x < 1/2$:
+/// $$
+/// \Gamma(1-x)\Gamma(x) = \frac{\pi}{\sin(\pi x)}
+/// $$
+/// Positive integers up to $35$ are read from the exact factorial instead.
+/// 
+/// ## Inputs
+/// - `x`: the value to evaluate ($x$).
+/// 
+/// Returns the value of the gamma function, and `NaN` at its poles, the non-positive integers.
+/// For complex values, or generic code, see the `Gamma` trait.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::{ gamma, factorial };
+/// let res_1: f64 = gamma(2.3);
+/// let res_2: f64 = gamma(-0.45);
+/// let res_3: f64 = gamma(170.5);
+/// assert!((res_1 - 1.16671190519816).abs() < 1.0e-13);
+/// assert!((res_2 - -3.591387263852389).abs() < 1.0e-13);
+/// assert!((res_3 / 5.56209241456e305 - 1.0).abs() < 1.0e-11);
+/// assert_eq!(gamma(5), 24.0);
+/// 
+/// // Integers are exact, near-integers still use the approximation
+/// for n in 1..=20_usize {
+///     assert_eq!(gamma(n as f64 + 1.0), factorial(n) as f64);
+/// }
+/// assert_eq!(gamma(30), 8.841761993739701954543616e30);
+/// assert!((gamma(4.9999) - 23.99638561634390).abs() < 1.0e-12);
+/// 
+/// // Poles of the function
+/// assert!(gamma(0.0).is_nan());
+/// assert!(gamma(-1.0).is_nan());
+/// assert!(gamma(-1.0e20).is_nan());
+/// ```
+pub fn gamma<T>(value: T) -> f64
+where T: Into<f64> {
+
+    let x: f64 = value.into();
+
+    // No value at the poles, where the limits from each side have opposite signs
+    if x <= 0.0 && x.fract() == 0.0 {
+        return f64::NAN;
+    }
+
+    // Exact values for the small positive integers
+    if (1.0..=GAMMA_INT_MAX).contains(&x) && x.fract() == 0.0 {
+        if let Some(f) = checked_factorial(x as usize - 1) {
+            return f as f64;
+        }
+    }
+
+    // Reflection formula for the lower values
+    if x < 0.5 {
+        return PI / ((PI * x).sin() * gamma(1.0 - x));
+    }
+
+    let xm: f64 = x - 1.0;
+    let t: f64 = xm + LANCZOS_G + 0.5;
+
+    // The power is split in two to push back the overflow
+    let half_pow: f64 = t.powf(0.5 * (xm + 0.5));
+
+    TAU.sqrt() * half_pow * (half_pow * (-t).exp()) * lanczos_sum(xm)
+}
+
+/// # Lanczos series
+/// 
+/// Computes the series $A_g(x)$ of the Lanczos approximation.
+fn lanczos_sum(x: f64) -> f64 {
+    LANCZOS_COEF.iter().enumerate().skip(1).fold(LANCZOS_COEF[0], |res, (i, c)| res + c / (x + i as f64))
+}
+
+/// # Gamma trait
+/// 
+/// Provides the [gamma function](https://en.wikipedia.org/wiki/Gamma_function) for both real and complex values,
+/// allowing generic code over either type. The real implementation calls `gamma`, and the complex one uses the
+/// same Lanczos approximation and reflection formula, so that both agree on the real axis.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::Gamma;
+/// # use scilib::math::complex::Complex;
+/// // Generic over the type, computing x! = Gamma(x + 1)
+/// fn fact<T: Gamma + std::ops::Add<f64, Output = T>>(x: T) -> T {
+///     (x + 1.0).gamma()
+/// }
+/// 
+/// assert!((fact(4.0) - 24.0).abs() < 1.0e-12);
+/// assert!((fact(Complex::from(4.0, 0.0)) - 24.0).modulus() < 1.0e-12);
+/// 
+/// // Both implementations agree on the real axis
+/// for x in [0.3, 2.7, -1.4, 12.5] {
+///     let c = Gamma::gamma(&Complex::from(x, 0.0));
+///     let r = Gamma::gamma(&x);
+///     assert!((c.re / r - 1.0).abs() < 1.0e-13);
+///     assert_eq!(c.im, 0.0);
+/// }
+/// 
+/// let res = Gamma::gamma(&Complex::from(1.0, 1.0));
+/// assert!((res - Complex::from(0.498015668118356, -0.154949828301811)).modulus() < 1.0e-13);
+/// let res = Gamma::gamma(&Complex::from(-2.5, 0.7));
+/// assert!((res - Complex::from(-0.159818716362933, -0.157566549081515)).modulus() < 1.0e-13);
+/// ```
+pub trait Gamma {
+    /// Returns the value of the gamma function.
+    fn gamma(&self) -> Self;
+}
+
+impl Gamma for f64 {
+    fn gamma(&self) -> Self {
+        gamma(*self)
+    }
+}
+
+impl Gamma for Complex {
+    fn gamma(&self) -> Self {
+
+        // Reflection formula for the lower values
+        if self.re < 0.5 {
+            return PI / ((PI * *self).sin() * (1.0 - *self).gamma());
+        }
+
+        let zm: Self = *self - 1.0;
+        let t: Self = zm + LANCZOS_G + 0.5;
+        let sum: Self = LANCZOS_COEF.iter().enumerate().skip(1).fold(LANCZOS_COEF[0].into(), |res, (i, c)| {
+            res + *c / (zm + i as f64)
+        });
+
+        TAU.sqrt() * ((zm + 0.5) * t.ln() - t).exp() * sum
+    }
+}
+
+/// # Logarithm of the gamma function
+/// 
+/// ## Definition
+/// Computes the natural logarithm of the absolute value of the [gamma function](https://en.wikipedia.org/wiki/Gamma_function):
+/// $$
+/// \ln|\Gamma(x)|
+/// $$
+/// 
+/// The gamma function overflows `f64` for $x > 171.6$, while its logarithm can be computed for much larger values.
+/// We use the [Lanczos approximation](https://en.wikipedia.org/wiki/Lanczos_approximation):
+/// $$
+/// \Gamma(x+1) = \sqrt{2\pi}\left( x+g+\frac{1}{2} \right)^{x+1/2}\exp\left( -x-g-\frac{1}{2} \right)A_g(x)
+/// $$
+/// And the reflection formula for $x < 1/2$:
+/// $$
+/// \Gamma(1-x)\Gamma(x) = \frac{\pi}{\sin(\pi x)}
+/// $$
+/// 
+/// ## Inputs
+/// - `x`: the value to evaluate ($x$)
+/// 
+/// Returns the value of $\ln|\Gamma(x)|$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::ln_gamma;
+/// let res_1: f64 = ln_gamma(171);
+/// let res_2: f64 = ln_gamma(0.5);
+/// let res_3: f64 = ln_gamma(-2.5);
+/// assert!((res_1 - 706.5730622457874).abs() < 1.0e-10);
+/// assert!((res_2 - 0.5723649429247004).abs() < 1.0e-14);
+/// assert!((res_3 - -0.05624371649767457).abs() < 1.0e-14);
+/// ```
+pub fn ln_gamma<T>(value: T) -> f64
+where T: Into<f64> {
+
+    let x: f64 = value.into();
+
+    // Reflection formula for the lower values
+    if x < 0.5 {
+        return (PI / (PI * x).sin().abs()).ln() - ln_gamma(1.0 - x);
+    }
+
+    let xm: f64 = x - 1.0;
+    let t: f64 = xm + LANCZOS_G + 0.5;
+
+    0.5 * TAU.ln() + (xm + 0.5) * t.ln() - t + lanczos_sum(xm).ln()
+}
+
+/// # Sign of the gamma function
+/// 
+/// The gamma function is positive for $x > 0$, and alternates sign between each negative integer.
+fn gamma_sign(x: f64) -> f64 {
+    if x > 0.0 || (x.floor() as i64) % 2 == 0 {
+        1.0
+    } else {
+        -1.0
+    }
+}
+
+/// # Euler Beta function
+/// 
+/// ## Definition
+/// The [beta function](https://en.wikipedia.org/wiki/Beta_function) is an integral similar to the gamma function,
+/// and it is defined as:
+/// $$
+/// B(x,y) = B(y,x) = \int_{0}^{1}t^{x-1}(1-t)^{y-1}dt
+/// $$
+/// 
+/// The current implementation relies on the relation:
+/// $$
+/// B(x,y) = \frac{\Gamma(x)\Gamma(y)}{\Gamma(x+y)}
+/// $$
+/// Which is computed through the logarithm of the gamma function, to avoid overflowing for large inputs.
+/// 
+/// ## Inputs
+/// - `x` and `y` are the points at which to evaluate the function ($x$, $y$).
+/// 
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::beta;
+/// let res: f64 = beta(1, 1.1);
+/// let comp1: f64 = beta(3, 2);
+/// let comp2: f64 = beta(2, 3);
+/// let neg: f64 = beta(-1.2, 2.5);
+/// let large: f64 = beta(200, 150.5);
+/// assert!((res - 0.909090).abs() < 1.0e-5);
+/// assert!((neg - 7.185274432649909).abs() < 1.0e-12);
+/// assert!(large > 0.0 && large.is_finite());
+/// assert_eq!(comp1, comp2);
+/// ```
+pub fn beta<T, U>(x: T, y: U) -> f64
+where T: Into<f64>, U: Into<f64> {
+
+    let x_f: f64 = x.into();
+    let y_f: f64 = y.into();
+
+    // The logarithm loses the sign, we track it separately
+    let sign: f64 = gamma_sign(x_f) * gamma_sign(y_f) * gamma_sign(x_f + y_f);
+
+    sign * (ln_gamma(x_f) + ln_gamma(y_f) - ln_gamma(x_f + y_f)).exp()
+}
+
+/// # Regularized incomplete beta function
+/// 
+/// ## Definition
+/// The [regularized incomplete beta function](https://en.wikipedia.org/wiki/Beta_function#Incomplete_beta_function)
+/// is defined as:
+/// $$
+/// I_x(a,b) = \frac{B(x;a,b)}{B(a,b)} = \frac{1}{B(a,b)}\int_{0}^{x}t^{a-1}(1-t)^{b-1}dt
+/// $$
+/// 
+/// The computation relies on the continued fraction representation, evaluated with the modified Lentz method:
+/// $$
+/// I_x(a,b) = \frac{x^a(1-x)^b}{aB(a,b)}\left( \frac{1}{1+}\frac{d_1}{1+}\frac{d_2}{1+}\cdots \right)
+/// $$
+/// For $x > (a+1)/(a+b+2)$, the symmetry relation $I_x(a,b) = 1 - I_{1-x}(b,a)$ is used to ensure
+/// the convergence of the fraction.
+/// 
+/// ## Inputs
+/// - `x`: the upper bound of the integral ($0 \le x \le 1$)
+/// - `a` and `b`: the parameters of the function ($a > 0$, $b > 0$)
+/// 
+/// Returns the value of $I_x(a,b)$, or `NaN` if the inputs are out of the domain, `NaN` or infinite.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::beta_inc_reg;
+/// let sym: f64 = beta_inc_reg(0.5, 2.0, 2.0);
+/// let res: f64 = beta_inc_reg(0.3, 2.0, 5.0);
+/// assert!((sym - 0.5).abs() < 1.0e-12);
+/// assert!((res - 0.579825).abs() < 1.0e-12);
+/// assert_eq!(beta_inc_reg(0.0, 1.5, 3.2), 0.0);
+/// assert_eq!(beta_inc_reg(1.0, 1.5, 3.2), 1.0);
+/// assert!(beta_inc_reg(0.3, f64::NAN, 2.0).is_nan());
+/// assert!(beta_inc_reg(0.3, f64::INFINITY, 2.0).is_nan());
+/// assert!(beta_inc_reg(f64::NAN, 1.0, 2.0).is_nan());
+/// ```
+pub fn beta_inc_reg(x: f64, a: f64, b: f64) -> f64 {
+
+    // Checking the domain of definition, NaN failing every comparison
+    if !((0.0..=1.0).contains(&x) && a > 0.0 && b > 0.0 && a.is_finite() && b.is_finite()) {
+        return f64::NAN;
+    }
+
+    if x == 0.0 {
+        return 0.0;
+    } else if x == 1.0 {
+        return 1.0;
+    }
+
+    // Using the symmetry relation to ensure the convergence
+    if x > (a + 1.0) / (a + b + 2.0) {
+        return 1.0 - beta_inc_reg(1.0 - x, b, a);
+    }
+
+    let ln_beta: f64 = ln_gamma(a) + ln_gamma(b) - ln_gamma(a + b);
+    let front: f64 = (a * x.ln() + b * (1.0 - x).ln() - ln_beta).exp() / a;
+
+    front * beta_inc_fraction(x, a, b)
+}
+
+/// # Continued fraction of $I_x(a,b)$
+/// 
+/// Evaluated with the modified Lentz method, converges quickly for $x < (a+1)/(a+b+2)$.
+fn beta_inc_fraction(x: f64, a: f64, b: f64) -> f64 {
+
+    let mut c: f64 = 1.0;
+    let mut d: f64 = 1.0 - (a + b) * x / (a + 1.0);
+    if d.abs() < FPMIN {
+        d = FPMIN;
+    }
+    d = 1.0 / d;
+    let mut res: f64 = d;
+
+    let mut m: f64;
+    let mut m2: f64;
+    let mut an: f64;
+    let mut delta: f64;
+
+    for i in 1..=FRACTION_MAX_ITER {
+        m = i as f64;
+        m2 = 2.0 * m;
+
+        // Even step of the recurrence
+        an = m * (b - m) * x / ((a + m2 - 1.0) * (a + m2));
+        d = 1.0 + an * d;
+        if d.abs() < FPMIN {
+            d = FPMIN;
+        }
+        c = 1.0 + an / c;
+        if c.abs() < FPMIN {
+            c = FPMIN;
+        }
+        d = 1.0 / d;
+        res *= d * c;
+
+        // Odd step of the recurrence
+        an = -(a + m) * (a + b + m) * x / ((a + m2) * (a + m2 + 1.0));
+        d = 1.0 + an * d;
+        if d.abs() < FPMIN {
+            d = FPMIN;
+        }
+        c = 1.0 + an / c;
+        if c.abs() < FPMIN {
+            c = FPMIN;
+        }
+        d = 1.0 / d;
+        delta = d * c;
+        res *= delta;
+
+        // We exit when convergence reaches the precision
+        if (delta - 1.0).abs() < PRECISION {
+            break;
+        }
+    }
+
+    res
+}
+
+/// # Digamma function
+/// 
+/// ## Definition
+/// The [digamma function](https://en.wikipedia.org/wiki/Digamma_function) is the logarithmic derivative
+/// of the gamma function:
+/// $$
+/// \psi(x) = \frac{d}{dx}\ln(\Gamma(x)) = \frac{\Gamma^\prime(x)}{\Gamma(x)}
+/// $$
+/// 
+/// We use the recurrence relation $\psi(x) = \psi(x+1) - 1/x$ to shift the argument to a large enough value,
+/// and then use the asymptotic expansion:
+/// $$
+/// \psi(x) \sim \ln(x) - \frac{1}{2x} - \sum_{k=1}^{\infty}\frac{B_{2k}}{2kx^{2k}}
+/// $$
+/// Negative values are first brought to positive ones with the reflection formula:
+/// $$
+/// \psi(1-x) = \psi(x) + \pi\cot(\pi x)
+/// $$
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the function ($x$)
+/// 
+/// Returns the value of the digamma function, or `NaN` on its poles (the non-positive integers),
+/// at $-\infty$ and for a `NaN` input.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::digamma;
+/// # use scilib::constant::EULER_MASCHERONI;
+/// let res_1: f64 = digamma(1);
+/// let res_2: f64 = digamma(-0.5);
+/// assert!((res_1 - -EULER_MASCHERONI).abs() < 1.0e-14);
+/// assert!((res_2 - 0.03648997397857652).abs() < 1.0e-13);
+/// assert!((digamma(-2.7) - -1.1153471291406896).abs() < 1.0e-13);
+/// assert!((digamma(-1.0e12 - 0.5) - 27.63102111592955).abs() < 1.0e-13);
+/// 
+/// assert!(digamma(-2.0).is_nan());
+/// assert_eq!(digamma(f64::INFINITY), f64::INFINITY);
+/// assert!(digamma(f64::NEG_INFINITY).is_nan());
+/// ```
+pub fn digamma<T>(value: T) -> f64
+where T: Into<f64> {
+
+    let mut x: f64 = value.into();
+
+    if x.is_nan() || x == f64::NEG_INFINITY || (x <= 0.0 && x.fract() == 0.0) {
+        return f64::NAN;
+    } else if x == f64::INFINITY {
+        return f64::INFINITY;
+    } else if x < 0.0 {
+        // Reflection formula, the cotangent being evaluated on the exact fractional part
+        return digamma(1.0 - x) - PI * cot(PI * (x - x.floor()));
+    }
+
+    let mut res: f64 = 0.0;
+
+    // Shifting the value with the recurrence relation
+    while x < ASYMPTOTIC_LIM {
+        res -= 1.0 / x;
+        x += 1.0;
+    }
+
+    // Asymptotic expansion
+    res += x.ln() - 0.5 / x;
+
+    let x2: f64 = x.powi(-2);
+    let mut xp: f64 = 1.0;
+    for (k, b) in BERNOULLI_EVEN.iter().enumerate() {
+        xp *= x2;
+        res -= b * xp / (2 * k + 2) as f64;
+    }
+
+    res
+}
+
+/// # Polygamma function
+/// 
+/// ## Definition
+/// The [polygamma function](https://en.wikipedia.org/wiki/Polygamma_function) of order $n$ is the $n^{th}$
+/// derivative of the digamma function:
+/// $$
+/// \psi^{(n)}(x) = \frac{d^n}{dx^n}\psi(x) = \frac{d^{n+1}}{dx^{n+1}}\ln(\Gamma(x))
+/// $$
+/// 
+/// For $n \ge 1$, it is computed using its relation to the Hurwitz zeta function:
+/// $$
+/// \psi^{(n)}(x) = (-1)^{n+1}n!\zeta(n+1, x)
+/// $$
+/// When $n = 0$, the function falls back on the digamma function. Negative values are brought back to their
+/// fractional part $f = x + m$ with the recurrence relation, whose $m$ terms sum to a difference of zeta functions:
+/// $$
+/// \psi^{(n)}(x) = \psi^{(n)}(f) + n!\left( \zeta(n+1, 1-f) - \zeta(n+1, m+1-f) \right)
+/// $$
+/// 
+/// ## Inputs
+/// - `n`: the order of the derivative ($n$)
+/// - `x`: the value at which to evaluate the function ($x$)
+/// 
+/// Returns the value of the polygamma function of order `n`, $0$ at $\infty$, and `NaN` at its poles, the non-positive
+/// integers, at $-\infty$ or for a `NaN` input.
+/// 
+/// ## Example
+/// ```
+/// # use std::f64::consts::PI;
+/// # use scilib::math::basic::{ polygamma, digamma };
+/// let trigamma: f64 = polygamma(1, 1.0);
+/// let tetragamma: f64 = polygamma(2, 2.5);
+/// assert!((trigamma - PI.powi(2) / 6.0).abs() < 1.0e-14);
+/// assert!((tetragamma - -0.23620405164172739).abs() < 1.0e-14);
+/// assert_eq!(polygamma(0, 3.2), digamma(3.2));
+/// assert_eq!(polygamma(1, f64::INFINITY), 0.0);
+/// assert!(polygamma(1, f64::NEG_INFINITY).is_nan());
+/// 
+/// // Negative values, with the same poles as the digamma function
+/// assert!((polygamma(1, -2.5) - 9.5392466449891238).abs() < 1.0e-13);
+/// assert!((polygamma(2, -0.3) - 67.63908119988316).abs() < 1.0e-12);
+/// assert!((polygamma(1, -1.0e12 - 0.5) - 9.8696044010883586).abs() < 1.0e-10);
+/// assert!(polygamma(1, -2.0).is_nan() && digamma(-2.0).is_nan());
+/// ```
+pub fn polygamma(n: usize, x: f64) -> f64 {
+
+    // The zeroth order is the digamma function
+    if n == 0 {
+        return digamma(x);
+    } else if x.is_nan() || x == f64::NEG_INFINITY || (x <= 0.0 && x.fract() == 0.0) {
+        return f64::NAN;
+    } else if x == f64::INFINITY {
+        return 0.0;
+    }
+
+    let s: i32 = n as i32 + 1;
+    let fact: f64 = (1..=n).fold(1.0, |res, val| res * val as f64);
+
+    // Recurrence down from the fractional part, without summing its terms one by one
+    if x < 0.0 {
+        let m: f64 = -x.floor();
+        let f: f64 = x + m;
+        return polygamma(n, f) + fact * (hurwitz_integer(s, 1.0 - f) - hurwitz_integer(s, m + 1.0 - f));
+    }
+
+    let sign: f64 = (-1.0_f64).powi(s);
+
+    sign * fact * hurwitz_integer(s, x)
+}
+
+/// Computes the Hurwitz zeta function $\zeta(s, a)$ for an integer $s \ge 2$ and $a > 0$.
+fn hurwitz_integer(s: i32, a: f64) -> f64 {
+
+    let mut shift: f64 = a;
+    let mut res: f64 = 0.0;
+
+    // Shifting the value to ensure the convergence of the series
+    while shift < ASYMPTOTIC_LIM + s as f64 {
+        res += shift.powi(-s);
+        shift += 1.0;
+    }
+
+    res + hurwitz_asymptotic(Complex::from(s as f64, 0.0), Complex::from(shift, 0.0)).re
+}
+
+/// # Regularized lower incomplete gamma function
+/// 
+/// ## Definition
+/// The [regularized lower incomplete gamma function](https://en.wikipedia.org/wiki/Incomplete_gamma_function)
+/// is defined as:
+/// $$
+/// P(s,x) = \frac{\gamma(s,x)}{\Gamma(s)} = \frac{1}{\Gamma(s)}\int_{0}^{x}t^{s-1}\exp(-t)dt
+/// $$
+/// 
+/// For $x < s + 1$ we use the series expansion:
+/// $$
+/// P(s,x) = x^s\exp(-x)\sum_{k=0}^{\infty}\frac{x^k}{\Gamma(s+k+1)}
+/// $$
+/// Otherwise, we compute $1 - Q(s,x)$ with the continued fraction of $Q$, which converges faster.
+/// 
+/// ## Inputs
+/// - `s`: the parameter of the function ($s > 0$, finite)
+/// - `x`: the upper bound of the integral ($x \ge 0$)
+/// 
+/// Returns the value of $P(s,x)$, or `NaN` if the inputs are out of the domain.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::gamma_reg_p;
+/// let res_0: f64 = gamma_reg_p(2.5, 0.0);
+/// let res_inf: f64 = gamma_reg_p(2.5, f64::INFINITY);
+/// let res: f64 = gamma_reg_p(2.0, 1.5);
+/// assert_eq!(res_0, 0.0);
+/// assert_eq!(res_inf, 1.0);
+/// assert!((res - 0.4421745996289254).abs() < 1.0e-12);
+/// assert!(gamma_reg_p(f64::INFINITY, 1.0).is_nan());
+/// ```
+pub fn gamma_reg_p(s: f64, x: f64) -> f64 {
+
+    // Checking the domain of definition
+    if s <= 0.0 || !s.is_finite() || x < 0.0 || x.is_nan() {
+        return f64::NAN;
+    }
+
+    if x == 0.0 {
+        0.0
+    } else if x.is_infinite() {
+        1.0
+    } else if x < s + 1.0 {
+        gamma_reg_series(s, x)
+    } else {
+        1.0 - gamma_reg_fraction(s, x)
+    }
+}
+
+/// # Regularized upper incomplete gamma function
+/// 
+/// ## Definition
+/// The [regularized upper incomplete gamma function](https://en.wikipedia.org/wiki/Incomplete_gamma_function)
+/// is defined as:
+/// $$
+/// Q(s,x) = \frac{\Gamma(s,x)}{\Gamma(s)} = 1 - P(s,x)
+/// $$
+/// 
+/// For $x \ge s + 1$ we use the continued fraction:
+/// $$
+/// Q(s,x) = \frac{x^s\exp(-x)}{\Gamma(s)}\left( \frac{1}{x+1-s-}\frac{1\cdot(1-s)}{x+3-s-}\frac{2\cdot(2-s)}{x+5-s-}\cdots \right)
+/// $$
+/// Otherwise, we compute $1 - P(s,x)$ with the series expansion of $P$.
+/// 
+/// ## Inputs
+/// - `s`: the parameter of the function ($s > 0$, finite)
+/// - `x`: the lower bound of the integral ($x \ge 0$)
+/// 
+/// Returns the value of $Q(s,x)$, or `NaN` if the inputs are out of the domain.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::{ gamma_reg_q, factorial };
+/// // The cumulative distribution function of the Poisson distribution
+/// let lambda: f64 = 3.0;
+/// let k: usize = 2;
+/// let expected: f64 = (0..=k).fold(0.0, |res, i| res + lambda.powi(i as i32) / factorial(i) as f64) * (-lambda).exp();
+/// let res: f64 = gamma_reg_q(k as f64 + 1.0, lambda);
+/// assert!((res - expected).abs() < 1.0e-12);
+/// assert_eq!(gamma_reg_q(1.2, 0.0), 1.0);
+/// assert!(gamma_reg_q(f64::INFINITY, 1.0).is_nan());
+/// ```
+pub fn gamma_reg_q(s: f64, x: f64) -> f64 {
+
+    // Checking the domain of definition
+    if s <= 0.0 || !s.is_finite() || x < 0.0 || x.is_nan() {
+        return f64::NAN;
+    }
+
+    if x == 0.0 {
+        1.0
+    } else if x.is_infinite() {
+        0.0
+    } else if x < s + 1.0 {
+        1.0 - gamma_reg_series(s, x)
+    } else {
+        gamma_reg_fraction(s, x)
+    }
+}
+
+/// # Series expansion of $P(s,x)$
+/// 
+/// Converges quickly for $x < s + 1$.
+fn gamma_reg_series(s: f64, x: f64) -> f64 {
+
+    let mut ap: f64 = s;            // Incremented parameter
+    let mut term: f64 = 1.0 / s;    // Term at each iteration
+    let mut sum: f64 = term;        // Result of the series
+
+    for _ in 0..SERIES_MAX_ITER {
+        ap += 1.0;
+        term *= x / ap;
+        sum += term;
+
+        // We exit when convergence reaches the precision
+        if term.abs() < sum.abs() * PRECISION {
+            break;
+        }
+    }
+
+    sum * (s * x.ln() - x - ln_gamma(s)).exp()
+}
+
+/// # Continued fraction of $Q(s,x)$
+/// 
+/// Evaluated with the modified Lentz method, converges quickly for $x \ge s + 1$.
+fn gamma_reg_fraction(s: f64, x: f64) -> f64 {
+
+    let res: f64 = eval_continued_fraction(|n| {
+        let nf: f64 = n as f64;
+        match n {
+            0 => (0.0, 0.0),
+            1 => (1.0, x + 1.0 - s),
+            _ => (-(nf - 1.0) * (nf - 1.0 - s), x + 2.0 * nf - 1.0 - s)
+        }
+    }, PRECISION, FRACTION_MAX_ITER);
+
+    res * (s * x.ln() - x - ln_gamma(s)).exp()
+}
+
+/// # Continued fraction evaluation
+/// 
+/// ## Definition
+/// Evaluates the generalized [continued fraction](https://en.wikipedia.org/wiki/Generalized_continued_fraction):
+/// $$
+/// f = b_0 + \cfrac{a_1}{b_1 + \cfrac{a_2}{b_2 + \cfrac{a_3}{b_3 + \dots}}}
+/// $$
+/// Using the modified Lentz algorithm, which computes the successive convergents without recomputing the whole
+/// fraction, and shields the divisions from vanishing denominators.
+/// 
+/// ## Inputs
+/// - `terms`: returns the pair $(a_n, b_n)$ for each $n$, $a_0$ being ignored
+/// - `tol`: the relative change of the convergents at which to stop
+/// - `max_iter`: the maximum number of terms to evaluate
+/// 
+/// Returns the value of the continued fraction, or the last convergent if `max_iter` is reached first.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::eval_continued_fraction;
+/// // The golden ratio is 1 + 1 / (1 + 1 / (1 + ...))
+/// let phi: f64 = eval_continued_fraction(|_| (1.0, 1.0), 1.0e-15, 100);
+/// assert!((phi - (1.0 + 5.0_f64.sqrt()) / 2.0).abs() < 1.0e-15);
+/// 
+/// // Lambert's fraction for the tangent, x / (1 - x^2 / (3 - x^2 / (5 - ...)))
+/// let x: f64 = 1.2;
+/// let tan: f64 = eval_continued_fraction(|n| match n {
+///     0 => (0.0, 0.0),
+///     1 => (x, 1.0),
+///     _ => (-x * x, 2.0 * n as f64 - 1.0)
+/// }, 1.0e-15, 100);
+/// assert!((tan - x.tan()).abs() < 1.0e-14);
+/// ```
+pub fn eval_continued_fraction<F>(terms: F, tol: f64, max_iter: usize) -> f64
+where F: Fn(usize) -> (f64, f64) {
+
+    let mut res: f64 = terms(0).1;
+    if res.abs() < FPMIN {
+        res = FPMIN;
+    }
+
+    let mut c: f64 = res;
+    let mut d: f64 = 0.0;
+    let mut delta: f64;
+
+    for n in 1..=max_iter {
+        let (a, b): (f64, f64) = terms(n);
+
+        d = b + a * d;
+        if d.abs() < FPMIN {
+            d = FPMIN;
+        }
+
+        c = b + a / c;
+        if c.abs() < FPMIN {
+            c = FPMIN;
+        }
+
+        d = 1.0 / d;
+        delta = c * d;
+        res *= delta;
+
+        // We exit when convergence reaches the precision
+        if (delta - 1.0).abs() < tol {
+            break;
+        }
+    }
+
+    res
+}
+
+/// # Continued fraction evaluation with complex terms
+/// 
+/// Same modified Lentz algorithm as `eval_continued_fraction`, for complex $(a_n, b_n)$.
+fn eval_continued_fraction_complex<F>(terms: F, tol: f64, max_iter: usize) -> Complex
+where F: Fn(usize) -> (Complex, Complex) {
+
+    let mut res: Complex = terms(0).1;
+    if res.modulus() < FPMIN {
+        res = Complex::from(FPMIN, 0.0);
+    }
+
+    let mut c: Complex = res;
+    let mut d: Complex = Complex::ZERO;
+    let mut delta: Complex;
+
+    for n in 1..=max_iter {
+        let (a, b): (Complex, Complex) = terms(n);
+
+        d = b + a * d;
+        if d.modulus() < FPMIN {
+            d = Complex::from(FPMIN, 0.0);
+        }
+
+        c = b + a / c;
+        if c.modulus() < FPMIN {
+            c = Complex::from(FPMIN, 0.0);
+        }
+
+        d = 1.0 / d;
+        delta = c * d;
+        res *= delta;
+
+        // We exit when convergence reaches the precision
+        if (delta - 1.0).modulus() < tol {
+            break;
+        }
+    }
+
+    res
+}
+
+/// # Clamp function
+/// 
+/// ## Definition
+/// The [clamp function](https://en.wikipedia.org/wiki/Clamping_(graphics)) restricts a value to an interval:
+/// $$
+/// \mathrm{clamp}(x, a, b) = \min(\max(x, a), b)
+/// $$
+/// Unlike `f64::clamp`, it does not panic when the bounds are not ordered, the upper bound prevailing.
+/// 
+/// ## Inputs
+/// - `x`: the value to restrict ($x$)
+/// - `lo`: the lower bound ($a$)
+/// - `hi`: the upper bound ($b$)
+/// 
+/// Returns the value restricted to $[a, b]$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::clamp;
+/// assert_eq!(clamp(-0.5, 0.0, 1.0), 0.0);
+/// assert_eq!(clamp(0.3, 0.0, 1.0), 0.3);
+/// assert_eq!(clamp(2.5, 0.0, 1.0), 1.0);
+/// ```
+pub fn clamp(x: f64, lo: f64, hi: f64) -> f64 {
+
+    if x < lo {
+        lo.min(hi)
+    } else if x > hi {
+        hi
+    } else {
+        x
+    }
+}
+
+/// # Smoothstep function
+/// 
+/// ## Definition
+/// The [smoothstep function](https://en.wikipedia.org/wiki/Smoothstep) is the Hermite interpolation
+/// between two edges:
+/// $$
+/// S(x) = 3t^2 - 2t^3 \quad\text{with}\quad t = \mathrm{clamp}\left( \frac{x - e_0}{e_1 - e_0}, 0, 1 \right)
+/// $$
+/// 
+/// ## Inputs
+/// - `edge0`: the lower edge ($e_0$)
+/// - `edge1`: the upper edge ($e_1$)
+/// - `x`: the value at which to evaluate the function ($x$)
+/// 
+/// Returns the value of the smoothstep function, zero below $e_0$ and one above $e_1$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::smoothstep;
+/// assert_eq!(smoothstep(1.0, 3.0, 0.0), 0.0);
+/// assert_eq!(smoothstep(1.0, 3.0, 1.0), 0.0);
+/// assert_eq!(smoothstep(1.0, 3.0, 2.0), 0.5);
+/// assert_eq!(smoothstep(1.0, 3.0, 2.5), 0.84375);
+/// assert_eq!(smoothstep(1.0, 3.0, 3.0), 1.0);
+/// assert_eq!(smoothstep(1.0, 3.0, 10.0), 1.0);
+/// ```
+pub fn smoothstep(edge0: f64, edge1: f64, x: f64) -> f64 {
+    let t: f64 = clamp((x - edge0) / (edge1 - edge0), 0.0, 1.0);
+    t.powi(2) * (3.0 - 2.0 * t)
+}
+
+/// # Sigmoid function
+/// 
+/// ## Definition
+/// The [sigmoid function](https://en.wikipedia.org/wiki/Sigmoid_function) is defined as:
+/// $$
+/// \sigma(x) = \frac{1}{1 + \exp(-x)} = 1 - \sigma(-x)
+/// $$
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the function ($x$).
+/// 
+/// Returns the value of the sigmoid function.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::sigmoid;
+/// let res1: f64 = sigmoid(-1.0);
+/// let res2: f64 = sigmoid(0.0);
+/// let res_comp: f64 = sigmoid(1.0);
+/// assert!((res1 - 0.26894142136999).abs() < 1.0e-12);
+/// assert_eq!(res2, 0.5);
+/// assert_eq!(res1, 1.0 - res_comp);
+/// ```
+pub fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// # Logit function
+/// 
+/// ## Definition
+/// The [logit function](https://en.wikipedia.org/wiki/Logit) is the inverse of the sigmoid function:
+/// $$
+/// \mathrm{logit}(p) = \ln\left( \frac{p}{1-p} \right) = \sigma^{-1}(p)
+/// $$
+/// 
+/// ## Inputs
+/// - `p`: the probability at which to evaluate the function ($p$)
+/// 
+/// Returns the value of the logit function, $\pm\infty$ at the boundaries, and `NaN` outside of $[0, 1]$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::{ logit, sigmoid };
+/// for p in [0.001, 0.2, 0.5, 0.75, 0.999] {
+///     assert!((sigmoid(logit(p)) - p).abs() < 1.0e-15);
+/// }
+/// assert_eq!(logit(0.5), 0.0);
+/// assert_eq!(logit(0.0), f64::NEG_INFINITY);
+/// assert_eq!(logit(1.0), f64::INFINITY);
+/// assert!(logit(1.5).is_nan());
+/// ```
+pub fn logit(p: f64) -> f64 {
+
+    if !(0.0..=1.0).contains(&p) {
+        return f64::NAN;
+    }
+
+    (p / (1.0 - p)).ln()
+}
+
+/// # Rectified linear unit
+/// 
+/// ## Definition
+/// The [rectified linear unit](https://en.wikipedia.org/wiki/Rectifier_(neural_networks)) is defined as:
+/// $$
+/// \mathrm{ReLU}(x) = \max(0, x)
+/// $$
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the function ($x$)
+/// 
+/// Returns the value of the rectified linear unit.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::relu;
+/// assert_eq!(relu(-2.5), 0.0);
+/// assert_eq!(relu(0.0), 0.0);
+/// assert_eq!(relu(1.0e300), 1.0e300);
+/// ```
+pub fn relu(x: f64) -> f64 {
+    x.max(0.0)
+}
+
+/// # Leaky rectified linear unit
+/// 
+/// ## Definition
+/// The [leaky rectified linear unit](https://en.wikipedia.org/wiki/Rectifier_(neural_networks)#Leaky_ReLU)
+/// is defined as:
+/// $$
+/// f(x) = \max(0, x) + \alpha\min(0, x)
+/// $$
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the function ($x$)
+/// - `alpha`: the slope for negative values ($\alpha$)
+/// 
+/// Returns the value of the leaky rectified linear unit.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::leaky_relu;
+/// assert_eq!(leaky_relu(-2.0, 0.01), -0.02);
+/// assert_eq!(leaky_relu(0.0, 0.01), 0.0);
+/// assert_eq!(leaky_relu(1.0e300, 0.01), 1.0e300);
+/// ```
+pub fn leaky_relu(x: f64, alpha: f64) -> f64 {
+
+    if x > 0.0 {
+        x
+    } else {
+        alpha * x
+    }
+}
+
+/// # Softplus function
+/// 
+/// ## Definition
+/// The [softplus function](https://en.wikipedia.org/wiki/Softplus) is defined as:
+/// $$
+/// \zeta(x) = \ln(1 + \exp(x)) = x + \ln(1 + \exp(-x))
+/// $$
+/// The second form is used for positive values to avoid any overflow.
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the function ($x$)
+/// 
+/// Returns the value of the softplus function.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::softplus;
+/// assert_eq!(softplus(0.0), 2.0_f64.ln());
+/// assert!((softplus(-1.0) - 0.3132616875182228).abs() < 1.0e-15);
+/// assert_eq!(softplus(1000.0), 1000.0);
+/// assert!(softplus(-1000.0) >= 0.0);
+/// ```
+pub fn softplus(x: f64) -> f64 {
+
+    if x > 0.0 {
+        x + (-x).exp().ln_1p()
+    } else {
+        x.exp().ln_1p()
+    }
+}
+
+/// # Swish function
+/// 
+/// ## Definition
+/// The [swish function](https://en.wikipedia.org/wiki/Swish_function) is defined as:
+/// $$
+/// \mathrm{swish}(x) = x\sigma(x) = \frac{x}{1 + \exp(-x)}
+/// $$
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the function ($x$)
+/// 
+/// Returns the value of the swish function.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::swish;
+/// assert_eq!(swish(0.0), 0.0);
+/// assert!((swish(1.0) - 0.7310585786300049).abs() < 1.0e-15);
+/// assert_eq!(swish(1000.0), 1000.0);
+/// ```
+pub fn swish(x: f64) -> f64 {
+    x * sigmoid(x)
+}
+
+/// # Softmax function
+/// 
+/// ## Definition
+/// The [softmax function](https://en.wikipedia.org/wiki/Softmax_function) is defined as:
+/// $$
+/// \sigma(\mathbf{x})_i = \frac{\exp(x_i)}{\sum_j\exp(x_j)} = \frac{\exp(x_i - m)}{\sum_j\exp(x_j - m)}
+/// $$
+/// Where $m$ is the maximum of the values, subtracted to avoid any overflow.
+/// 
+/// ## Inputs
+/// - `xs`: the values to evaluate ($\mathbf{x}$)
+/// 
+/// Returns the vector of probabilities, which sum to one, or an empty vector for an empty slice.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::softmax;
+/// let res: Vec<f64> = softmax(&[1.0, 2.0, 1000.0, 3.0]);
+/// assert!((res.iter().sum::<f64>() - 1.0).abs() < 1.0e-15);
+/// assert_eq!(res[2], 1.0);
+/// let res_eq: Vec<f64> = softmax(&[0.5, 0.5]);
+/// assert_eq!(res_eq, vec![0.5, 0.5]);
+/// assert!(softmax(&[]).is_empty());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn softmax(xs: &[f64]) -> Vec<f64> {
+
+    let max: f64 = xs.iter().fold(f64::NEG_INFINITY, |res, &val| res.max(val));
+    let res: Vec<f64> = xs.iter().map(|val| (val - max).exp()).collect();
+    let sum: f64 = res.iter().sum();
+
+    res.iter().map(|val| val / sum).collect()
+}
+
+/// # Log-sum-exp function
+/// 
+/// ## Definition
+/// The [log-sum-exp function](https://en.wikipedia.org/wiki/LogSumExp) is defined as:
+/// $$
+/// \mathrm{LSE}(\mathbf{x}) = \ln\left( \sum_i\exp(x_i) \right) = m + \ln\left( \sum_i\exp(x_i - m) \right)
+/// $$
+/// Where $m$ is the maximum of the values, subtracted to avoid any overflow.
+/// 
+/// ## Inputs
+/// - `xs`: the values to evaluate ($\mathbf{x}$)
+/// 
+/// Returns the value of the log-sum-exp, $-\infty$ for an empty slice.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::logsumexp;
+/// let res: f64 = logsumexp(&[1.0, 2.0, 3.0]);
+/// assert!((res - 3.40760596444438).abs() < 1.0e-14);
+/// assert!((logsumexp(&[1000.0, 1000.0]) - (1000.0 + 2.0_f64.ln())).abs() < 1.0e-12);
+/// assert_eq!(logsumexp(&[]), f64::NEG_INFINITY);
+/// ```
+pub fn logsumexp(xs: &[f64]) -> f64 {
+
+    let max: f64 = xs.iter().fold(f64::NEG_INFINITY, |res, &val| res.max(val));
+
+    // Avoiding the undefined difference of infinite values
+    if max.is_infinite() {
+        return max;
+    }
+
+    max + xs.iter().map(|val| (val - max).exp()).sum::<f64>().ln()
+}
+
+/// # Gaussian function
+/// 
+/// ## Definition
+/// The [gaussian function](https://en.wikipedia.org/wiki/Gaussian_function) is defined as:
+/// $$
+/// g(x) = a\cdot\exp\left(-\frac{(b - x)^2}{2c^2}\right)
+/// $$
+/// 
+/// ## Inputs
+/// - `a`: the amplitude ($a$)
+/// - `b`: the center ($b$)
+/// - `c`: the standard deviation ($c$)
+/// - `x`: the value to evaluate ($x$).
+/// 
+/// Returns the value of the gaussian function with parameters $a$, $b$, $c$ at $x$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::gaussian;
+/// let res1: f64 = gaussian(1.0, 2.0, 3.0, 0.0);
+/// assert_eq!(res1, 0.8007374029168081);
+/// ```
+pub fn gaussian(a: f64, b: f64, c: f64, x: f64) -> f64 {
+    a * (-(x - b).powi(2) / (2.0 * c.powi(2))).exp()
+}
+
+/// # Normalized gaussian function
+/// 
+/// ## Definition
+/// The [normalized gaussian function](https://en.wikipedia.org/wiki/Gaussian_function) is defined as:
+/// $$
+/// g(x) = \frac{1}{\sigma\sqrt{2\pi}}\cdot\exp\left( -\frac{(x - \mu)^2}{2\sigma^2} \right)
+/// $$
+/// 
+/// ## Inputs
+/// - `mu`: the expected value ($\mu$)
+/// - `sigma`: the variance ($\sigma$)
+/// - `x`: the value to evaluate ($x$)
+/// 
+/// Returns the value of the normalized gaussian function with parameters
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::gaussian_normed;
+/// let res: f64 = gaussian_normed(1.0, 2.0, 3.0);
+/// assert!((res - 0.120985362259).abs() < 1e-12);
+/// ```
+pub fn gaussian_normed(mu: f64, sigma: f64, x: f64) -> f64 {
+    (1.0 / (sigma * TAU.sqrt())) * (-(x - mu).powi(2) / (2.0 * sigma.powi(2))).exp()
+}
+
+/// # Two-dimensional gaussian function
+/// 
+/// ## Definition
+/// The [two-dimensional gaussian function](https://en.wikipedia.org/wiki/Gaussian_function#Two-dimensional_Gaussian_function)
+/// with correlation $\rho$ is defined as:
+/// $$
+/// g(x,y) = a\cdot\exp\left( -\frac{1}{2(1-\rho^2)}\left[ \frac{(x-\mu_x)^2}{\sigma_x^2} -
+/// \frac{2\rho(x-\mu_x)(y-\mu_y)}{\sigma_x\sigma_y} + \frac{(y-\mu_y)^2}{\sigma_y^2} \right] \right)
+/// $$
+/// 
+/// ## Inputs
+/// - `a`: the amplitude ($a$)
+/// - `center`: the center ($\mu_x$, $\mu_y$)
+/// - `sigma`: the standard deviations along each axis ($\sigma_x$, $\sigma_y$)
+/// - `rho`: the correlation between both axes ($-1 < \rho < 1$), zero for independent axes
+/// - `x`, `y`: the point to evaluate ($x$, $y$)
+/// 
+/// Returns the value of the two-dimensional gaussian function at $(x, y)$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::{ gaussian, gaussian_2d };
+/// let res: f64 = gaussian_2d(2.0, (1.0, -1.0), (0.5, 2.0), 0.0, 1.3, 0.4);
+/// assert!((res - gaussian(2.0, 1.0, 0.5, 1.3) * gaussian(1.0, -1.0, 2.0, 0.4)).abs() < 1.0e-15);
+/// assert_eq!(gaussian_2d(2.0, (1.0, -1.0), (0.5, 2.0), 0.6, 1.0, -1.0), 2.0);
+/// ```
+pub fn gaussian_2d(a: f64, center: (f64, f64), sigma: (f64, f64), rho: f64, x: f64, y: f64) -> f64 {
+
+    let dx: f64 = (x - center.0) / sigma.0;
+    let dy: f64 = (y - center.1) / sigma.1;
+
+    a * (-(dx.powi(2) - 2.0 * rho * dx * dy + dy.powi(2)) / (2.0 * (1.0 - rho.powi(2)))).exp()
+}
+
+/// # Normalized two-dimensional gaussian function
+/// 
+/// ## Definition
+/// The normalized [two-dimensional gaussian function](https://en.wikipedia.org/wiki/Multivariate_normal_distribution#Bivariate_case)
+/// is the probability density of the bivariate normal distribution, with amplitude:
+/// $$
+/// a = \frac{1}{2\pi\sigma_x\sigma_y\sqrt{1-\rho^2}}
+/// $$
+/// 
+/// ## Inputs
+/// - `center`: the center ($\mu_x$, $\mu_y$)
+/// - `sigma`: the standard deviations along each axis ($\sigma_x$, $\sigma_y$)
+/// - `rho`: the correlation between both axes ($-1 < \rho < 1$), zero for independent axes
+/// - `x`, `y`: the point to evaluate ($x$, $y$)
+/// 
+/// Returns the value of the normalized two-dimensional gaussian function at $(x, y)$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::{ gaussian_normed, gaussian_2d_normed };
+/// let res: f64 = gaussian_2d_normed((1.0, -1.0), (0.5, 2.0), 0.0, 1.3, 0.4);
+/// assert!((res - gaussian_normed(1.0, 0.5, 1.3) * gaussian_normed(-1.0, 2.0, 0.4)).abs() < 1.0e-15);
+/// 
+/// // The integral over the plane is one
+/// let step: f64 = 0.05;
+/// let mut sum: f64 = 0.0;
+/// for i in -200..=200 {
+///     for j in -200..=200 {
+///         sum += gaussian_2d_normed((0.5, -0.5), (1.0, 1.5), 0.7, i as f64 * step, j as f64 * step);
+///     }
+/// }
+/// assert!((sum * step.powi(2) - 1.0).abs() < 1.0e-8);
+/// ```
+pub fn gaussian_2d_normed(center: (f64, f64), sigma: (f64, f64), rho: f64, x: f64, y: f64) -> f64 {
+    let a: f64 = 1.0 / (TAU * sigma.0 * sigma.1 * (1.0 - rho.powi(2)).sqrt());
+    gaussian_2d(a, center, sigma, rho, x, y)
+}
+
+/// # Multivariate normalized gaussian function
+/// 
+/// ## Definition
+/// The [multivariate normal distribution](https://en.wikipedia.org/wiki/Multivariate_normal_distribution)
+/// in $k$ dimensions has the density:
+/// $$
+/// g(\mathbf{x}) = \frac{1}{\sqrt{(2\pi)^k|\Sigma|}}\exp\left( -\frac{1}{2}(\mathbf{x}-\boldsymbol{\mu})^T\Sigma^{-1}(\mathbf{x}-\boldsymbol{\mu}) \right)
+/// $$
+/// The covariance matrix is handled with its Cholesky decomposition $\Sigma = LL^T$.
+/// 
+/// ## Inputs
+/// - `mu`: the expected value ($\boldsymbol{\mu}$)
+/// - `cov`: the covariance matrix, symmetric positive-definite, given as a list of rows ($\Sigma$)
+/// - `x`: the point to evaluate ($\mathbf{x}$)
+/// 
+/// Returns the value of the density at $\mathbf{x}$, or `NaN` if the dimensions do not match
+/// or if the covariance matrix is not positive-definite.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::{ gaussian_2d_normed, gaussian_multivariate };
+/// let cov: Vec<Vec<f64>> = vec![vec![0.25, 0.6], vec![0.6, 4.0]];
+/// let res: f64 = gaussian_multivariate(&[1.0, -1.0], &cov, &[1.3, 0.4]);
+/// let res_2d: f64 = gaussian_2d_normed((1.0, -1.0), (0.5, 2.0), 0.6, 1.3, 0.4);
+/// assert!((res - res_2d).abs() < 1.0e-15);
+/// assert!(gaussian_multivariate(&[0.0, 0.0], &[vec![1.0, 2.0], vec![2.0, 1.0]], &[0.0, 0.0]).is_nan());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn gaussian_multivariate(mu: &[f64], cov: &[Vec<f64>], x: &[f64]) -> f64 {
+
+    let k: usize = mu.len();
+
+    if x.len() != k || cov.len() != k || cov.iter().any(|row| row.len() != k) {
+        return f64::NAN;
+    }
+
+    // Cholesky decomposition of the covariance matrix
+    let mut l: Vec<Vec<f64>> = vec![vec![0.0; k]; k];
+
+    for i in 0..k {
+        for j in 0..=i {
+            let sum: f64 = cov[i][j] - (0..j).map(|p| l[i][p] * l[j][p]).sum::<f64>();
+
+            if i == j {
+                if sum <= 0.0 {
+                    return f64::NAN;
+                }
+                l[i][i] = sum.sqrt();
+            } else {
+                l[i][j] = sum / l[j][j];
+            }
+        }
+    }
+
+    // Solving L z = x - mu, so that the quadratic form is |z|^2
+    let mut z: Vec<f64> = vec![0.0; k];
+
+    for i in 0..k {
+        let sum: f64 = (x[i] - mu[i]) - (0..i).map(|p| l[i][p] * z[p]).sum::<f64>();
+        z[i] = sum / l[i][i];
+    }
+
+    let quad: f64 = z.iter().map(|val| val.powi(2)).sum();
+    let det_sqrt: f64 = (0..k).map(|i| l[i][i]).product();
+
+    (-0.5 * quad).exp() / (TAU.powf(k as f64 / 2.0) * det_sqrt)
+}
+
+/// # Faddeeva function
+/// 
+/// ## Definition
+/// The [Faddeeva function](https://en.wikipedia.org/wiki/Faddeeva_function) is a scaled complementary error function:
+/// $$
+/// w(z) = \exp(-z^2)\mathrm{erfc}(-iz)
+/// $$
+/// 
+/// We use the algorithm of Poppe and Wijers (ACM TOMS 680): a power series close to the origin, and elsewhere
+/// the Laplace continued fraction, accelerated by a truncated Taylor expansion in the intermediate region.
+/// The other quadrants follow from $w(-z) = 2\exp(-z^2) - w(z)$ and $w(-\bar{z}) = \overline{w(z)}$.
+/// The relative precision is close to `1.0e-14` across the complex plane.
+/// 
+/// ## Inputs
+/// - `val`: the point at which to evaluate the function ($z$)
+/// 
+/// Returns the value of $w(z)$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::basic::faddeeva;
+/// let res = faddeeva(Complex::from(1.0, 1.0));
+/// assert!((res - Complex::from(0.3047442052569126, 0.2082189382028316)).modulus() < 1.0e-15);
+/// 
+/// let res = faddeeva(Complex::from(3.0, -1.0));
+/// assert!((res - Complex::from(-0.06467357479385969, 0.17373084850174396)).modulus() < 1.0e-15);
+/// 
+/// // On the real axis, the real part is exp(-x^2)
+/// let res = faddeeva(10.0);
+/// assert_eq!(res.re, (-100.0_f64).exp());
+/// assert!((res.im - 0.05670539423288759).abs() < 1.0e-16);
+/// ```
+pub fn faddeeva<T>(val: T) -> Complex
+where T: Into<Complex> {
+
+    let z: Complex = val.into();
+    let (xabs, yabs): (f64, f64) = (z.re.abs(), z.im.abs());
+
+    // Scaled coordinates defining the regions of the algorithm
+    let xs: f64 = xabs / 6.3;
+    let ys: f64 = yabs / 4.4;
+    let qrho: f64 = xs.powi(2) + ys.powi(2);
+
+    let xquad: f64 = xabs.powi(2) - yabs.powi(2);
+    let yquad: f64 = 2.0 * xabs * yabs;
+
+    // exp(-z^2) in the first quadrant, when computed by the series
+    let (mut u2, mut v2): (f64, f64) = (0.0, 0.0);
+    let (mut u, mut v): (f64, f64);
+    let series: bool = qrho < 0.085_264;
+
+    if series {
+        // Power series of erf, close to the origin
+        let q: f64 = (1.0 - 0.85 * ys) * qrho.sqrt();
+        let n: usize = (6.0 + 72.0 * q).round() as usize;
+        let mut j: usize = 2 * n + 1;
+        let mut xsum: f64 = 1.0 / j as f64;
+        let mut ysum: f64 = 0.0;
+
+        for i in (1..=n).rev() {
+            j -= 2;
+            let xaux: f64 = (xsum * xquad - ysum * yquad) / i as f64;
+            ysum = (xsum * yquad + ysum * xquad) / i as f64;
+            xsum = xaux + 1.0 / j as f64;
+        }
+
+        let u1: f64 = 1.0 - FRAC_2_SQRT_PI * (xsum * yabs + ysum * xabs);
+        let v1: f64 = FRAC_2_SQRT_PI * (xsum * xabs - ysum * yabs);
+        let daux: f64 = (-xquad).exp();
+        u2 = daux * yquad.cos();
+        v2 = -daux * yquad.sin();
+
+        u = u1 * u2 - v1 * v2;
+        v = u1 * v2 + v1 * u2;
+    } else {
+        // Laplace continued fraction, with the Taylor acceleration in the intermediate region
+        let (h, kapn, nu): (f64, usize, usize) = if qrho > 1.0 {
+            (0.0, 0, (3.0 + 1442.0 / (26.0 * qrho.sqrt() + 77.0)) as usize)
+        } else {
+            let q: f64 = (1.0 - ys) * (1.0 - qrho).sqrt();
+            (1.88 * q, (7.0 + 34.0 * q).round() as usize, (16.0 + 26.0 * q).round() as usize)
+        };
+
+        let h2: f64 = 2.0 * h;
+        let mut qlambda: f64 = if h > 0.0 { h2.powi(kapn as i32) } else { 0.0 };
+        let (mut rx, mut ry, mut sx, mut sy): (f64, f64, f64, f64) = (0.0, 0.0, 0.0, 0.0);
+
+        for n in (0..=nu).rev() {
+            let np1: f64 = (n + 1) as f64;
+            let tx: f64 = yabs + h + np1 * rx;
+            let ty: f64 = xabs - np1 * ry;
+            let c: f64 = 0.5 / (tx.powi(2) + ty.powi(2));
+            rx = c * tx;
+            ry = c * ty;
+
+            if h > 0.0 && n <= kapn {
+                let tx: f64 = qlambda + sx;
+                sx = rx * tx - ry * sy;
+                sy = ry * tx + rx * sy;
+                qlambda /= h2;
+            }
+        }
+
+        if h == 0.0 {
+            u = FRAC_2_SQRT_PI * rx;
+            v = FRAC_2_SQRT_PI * ry;
+        } else {
+            u = FRAC_2_SQRT_PI * sx;
+            v = FRAC_2_SQRT_PI * sy;
+        }
+
+        if yabs == 0.0 {
+            u = (-xabs.powi(2)).exp();
+        }
+    }
+
+    // Going back to the original quadrant
+    if z.im < 0.0 {
+        if series {
+            u2 *= 2.0;
+            v2 *= 2.0;
+        } else {
+            let w1: f64 = 2.0 * (-xquad).exp();
+            u2 = w1 * yquad.cos();
+            v2 = -w1 * yquad.sin();
+        }
+
+        u = u2 - u;
+        v = v2 - v;
+        if z.re > 0.0 {
+            v = -v;
+        }
+    } else if z.re < 0.0 {
+        v = -v;
+    }
+
+    Complex::from(u, v)
+}
+
+/// # Error function
+/// 
+/// ## Definition
+/// The [error function](https://en.wikipedia.org/wiki/Error_function) is defined as:
+/// $$
+/// \mathrm{erf}(z) = \frac{2}{\sqrt{\pi}}\int_{0}^{z}\exp(-t^2)dt
+/// $$
+/// 
+/// We define the error function for complex number. Close to the origin, we use its Taylor series, and
+/// elsewhere the Faddeeva function, with $\mathrm{erf}(-z) = -\mathrm{erf}(z)$ to keep $\Re(z) \geq 0$:
+/// $$
+/// \mathrm{erf}(z) = 1 - \exp(-z^2)w(iz)
+/// $$
+/// 
+/// For real values, `erf_real` is both faster and more precise.
+/// 
+/// ## Inputs
+/// - `val`: the point at which to evaluate the function ($z$)
+/// 
+/// Returns the error function value at $z$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::basic::erf;
+/// let r = erf(2.1);
+/// let c = erf(Complex::from(-0.1, 0.7));
+/// assert!((r.re - 0.997021).abs() < 1.0e-5);
+/// assert!((c.re - -0.18297754).abs() < 1.0e-5 && (c.im - 0.92747498).abs() < 1.0e-5);
+/// 
+/// let c = erf(Complex::from(5.0, 5.0));
+/// assert!((c - Complex::from(0.9303796037430951, 0.03893619089512138)).modulus() < 1.0e-14);
+/// let c = erf(Complex::from(-2.0, 0.3));
+/// assert!((c - Complex::from(-0.9987630892171225, 0.004930619809302624)).modulus() < 1.0e-14);
+/// let c = erf(Complex::from(0.01, 0.02));
+/// assert!((c - Complex::from(0.011287929523862137, 0.02256833516582954)).modulus() < 1.0e-17);
+/// ```
+pub fn erf<T>(val: T) -> Complex
+where T: Into<Complex> {
+
+    let z: Complex = val.into();
+
+    if z.modulus() < ERF_SERIES_LIM {
+        return erf_series(z);
+    }
+
+    if z.re < 0.0 {
+        return -erf(-z);
+    }
+
+    1.0 - (-z.powi(2)).exp() * faddeeva(Complex::i() * z)
+}
+
+/// Computes the error function with its Taylor series.
+fn erf_series(x: Complex) -> Complex {
+
+    let mut n: f64 = 0.0;               // Index of iteration
+    let mut d1: f64 = 1.0;              // First div
+    let mut d2: f64;                    // Second div
+    let mut sg: f64 = 1.0;              // Sign of the term
+
+    let mut term: Complex = x;          // Term at each iter
+    let mut res: Complex = 0.0.into();  // Result
+
+    // If the term is too small we exit
+    if term.modulus() < PRECISION {
+        return res;
+    }
+
+    'convergence: loop {
+        res += term;
+
+        // We exit when convergence reaches the precision
+        if (term / res).modulus() < PRECISION {
+            break 'convergence;
+        }
+
+        n += 1.0;
+        sg *= -1.0;
+        d1 *= n;
+        d2 = 2.0 * n + 1.0;
+        term = sg * x.powi(d2 as i32) / (d1 * d2);
+    }
+
+    FRAC_2_SQRT_PI * res
+}
+
+/// # Real error function
+/// 
+/// ## Definition
+/// The [error function](https://en.wikipedia.org/wiki/Error_function) restricted to real numbers:
+/// $$
+/// \mathrm{erf}(x) = \frac{2}{\sqrt{\pi}}\int_{0}^{x}\exp(-t^2)dt
+/// $$
+/// 
+/// The computation only relies on real arithmetic, with the rational approximations of
+/// [W. J. Cody](https://doi.org/10.1090/S0025-5718-1969-0247736-4), accurate to double precision at a fixed cost.
+/// The function itself is approximated for $|x| \le 0.46875$, and its complement $\mathrm{erfc}(x)$ beyond,
+/// the factor $\exp(-x^2)$ of the latter being split in two to keep its precision.
+/// 
+/// ## Inputs
+/// - `x`: the point at which to evaluate the function ($x$)
+/// 
+/// Returns the error function value at $x$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::erf_real;
+/// assert!((erf_real(0.5) - 0.5204998778130465).abs() < 1.0e-12);
+/// assert!((erf_real(1.0) - 0.8427007929497149).abs() < 1.0e-12);
+/// assert!((erf_real(2.0) - 0.9953222650189527).abs() < 1.0e-12);
+/// assert!((erf_real(3.5) - 0.9999992569016276).abs() < 1.0e-12);
+/// assert!((erf_real(5.0) - 0.9999999999984626).abs() < 1.0e-16);
+/// assert_eq!(erf_real(-1.3), -erf_real(1.3));
+/// assert_eq!(erf_real(0.0), 0.0);
+/// assert_eq!(erf_real(f64::NEG_INFINITY), -1.0);
+/// assert!(erf_real(f64::NAN).is_nan());
+/// ```
+pub fn erf_real(x: f64) -> f64 {
+
+    let y: f64 = x.abs();
+
+    if x.is_nan() {
+        return f64::NAN;
+    } else if y <= ERF_CENTRAL_LIM {
+        let t: f64 = x.powi(2);
+        return x * ERF_A.iter().fold(0.0, |res, a| res * t + a) / ERF_B.iter().fold(0.0, |res, b| res * t + b);
+    } else if y >= 6.0 {
+        // The complement is below the precision
+        return 1.0_f64.copysign(x);
+    }
+
+    // Complementary function, without its exponential factor
+    let rat: f64 = if y <= ERF_TAIL_LIM {
+        ERF_C.iter().fold(0.0, |res, c| res * y + c) / ERF_D.iter().fold(0.0, |res, d| res * y + d)
+    } else {
+        let t: f64 = y.powi(-2);
+        let corr: f64 = t * ERF_E.iter().fold(0.0, |res, e| res * t + e) / ERF_F.iter().fold(0.0, |res, f| res * t + f);
+        (0.5 * FRAC_2_SQRT_PI - corr) / y
+    };
+
+    // Splitting exp(-y^2), with ys holding a few bits of y so that ys^2 is exact
+    let ys: f64 = (16.0 * y).floor() / 16.0;
+    let erfc: f64 = (-ys.powi(2)).exp() * (-(y - ys) * (y + ys)).exp() * rat;
+
+    ((0.5 - erfc) + 0.5).copysign(x)
+}
+
+/// # Complementary error function
+/// 
+/// ## Definition
+/// The [complementary error function](https://en.wikipedia.org/wiki/Error_function) is defined as:
+/// $$
+/// \mathrm{erfc}(z) = 1 - \mathrm{erf}(z)
+/// $$
+/// 
+/// It is computed directly from the Faddeeva function, $\mathrm{erfc}(z) = \exp(-z^2)w(iz)$ for $\Re(z) \geq 0$,
+/// and $\mathrm{erfc}(-z) = 2 - \mathrm{erfc}(z)$ otherwise, avoiding the cancellation for large values.
+/// 
+/// ## Inputs
+/// - `val`: the point at which to evaluate the function ($z$)
+/// 
+/// Returns the complement of the error function, `1 - erf(z)`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::basic::erfc;
+/// let c = Complex::from(1.25, 0.3);
+/// let res = erfc(c);
+/// assert!((res.re - 0.0505570).abs() < 1.0e-5 && (res.im - -0.0663174).abs() < 1.0e-5);
+/// 
+/// let res = erfc(Complex::from(6.0, 0.5));
+/// assert!((res - Complex::from(2.698246749962258e-17, 5.531039405270454e-18)).modulus() < 1.0e-30);
+/// let res = erfc(Complex::from(-2.5, 1.0));
+/// assert!((res - Complex::from(1.9993826851377998, 0.0008469445433937926)).modulus() < 1.0e-14);
+/// ```
+pub fn erfc<T>(val: T) -> Complex
+where T: Into<Complex> {
+
+    let z: Complex = val.into();
+
+    if z.re < 0.0 {
+        return 2.0 - erfc(-z);
+    }
+
+    (-z.powi(2)).exp() * faddeeva(Complex::i() * z)
+}
+
+/// # Imaginary error function
+/// 
+/// ## Definition
+/// The [imaginary error function](https://en.wikipedia.org/wiki/Error_function) is defined as:
+/// $$
+/// \mathrm{erfi}(z) = -i\cdot\mathrm{erf}(iz)
+/// $$
+/// 
+/// ## Inputs
+/// - `val`: the point at which to evaluate the function ($z$)
+/// 
+/// Returns the imaginary error function, $-i\mathrm{erf}(iz)$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::basic::erfi;
+/// let c = Complex::from(0.07, -1.1);
+/// let res = erfi(c);
+/// assert!((res.re - 0.02349883).abs() < 1.0e-5 && (res.im - -0.88201955).abs() < 1.0e-5);
+/// ```
+pub fn erfi<T>(val: T) -> Complex
+where T: Into<Complex> {
+    -Complex::i() * erf(Complex::i() * val)
+}
+
+/// # Dawson function
+/// 
+/// ## Definition
+/// The [Dawson function](https://en.wikipedia.org/wiki/Dawson_function) is defined as:
+/// $$
+/// D(x) = \exp(-x^2)\int_{0}^{x}\exp(t^2)dt = \frac{\sqrt{\pi}}{2}\exp(-x^2)\mathrm{erfi}(x)
+/// $$
+/// 
+/// Computing it through $\mathrm{erfi}$ overflows for large $x$, so we use a Taylor series for small values,
+/// [Rybicki's method](https://doi.org/10.1063/1.4822753) for intermediate values:
+/// $$
+/// D(x) = \lim_{h\to 0}\frac{1}{\sqrt{\pi}}\sum_{n~\mathrm{odd}}\frac{\exp(-(x-nh)^2)}{n}
+/// $$
+/// And the asymptotic expansion for large values:
+/// $$
+/// D(x) \sim \frac{1}{2x}\left( 1 + \frac{1}{2x^2} + \frac{3}{4x^4} + \frac{15}{8x^6} + \cdots \right)
+/// $$
+/// 
+/// ## Inputs
+/// - `x`: the point at which to evaluate the function ($x$)
+/// 
+/// Returns the Dawson function value at $x$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::dawson;
+/// // The maximum of the function
+/// let peak: f64 = dawson(0.9241388730045918);
+/// assert!((peak - 0.5410442246351817).abs() < 1.0e-14);
+/// assert!(peak > dawson(0.92) && peak > dawson(0.93));
+/// 
+/// assert!((dawson(0.1) - 0.09933599239785287).abs() < 1.0e-14);
+/// assert!((dawson(10.0) - 0.05025384718759853).abs() < 1.0e-14);
+/// assert!((dawson(150.0) - 0.003333407412346228).abs() < 1.0e-14);
+/// assert_eq!(dawson(-2.0), -dawson(2.0));
+/// assert_eq!(dawson(0.0), 0.0);
+/// ```
+pub fn dawson(x: f64) -> f64 {
+
+    // The function is odd, we work with the positive half
+    let xa: f64 = x.abs();
+    let res: f64;
+
+    if xa < DAWSON_SMALL {
+        // Taylor series: sum of (-1)^n 2^n x^(2n+1) / (2n+1)!!
+        let x2: f64 = xa.powi(2);
+        let mut term: f64 = xa;
+        let mut sum: f64 = xa;
+
+        // The ratio between terms is small enough that a few are needed
+        for n in 1..DAWSON_TERMS {
+            term *= -2.0 * x2 / (2 * n + 1) as f64;
+            sum += term;
+        }
+
+        res = sum;
+    } else if xa < DAWSON_LARGE {
+        // Rybicki's method, summing the odd terms around the point
+        let n_low: i64 = ((xa - DAWSON_SPAN) / DAWSON_H).floor() as i64;
+        let n_high: i64 = ((xa + DAWSON_SPAN) / DAWSON_H).ceil() as i64;
+        let mut sum: f64 = 0.0;
+
+        for n in (n_low..=n_high).filter(|n| n % 2 != 0) {
+            sum += (-(xa - n as f64 * DAWSON_H).powi(2)).exp() / n as f64;
+        }
+
+        res = sum / PI.sqrt();
+    } else {
+        // Asymptotic expansion
+        let x2: f64 = xa.powi(-2);
+        res = 0.5 / xa * (1.0 + x2 * (0.5 + x2 * (0.75 + x2 * 1.875)));
+    }
+
+    res.copysign(x)
+}
+
+/// # Fresnel integrals
+/// 
+/// ## Definition
+/// The [Fresnel integrals](https://en.wikipedia.org/wiki/Fresnel_integral) are defined, in their normalized form, as:
+/// $$
+/// S(x) = \int_{0}^{x}\sin\left( \frac{\pi t^2}{2} \right)dt,~~~C(x) = \int_{0}^{x}\cos\left( \frac{\pi t^2}{2} \right)dt
+/// $$
+/// 
+/// For small values, we use the power series of both integrals. For larger values, we use the relation
+/// to the complementary error function:
+/// $$
+/// C(x) + iS(x) = \frac{1+i}{2}\mathrm{erf}\left( \frac{\sqrt{\pi}}{2}(1-i)x \right)
+/// $$
+/// Where $\mathrm{erfc}$ is computed with its continued fraction, evaluated with the modified Lentz method.
+/// 
+/// ## Inputs
+/// - `x`: the upper bound of the integrals ($x$)
+/// 
+/// Returns the tuple $(S(x), C(x))$, which tends to $(\pm 0.5, \pm 0.5)$ at $\pm\infty$, and `NaN` for a `NaN` input.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::fresnel;
+/// let (s1, c1): (f64, f64) = fresnel(1.0);
+/// let (s2, c2): (f64, f64) = fresnel(3.7);
+/// let (s_inf, c_inf): (f64, f64) = fresnel(1.0e6);
+/// assert!((s1 - 0.4382591473903548).abs() < 1.0e-12 && (c1 - 0.7798934003768228).abs() < 1.0e-12);
+/// assert!((s2 - 0.574980349887473).abs() < 1.0e-12 && (c2 - 0.541945662154487).abs() < 1.0e-12);
+/// assert!((s_inf - 0.5).abs() < 1.0e-6 && (c_inf - 0.5).abs() < 1.0e-6);
+/// 
+/// // Both functions are odd
+/// let (s_pos, c_pos): (f64, f64) = fresnel(0.6);
+/// let (s_neg, c_neg): (f64, f64) = fresnel(-0.6);
+/// assert_eq!(s_neg, -s_pos);
+/// assert_eq!(c_neg, -c_pos);
+/// 
+/// assert_eq!(fresnel(f64::INFINITY), (0.5, 0.5));
+/// assert_eq!(fresnel(f64::NEG_INFINITY), (-0.5, -0.5));
+/// assert!(fresnel(f64::NAN).0.is_nan() && fresnel(f64::NAN).1.is_nan());
+/// ```
+pub fn fresnel(x: f64) -> (f64, f64) {
+
+    if x.is_nan() {
+        return (f64::NAN, f64::NAN);
+    } else if x.is_infinite() {
+        return (0.5_f64.copysign(x), 0.5_f64.copysign(x));
+    }
+
+    let xa: f64 = x.abs();
+    let s: f64;
+    let c: f64;
+
+    if xa < FRESNEL_LIM {
+        // Power series of both integrals, computed together
+        let fact: f64 = FRAC_PI_2 * xa.powi(2);
+        let mut term: f64 = xa;
+        let mut sum_s: f64 = 0.0;
+        let mut sum_c: f64 = xa;
+        let mut sign: f64 = 1.0;
+        let mut k: usize = 0;
+
+        'convergence: loop {
+            k += 1;
+            term *= fact / k as f64;
+
+            // Odd terms go into S, even terms go into C, with alternating signs
+            if k % 2 == 1 {
+                sum_s += sign * term / (2 * k + 1) as f64;
+                sign = -sign;
+            } else {
+                sum_c += sign * term / (2 * k + 1) as f64;
+            }
+
+            // We exit when convergence reaches the precision
+            if term <= PRECISION * sum_c.abs() {
+                break 'convergence;
+            }
+        }
+
+        s = sum_s;
+        c = sum_c;
+    } else {
+        // Continued fraction of the complementary error function
+        let pix2: f64 = PI * xa.powi(2);
+        let b0: Complex = Complex::from(1.0, -pix2);
+        let mut h: Complex = eval_continued_fraction_complex(|n| {
+            let nf: f64 = n as f64;
+            match n {
+                0 => (Complex::ZERO, Complex::ZERO),
+                1 => (Complex::ONE, b0),
+                _ => (Complex::from(-(2.0 * nf - 3.0) * (2.0 * nf - 2.0), 0.0), b0 + 4.0 * (nf - 1.0))
+            }
+        }, PRECISION, FRACTION_MAX_ITER);
+
+        h *= Complex::from(xa, -xa);
+        let cs: Complex = Complex::from(0.5, 0.5) * (1.0 - Complex::from((0.5 * pix2).cos(), (0.5 * pix2).sin()) * h);
+        c = cs.re;
+        s = cs.im;
+    }
+
+    // Both functions are odd
+    (s.copysign(x), c.copysign(x))
+}
+
+/// # Inverse error function
+/// 
+/// ## Definition
+/// The [inverse error function](https://en.wikipedia.org/wiki/Error_function#Inverse_functions) is defined
+/// for $y \in ]-1, 1[$ by:
+/// $$
+/// \mathrm{erf}(\mathrm{erfinv}(y)) = y
+/// $$
+/// 
+/// We start from a rational approximation of the inverse of the normal distribution, using the relation:
+/// $$
+/// \mathrm{erfinv}(y) = \frac{1}{\sqrt{2}}\Phi^{-1}\left( \frac{1+y}{2} \right)
+/// $$
+/// In the central region, the result is refined with Newton's method against the error function.
+/// 
+/// ## Inputs
+/// - `y`: the value at which to evaluate the function ($y$)
+/// 
+/// Returns the inverse error function value at $y$, $\pm\infty$ for $y = \pm1$, and `NaN` outside of $[-1, 1]$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::{ erfinv, erf };
+/// for y in [-0.999, -0.7, -0.2, 0.1, 0.5, 0.9, 0.99999] {
+///     let x: f64 = erfinv(y);
+///     assert!((erf(x).re - y).abs() < 1.0e-9);
+/// }
+/// assert!((erfinv(0.5) - 0.4769362762044699).abs() < 1.0e-12);
+/// assert_eq!(erfinv(0.0), 0.0);
+/// assert_eq!(erfinv(1.0), f64::INFINITY);
+/// assert_eq!(erfinv(-1.0), f64::NEG_INFINITY);
+/// assert!(erfinv(1.2).is_nan());
+/// ```
+pub fn erfinv(y: f64) -> f64 {
+
+    // Checking the domain of definition
+    if y.is_nan() || y.abs() > 1.0 {
+        return f64::NAN;
+    } else if y.abs() == 1.0 {
+        return y * f64::INFINITY;
+    } else if y == 0.0 {
+        return 0.0;
+    }
+
+    // The function is odd, we work with the positive half
+    let ya: f64 = y.abs();
+    let p: f64 = 0.5 * (1.0 + ya);
+
+    let mut res: f64;
+
+    if p <= 1.0 - ERFINV_LOW {
+        // Central region
+        let q: f64 = p - 0.5;
+        let r: f64 = q.powi(2);
+        let num: f64 = ERFINV_A.iter().fold(0.0, |res, a| res * r + a);
+        let den: f64 = ERFINV_B.iter().fold(0.0, |res, b| res * r + b) * r + 1.0;
+        res = q * num / den / SQRT_2;
+
+        // Newton refinement, the error function being precise enough in this range
+        for _ in 0..2 {
+            res -= (erf_real(res) - ya) / (FRAC_2_SQRT_PI * (-res.powi(2)).exp());
+        }
+    } else {
+        // Tail region, computed from 1 - p directly to avoid cancellation
+        let q: f64 = (-2.0 * (0.5 * (1.0 - ya)).ln()).sqrt();
+        let num: f64 = ERFINV_C.iter().fold(0.0, |res, c| res * q + c);
+        let den: f64 = ERFINV_D.iter().fold(0.0, |res, d| res * q + d) * q + 1.0;
+        res = -num / den / SQRT_2;
+    }
+
+    res.copysign(y)
+}
+
+/// # Exponential integral $\mathrm{Ei}$
+/// 
+/// ## Definition
+/// The [exponential integral](https://en.wikipedia.org/wiki/Exponential_integral) is defined as the principal value of:
+/// $$
+/// \mathrm{Ei}(x) = -\int_{-x}^{\infty}\frac{\exp(-t)}{t}dt
+/// $$
+/// 
+/// For $x > 0$, we use the convergent series:
+/// $$
+/// \mathrm{Ei}(x) = \gamma + \ln(x) + \sum_{k=1}^{\infty}\frac{x^k}{k\cdot k!}
+/// $$
+/// Where $\gamma$ is the Euler-Mascheroni constant, and the asymptotic expansion for large values:
+/// $$
+/// \mathrm{Ei}(x) \sim \frac{\exp(x)}{x}\sum_{k=0}^{\infty}\frac{k!}{x^k}
+/// $$
+/// For $x < 0$, we use the relation $\mathrm{Ei}(x) = -E_1(-x)$.
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the function ($x$)
+/// 
+/// Returns the value of the exponential integral, $0$ at $-\infty$ and $\infty$ at $\infty$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::{ ei, e1 };
+/// assert!((ei(1.0) - 1.8951178163559368).abs() < 1.0e-14);
+/// assert!((ei(5.0) - 40.18527535580318).abs() < 1.0e-12);
+/// assert!((ei(50.0) / 1.05856368971317e20 - 1.0).abs() < 1.0e-12);
+/// assert!((ei(-2.0) - -0.04890051070806112).abs() < 1.0e-14);
+/// assert_eq!(ei(-1.5), -e1(1.5));
+/// assert_eq!(ei(0.0), f64::NEG_INFINITY);
+/// assert_eq!(ei(f64::INFINITY), f64::INFINITY);
+/// assert_eq!(ei(f64::NEG_INFINITY), 0.0);
+/// assert!(ei(f64::NAN).is_nan());
+/// ```
+pub fn ei(x: f64) -> f64 {
+
+    if x.is_nan() {
+        return f64::NAN;
+    } else if x == f64::INFINITY {
+        return f64::INFINITY;
+    } else if x == 0.0 {
+        return f64::NEG_INFINITY;
+    } else if x < 0.0 {
+        return -e1(-x);
+    }
+
+    let mut term: f64 = 1.0;
+    let mut sum: f64 = 0.0;
+
+    if x < EXPINT_LIM {
+        // Convergent series
+        for k in 1..=SERIES_MAX_ITER {
+            let kf: f64 = k as f64;
+            term *= x / kf;
+            sum += term / kf;
+
+            // We exit when convergence reaches the precision
+            if term / kf < f64::EPSILON * sum {
+                break;
+            }
+        }
+
+        sum + constant::EULER_MASCHERONI + x.ln()
+    } else {
+        // Asymptotic expansion, stopped when the terms start growing again
+        let mut prev: f64 = term;
+
+        for k in 1..=SERIES_MAX_ITER {
+            sum += term;
+            term *= k as f64 / x;
+
+            // We exit when convergence reaches the precision, or when the series diverges
+            if term < f64::EPSILON * sum || term > prev {
+                break;
+            }
+
+            prev = term;
+        }
+
+        sum * x.exp() / x
+    }
+}
+
+/// # Exponential integral $E_1$
+/// 
+/// ## Definition
+/// The [exponential integral](https://en.wikipedia.org/wiki/Exponential_integral) $E_1$ is defined as:
+/// $$
+/// E_1(x) = \int_{x}^{\infty}\frac{\exp(-t)}{t}dt
+/// $$
+/// 
+/// For $x \le 1$, we use the convergent series:
+/// $$
+/// E_1(x) = -\gamma - \ln(x) - \sum_{k=1}^{\infty}\frac{(-x)^k}{k\cdot k!}
+/// $$
+/// Otherwise, we use its continued fraction, evaluated with the modified Lentz method.
+/// For $x < 0$, we use the relation $E_1(x) = -\mathrm{Ei}(-x)$.
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the function ($x$)
+/// 
+/// Returns the value of the exponential integral, $0$ at $\infty$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::e1;
+/// assert!((e1(0.5) - 0.5597735947761608).abs() < 1.0e-14);
+/// assert!((e1(1.0) - 0.21938393439552029).abs() < 1.0e-14);
+/// assert!((e1(30.0) / 3.02155201068881e-15 - 1.0).abs() < 1.0e-12);
+/// assert_eq!(e1(0.0), f64::INFINITY);
+/// assert_eq!(e1(f64::INFINITY), 0.0);
+/// assert!(e1(f64::NAN).is_nan());
+/// ```
+pub fn e1(x: f64) -> f64 {
+
+    if x.is_nan() {
+        return f64::NAN;
+    } else if x == f64::INFINITY {
+        return 0.0;
+    } else if x == 0.0 {
+        return f64::INFINITY;
+    } else if x < 0.0 {
+        return -ei(-x);
+    }
+
+    if x <= 1.0 {
+        // Convergent series
+        let mut term: f64 = 1.0;
+        let mut sum: f64 = 0.0;
+
+        for k in 1..=SERIES_MAX_ITER {
+            let kf: f64 = k as f64;
+            term *= -x / kf;
+            sum += term / kf;
+
+            // We exit when convergence reaches the precision
+            if (term / kf).abs() < f64::EPSILON * sum.abs() {
+                break;
+            }
+        }
+
+        -constant::EULER_MASCHERONI - x.ln() - sum
+    } else {
+        // Continued fraction
+        let res: f64 = eval_continued_fraction(|n| {
+            let nf: f64 = n as f64;
+            match n {
+                0 => (0.0, 0.0),
+                1 => (1.0, x + 1.0),
+                _ => (-(nf - 1.0).powi(2), x + 2.0 * nf - 1.0)
+            }
+        }, FRACTION_TOL, FRACTION_MAX_ITER);
+
+        res * (-x).exp()
+    }
+}
+
+/// # Sine integral
+/// 
+/// ## Definition
+/// The [sine integral](https://en.wikipedia.org/wiki/Trigonometric_integral#Sine_integral) is defined as:
+/// $$
+/// \mathrm{Si}(x) = \int_{0}^{x}\mathrm{sinc}(t)dt = \sum_{k=0}^{\infty}\frac{(-1)^k x^{2k+1}}{(2k+1)(2k+1)!}
+/// $$
+/// The series is used for small values, and a continued fraction of $E_1(ix)$ otherwise.
+/// The function is odd and tends to $\pi/2$ at infinity.
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the function ($x$)
+/// 
+/// Returns the value of the sine integral, $\pm\pi/2$ at $\pm\infty$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::si;
+/// assert!((si(1.0) - 0.946083070367183).abs() < 1.0e-14);
+/// assert!((si(10.0) - 1.658347594218874).abs() < 1.0e-14);
+/// assert_eq!(si(-3.0), -si(3.0));
+/// assert!((si(1.0e8) - std::f64::consts::FRAC_PI_2).abs() < 1.0e-7);
+/// assert_eq!(si(f64::INFINITY), std::f64::consts::FRAC_PI_2);
+/// assert_eq!(si(f64::NEG_INFINITY), -std::f64::consts::FRAC_PI_2);
+/// assert!(si(f64::NAN).is_nan());
+/// ```
+pub fn si(x: f64) -> f64 {
+    trigonometric_integrals(x.abs()).0.copysign(x)
+}
+
+/// # Cosine integral
+/// 
+/// ## Definition
+/// The [cosine integral](https://en.wikipedia.org/wiki/Trigonometric_integral#Cosine_integral) is defined as:
+/// $$
+/// \mathrm{Ci}(x) = -\int_{x}^{\infty}\frac{\cos(t)}{t}dt = \gamma + \ln|x| + \sum_{k=1}^{\infty}\frac{(-1)^k x^{2k}}{2k(2k)!}
+/// $$
+/// Where $\gamma$ is the Euler-Mascheroni constant.
+/// The series is used for small values, and a continued fraction of $E_1(ix)$ otherwise.
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the function ($x$)
+/// 
+/// Returns the value of the cosine integral, $-\infty$ at $x = 0$ and $0$ at infinity.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::ci;
+/// assert!((ci(1.0) - 0.337403922900968).abs() < 1.0e-14);
+/// assert!((ci(10.0) - -0.04545643300445537).abs() < 1.0e-14);
+/// assert_eq!(ci(-3.0), ci(3.0));
+/// assert_eq!(ci(0.0), f64::NEG_INFINITY);
+/// assert_eq!(ci(f64::INFINITY), 0.0);
+/// assert!(ci(f64::NAN).is_nan());
+/// ```
+pub fn ci(x: f64) -> f64 {
+
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+
+    trigonometric_integrals(x.abs()).1
+}
+
+/// Computes both $\mathrm{Si}(x)$ and $\mathrm{Ci}(x)$ for $x \ge 0$
+fn trigonometric_integrals(x: f64) -> (f64, f64) {
+
+    if x.is_nan() {
+        return (f64::NAN, f64::NAN);
+    } else if x.is_infinite() {
+        return (FRAC_PI_2, 0.0);
+    } else if x == 0.0 {
+        return (0.0, f64::NEG_INFINITY);
+    }
+
+    if x <= SICI_LIM {
+        // Power series of both integrals, computed together
+        let mut term: f64 = 1.0;
+        let mut sum_s: f64 = 0.0;
+        let mut sum_c: f64 = 0.0;
+        let mut sign: f64 = 1.0;
+
+        for k in 1..=SERIES_MAX_ITER {
+            term *= x / k as f64;
+
+            // Odd terms go into Si, even terms go into Ci, with alternating signs
+            if k % 2 == 1 {
+                sum_s += sign * term / k as f64;
+            } else {
+                sign = -sign;
+                sum_c += sign * term / k as f64;
+            }
+
+            // We exit when convergence reaches the precision
+            if term / (k as f64) < f64::EPSILON * sum_s.abs() {
+                break;
+            }
+        }
+
+        (sum_s, constant::EULER_MASCHERONI + x.ln() + sum_c)
+    } else {
+        // Continued fraction of E1(ix)
+        let b0: Complex = Complex::from(1.0, x);
+        let mut h: Complex = eval_continued_fraction_complex(|n| {
+            let nf: f64 = n as f64;
+            match n {
+                0 => (Complex::ZERO, Complex::ZERO),
+                1 => (Complex::ONE, b0),
+                _ => (Complex::from(-(nf - 1.0).powi(2), 0.0), b0 + 2.0 * (nf - 1.0))
+            }
+        }, FRACTION_TOL, FRACTION_MAX_ITER);
+
+        h *= Complex::from(x.cos(), -x.sin());
+        (FRAC_PI_2 + h.im, -h.re)
+    }
+}
+
+/// # Lambert W function, principal branch
+/// 
+/// ## Definition
+/// The [Lambert W function](https://en.wikipedia.org/wiki/Lambert_W_function) is the inverse of $w\exp(w)$:
+/// $$
+/// W(x)\exp(W(x)) = x
+/// $$
+/// The principal branch $W_0$ is defined for $x \ge -1/e$, with $W_0(x) \ge -1$.
+/// 
+/// Starting from a series expansion near the branch point, or an asymptotic expansion for large values,
+/// the result is refined with Halley's method.
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the function ($x$)
+/// 
+/// Returns the value of $W_0(x)$, $\infty$ for $x = \infty$, or `NaN` for $x < -1/e$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::lambert_w0;
+/// # use std::f64::consts::E;
+/// assert_eq!(lambert_w0(0.0), 0.0);
+/// assert!((lambert_w0(E) - 1.0).abs() < 1.0e-15);
+/// assert_eq!(lambert_w0(-1.0 / E), -1.0);
+/// assert!((lambert_w0(-0.3678794) - -0.999526966607701).abs() < 1.0e-10);
+/// assert!((lambert_w0(1.0e10) - 20.02868541330495).abs() < 1.0e-12);
+/// assert!((lambert_w0(f64::MAX) - 703.2270331047702).abs() < 1.0e-12);
+/// assert_eq!(lambert_w0(f64::INFINITY), f64::INFINITY);
+/// assert!(lambert_w0(-0.5).is_nan());
+/// ```
+pub fn lambert_w0(x: f64) -> f64 {
+
+    let q: f64 = x + 1.0 / E;
+
+    if q.is_nan() || q < 0.0 {
+        return f64::NAN;
+    } else if q == 0.0 {
+        return -1.0;
+    } else if x == 0.0 {
+        return 0.0;
+    } else if x == f64::INFINITY {
+        return f64::INFINITY;
+    }
+
+    let w: f64 = if x < -0.25 {
+        // Series expansion near the branch point
+        let p: f64 = (2.0 * E * q).sqrt();
+        -1.0 + p - p.powi(2) / 3.0 + 11.0 / 72.0 * p.powi(3)
+    } else if x < 3.0 {
+        x.ln_1p()
+    } else {
+        // Asymptotic expansion
+        let l1: f64 = x.ln();
+        let l2: f64 = l1.ln();
+        l1 - l2 + l2 / l1
+    };
+
+    lambert_halley(x, w)
+}
+
+/// # Lambert W function, lower branch
+/// 
+/// ## Definition
+/// The [Lambert W function](https://en.wikipedia.org/wiki/Lambert_W_function) is the inverse of $w\exp(w)$:
+/// $$
+/// W(x)\exp(W(x)) = x
+/// $$
+/// The lower branch $W_{-1}$ is defined for $-1/e \le x < 0$, with $W_{-1}(x) \le -1$.
+/// 
+/// Starting from a series expansion near the branch point, or an asymptotic expansion close to zero,
+/// the result is refined with Halley's method.
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the function ($x$)
+/// 
+/// Returns the value of $W_{-1}(x)$, or `NaN` outside of $[-1/e, 0[$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::lambert_wm1;
+/// # use std::f64::consts::E;
+/// assert_eq!(lambert_wm1(-1.0 / E), -1.0);
+/// assert!((lambert_wm1(-0.3678794) - -1.00047318261309).abs() < 1.0e-10);
+/// assert!((lambert_wm1(-0.1) - -3.577152063957297).abs() < 1.0e-14);
+/// assert!((lambert_wm1(-1.0e-10) - -26.29523881924692).abs() < 1.0e-12);
+/// assert!(lambert_wm1(-0.5).is_nan());
+/// assert!(lambert_wm1(0.0).is_nan());
+/// ```
+pub fn lambert_wm1(x: f64) -> f64 {
+
+    let q: f64 = x + 1.0 / E;
+
+    if q.is_nan() || q < 0.0 || x >= 0.0 {
+        return f64::NAN;
+    } else if q == 0.0 {
+        return -1.0;
+    }
+
+    let w: f64 = if x < -0.25 {
+        // Series expansion near the branch point
+        let p: f64 = (2.0 * E * q).sqrt();
+        -1.0 - p - p.powi(2) / 3.0 - 11.0 / 72.0 * p.powi(3)
+    } else {
+        // Asymptotic expansion
+        let l1: f64 = (-x).ln();
+        let l2: f64 = (-l1).ln();
+        l1 - l2 + l2 / l1
+    };
+
+    lambert_halley(x, w)
+}
+
+/// Refines an estimation `w` of the Lambert W function at `x` with Halley's method.
+fn lambert_halley(x: f64, mut w: f64) -> f64 {
+
+    for _ in 0..LAMBERT_ITER {
+        // For positive w, the function and its derivative are divided by exp(w), which would overflow near f64::MAX
+        let (f, df): (f64, f64) = if w > 0.0 {
+            (w - x * (-w).exp(), w + 1.0)
+        } else {
+            let ew: f64 = w.exp();
+            (w * ew - x, ew * (w + 1.0))
+        };
+        let delta: f64 = f / (df - (w + 2.0) * f / (2.0 * w + 2.0));
+        w -= delta;
+
+        // We exit when convergence reaches the precision
+        if delta.abs() <= f64::EPSILON * w.abs() {
+            break;
+        }
+    }
+
+    w
+}
+
+/// # Complete elliptic integral of the first kind
+/// 
+/// ## Definition
+/// The [complete elliptic integral of the first kind](https://en.wikipedia.org/wiki/Elliptic_integral#Complete_elliptic_integral_of_the_first_kind)
+/// is defined, with the parameter $m = k^2$, as:
+/// $$
+/// K(m) = \int_{0}^{\pi/2}\frac{d\theta}{\sqrt{1 - m\sin^2(\theta)}} = \frac{\pi}{2\,\mathrm{AGM}(1, \sqrt{1-m})}
+/// $$
+/// Where $\mathrm{AGM}$ is the [arithmetic-geometric mean](https://en.wikipedia.org/wiki/Arithmetic%E2%80%93geometric_mean).
+/// 
+/// ## Inputs
+/// - `m`: the parameter of the integral ($m \le 1$)
+/// 
+/// Returns the value of $K(m)$, $\infty$ for $m = 1$, $0$ for $m = -\infty$, and `NaN` for $m > 1$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::ellipk;
+/// # use std::f64::consts::FRAC_PI_2;
+/// assert_eq!(ellipk(0.0), FRAC_PI_2);
+/// assert!((ellipk(0.5) - 1.854074677301372).abs() < 1.0e-15);
+/// assert!((ellipk(0.999999) - 8.294051463601061).abs() < 1.0e-12);
+/// assert_eq!(ellipk(1.0), f64::INFINITY);
+/// assert!((ellipk(-1.0e300) - 3.4677405831023e-148).abs() < 1.0e-160);
+/// assert_eq!(ellipk(f64::NEG_INFINITY), 0.0);
+/// assert!(ellipk(1.5).is_nan());
+/// ```
+pub fn ellipk(m: f64) -> f64 {
+    elliptic_agm(m).0
+}
+
+/// # Complete elliptic integral of the second kind
+/// 
+/// ## Definition
+/// The [complete elliptic integral of the second kind](https://en.wikipedia.org/wiki/Elliptic_integral#Complete_elliptic_integral_of_the_second_kind)
+/// is defined, with the parameter $m = k^2$, as:
+/// $$
+/// E(m) = \int_{0}^{\pi/2}\sqrt{1 - m\sin^2(\theta)}d\theta
+/// $$
+/// It is computed alongside $K(m)$ with the descending arithmetic-geometric mean recursion:
+/// $$
+/// E(m) = K(m)\left( 1 - \sum_{n=0}^{\infty}2^{n-1}c_n^2 \right)
+/// $$
+/// Where $c_0^2 = m$ and $c_{n+1} = (a_n - b_n) / 2$.
+/// 
+/// ## Inputs
+/// - `m`: the parameter of the integral ($m \le 1$)
+/// 
+/// Returns the value of $E(m)$, $\infty$ for $m = -\infty$, and `NaN` for $m > 1$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::ellipe;
+/// # use std::f64::consts::FRAC_PI_2;
+/// assert_eq!(ellipe(0.0), FRAC_PI_2);
+/// assert!((ellipe(0.5) - 1.350643881047675).abs() < 1.0e-15);
+/// assert!((ellipe(-2.0) - 2.184438142746202).abs() < 1.0e-14);
+/// assert_eq!(ellipe(1.0), 1.0);
+/// assert_eq!(ellipe(f64::NEG_INFINITY), f64::INFINITY);
+/// assert!(ellipe(1.5).is_nan());
+/// ```
+pub fn ellipe(m: f64) -> f64 {
+    elliptic_agm(m).1
+}
+
+/// Computes both $K(m)$ and $E(m)$ with the arithmetic-geometric mean.
+fn elliptic_agm(m: f64) -> (f64, f64) {
+
+    if m.is_nan() || m > 1.0 {
+        return (f64::NAN, f64::NAN);
+    } else if m == 1.0 {
+        return (f64::INFINITY, 1.0);
+    } else if m == f64::NEG_INFINITY {
+        return (0.0, f64::INFINITY);
+    }
+
+    let mut a: f64 = 1.0;
+    let mut b: f64 = (1.0 - m).sqrt();
+    let mut c: f64;
+    let mut pow: f64 = 0.5;
+    let mut sum: f64 = 0.5 * m;
+
+    for _ in 0..SERIES_MAX_ITER {
+        c = 0.5 * (a - b);
+
+        // We exit when convergence reaches the precision
+        if c.abs() <= f64::EPSILON * a || c.is_nan() {
+            break;
+        }
+
+        (a, b) = (0.5 * (a + b), (a * b).sqrt());
+        pow *= 2.0;
+        sum += pow * c.powi(2);
+    }
+
+    let k: f64 = FRAC_PI_2 / a;
+    (k, k * (1.0 - sum))
+}
+
+/// # Incomplete elliptic integral of the first kind
+/// 
+/// ## Definition
+/// The [incomplete elliptic integral of the first kind](https://en.wikipedia.org/wiki/Elliptic_integral#Incomplete_elliptic_integral_of_the_first_kind)
+/// is defined, with the parameter $m = k^2$, as:
+/// $$
+/// F(\varphi, m) = \int_{0}^{\varphi}\frac{d\theta}{\sqrt{1 - m\sin^2(\theta)}}
+/// $$
+/// It is computed with the [Carlson symmetric form](https://en.wikipedia.org/wiki/Carlson_symmetric_form) $R_F$:
+/// $$
+/// F(\varphi, m) = \sin(\varphi)R_F(\cos^2(\varphi), 1 - m\sin^2(\varphi), 1)
+/// $$
+/// Outside of $[-\pi/2, \pi/2]$, we use the quasi-periodicity $F(\varphi + j\pi, m) = F(\varphi, m) + 2jK(m)$.
+/// 
+/// ## Inputs
+/// - `phi`: the amplitude ($\varphi$)
+/// - `m`: the parameter of the integral ($m \le 1$)
+/// 
+/// Returns the value of $F(\varphi, m)$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::{ ellipf, ellipk };
+/// # use std::f64::consts::FRAC_PI_2;
+/// assert!((ellipf(FRAC_PI_2, 0.5) - ellipk(0.5)).abs() < 1.0e-15);
+/// assert!((ellipf(0.8, 0.7) - 0.864025026184176).abs() < 1.0e-15);
+/// assert!((ellipf(-4.0, 0.3) - -4.315943388399585).abs() < 1.0e-13);
+/// assert_eq!(ellipf(0.6, 0.0), 0.6);
+/// assert!(ellipf(1.2, 2.0).is_nan());
+/// ```
+pub fn ellipf(phi: f64, m: f64) -> f64 {
+
+    // Reduction of the amplitude to [-pi/2, pi/2]
+    let j: f64 = (phi / PI).round();
+    let phi_r: f64 = phi - j * PI;
+
+    let (sin, cos): (f64, f64) = phi_r.sin_cos();
+    let res: f64 = sin * carlson_rf(cos.powi(2), 1.0 - m * sin.powi(2), 1.0);
+
+    if j == 0.0 {
+        res
+    } else {
+        res + 2.0 * j * ellipk(m)
+    }
+}
+
+/// # Incomplete elliptic integral of the second kind
+/// 
+/// ## Definition
+/// The [incomplete elliptic integral of the second kind](https://en.wikipedia.org/wiki/Elliptic_integral#Incomplete_elliptic_integral_of_the_second_kind)
+/// is defined, with the parameter $m = k^2$, as:
+/// $$
+/// E(\varphi, m) = \int_{0}^{\varphi}\sqrt{1 - m\sin^2(\theta)}d\theta
+/// $$
+/// It is computed with the [Carlson symmetric forms](https://en.wikipedia.org/wiki/Carlson_symmetric_form) $R_F$ and $R_D$:
+/// $$
+/// E(\varphi, m) = \sin(\varphi)R_F(\cos^2(\varphi), 1 - m\sin^2(\varphi), 1)
+/// -\frac{m}{3}\sin^3(\varphi)R_D(\cos^2(\varphi), 1 - m\sin^2(\varphi), 1)
+/// $$
+/// Outside of $[-\pi/2, \pi/2]$, we use the quasi-periodicity $E(\varphi + j\pi, m) = E(\varphi, m) + 2jE(m)$.
+/// 
+/// ## Inputs
+/// - `phi`: the amplitude ($\varphi$)
+/// - `m`: the parameter of the integral ($m \le 1$)
+/// 
+/// Returns the value of $E(\varphi, m)$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::{ ellipe_inc, ellipe };
+/// # use std::f64::consts::FRAC_PI_2;
+/// assert!((ellipe_inc(FRAC_PI_2, 0.5) - ellipe(0.5)).abs() < 1.0e-15);
+/// assert!((ellipe_inc(0.8, 0.7) - 0.744027481870873).abs() < 1.0e-15);
+/// assert!((ellipe_inc(-4.0, 0.3) - -3.721065268268602).abs() < 1.0e-13);
+/// assert_eq!(ellipe_inc(0.6, 0.0), 0.6);
+/// ```
+pub fn ellipe_inc(phi: f64, m: f64) -> f64 {
 
-    let mut term: Complex = res_z / div;
+    // Reduction of the amplitude to [-pi/2, pi/2]
+    let j: f64 = (phi / PI).round();
+    let phi_r: f64 = phi - j * PI;
 
-    if term.modulus() <= 1.0e-8 {
-        return term;
+    let (sin, cos): (f64, f64) = phi_r.sin_cos();
+    let x: f64 = cos.powi(2);
+    let y: f64 = 1.0 - m * sin.powi(2);
+    let res: f64 = sin * carlson_rf(x, y, 1.0) - m * sin.powi(3) * carlson_rd(x, y, 1.0) / 3.0;
+
+    if j == 0.0 {
+        res
+    } else {
+        res + 2.0 * j * ellipe(m)
     }
+}
 
-    let mut res: Complex = Complex::new();
+/// Computes the Carlson symmetric form $R_F(x, y, z)$ with the duplication theorem.
+fn carlson_rf(mut x: f64, mut y: f64, mut z: f64) -> f64 {
+
+    let mut ave: f64;
+    let (mut dx, mut dy, mut dz): (f64, f64, f64);
 
     'convergence: loop {
-        res += term;
+        let (sx, sy, sz): (f64, f64, f64) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda: f64 = sx * (sy + sz) + sy * sz;
+        x = 0.25 * (x + lambda);
+        y = 0.25 * (y + lambda);
+        z = 0.25 * (z + lambda);
+        ave = (x + y + z) / 3.0;
+        dx = (ave - x) / ave;
+        dy = (ave - y) / ave;
+        dz = (ave - z) / ave;
+
+        // We exit when convergence reaches the precision, or if the inputs are invalid
+        let dev: f64 = dx.abs().max(dy.abs()).max(dz.abs());
+        if dev < CARLSON_TOL || dev.is_nan() {
+            break 'convergence;
+        }
+    }
+
+    let e2: f64 = dx * dy - dz.powi(2);
+    let e3: f64 = dx * dy * dz;
+
+    (1.0 + (e2 / 24.0 - 0.1 - 3.0 * e3 / 44.0) * e2 + e3 / 14.0) / ave.sqrt()
+}
+
+/// Computes the Carlson symmetric form $R_D(x, y, z)$ with the duplication theorem.
+fn carlson_rd(mut x: f64, mut y: f64, mut z: f64) -> f64 {
+
+    let mut sum: f64 = 0.0;
+    let mut fac: f64 = 1.0;
+    let mut ave: f64;
+    let (mut dx, mut dy, mut dz): (f64, f64, f64);
+
+    'convergence: loop {
+        let (sx, sy, sz): (f64, f64, f64) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda: f64 = sx * (sy + sz) + sy * sz;
+        sum += fac / (sz * (z + lambda));
+        fac *= 0.25;
+        x = 0.25 * (x + lambda);
+        y = 0.25 * (y + lambda);
+        z = 0.25 * (z + lambda);
+        ave = 0.2 * (x + y + 3.0 * z);
+        dx = (ave - x) / ave;
+        dy = (ave - y) / ave;
+        dz = (ave - z) / ave;
+
+        // We exit when convergence reaches the precision, or if the inputs are invalid
+        let dev: f64 = dx.abs().max(dy.abs()).max(dz.abs());
+        if dev < CARLSON_TOL || dev.is_nan() {
+            break 'convergence;
+        }
+    }
+
+    let ea: f64 = dx * dy;
+    let eb: f64 = dz.powi(2);
+    let ec: f64 = ea - eb;
+    let ed: f64 = ea - 6.0 * eb;
+    let ee: f64 = ed + 2.0 * ec;
+
+    let series: f64 = 1.0 + ed * (-3.0 / 14.0 + 9.0 / 88.0 * ed - 9.0 / 52.0 * dz * ee)
+        + dz * (ee / 6.0 + dz * (-9.0 / 22.0 * ec + 3.0 / 26.0 * dz * ea));
+
+    3.0 * sum + fac * series / (ave * ave.sqrt())
+}
+
+/// # Jacobi elliptic functions
+/// 
+/// ## Definition
+/// The [Jacobi elliptic functions](https://en.wikipedia.org/wiki/Jacobi_elliptic_functions) are defined from
+/// the amplitude $\varphi$, inverse of the incomplete elliptic integral $u = F(\varphi, m)$:
+/// $$
+/// \mathrm{sn}(u, m) = \sin(\varphi) \quad \mathrm{cn}(u, m) = \cos(\varphi) \quad \mathrm{dn}(u, m) = \sqrt{1 - m\sin^2(\varphi)}
+/// $$
+/// They are computed with the descending Landen transformation, based on the arithmetic-geometric mean.
+/// Parameters $m > 1$ are reduced with the reciprocal modulus transformation.
+/// 
+/// ## Inputs
+/// - `u`: the argument of the functions ($u$)
+/// - `m`: the parameter of the functions ($m$)
+/// 
+/// Returns the tuple $(\mathrm{sn}(u, m), \mathrm{cn}(u, m), \mathrm{dn}(u, m))$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::{ jacobi_elliptic, ellipk };
+/// let (sn, cn, dn): (f64, f64, f64) = jacobi_elliptic(0.7, 0.0);
+/// assert!((sn - 0.7_f64.sin()).abs() < 1.0e-15 && (cn - 0.7_f64.cos()).abs() < 1.0e-15 && dn == 1.0);
+/// 
+/// let (sn, cn, dn): (f64, f64, f64) = jacobi_elliptic(0.7, 1.0);
+/// assert!((sn - 0.7_f64.tanh()).abs() < 1.0e-15 && (cn - 1.0 / 0.7_f64.cosh()).abs() < 1.0e-15 && dn == cn);
+/// 
+/// let (sn, cn, dn): (f64, f64, f64) = jacobi_elliptic(1.3, 0.6);
+/// assert!((sn - 0.909895707925341).abs() < 1.0e-14);
+/// assert!((sn.powi(2) + cn.powi(2) - 1.0).abs() < 1.0e-14);
+/// assert!((dn.powi(2) + 0.6 * sn.powi(2) - 1.0).abs() < 1.0e-14);
+/// 
+/// let (sn, _, _): (f64, f64, f64) = jacobi_elliptic(ellipk(0.3), 0.3);
+/// assert!((sn - 1.0).abs() < 1.0e-14);
+/// ```
+pub fn jacobi_elliptic(u: f64, m: f64) -> (f64, f64, f64) {
+
+    let mut emc: f64 = 1.0 - m;
+
+    if emc == 0.0 {
+        let cn: f64 = 1.0 / u.cosh();
+        return (u.tanh(), cn, cn);
+    }
+
+    // Reciprocal modulus transformation for m > 1
+    let reciprocal: bool = emc < 0.0;
+    let mut u_r: f64 = u;
+    let mut d: f64 = 1.0;
+
+    if reciprocal {
+        d = m.sqrt();
+        emc /= -m;
+        u_r *= d;
+    }
+
+    // Descending Landen transformation
+    let mut em: [f64; LANDEN_STEPS] = [0.0; LANDEN_STEPS];
+    let mut en: [f64; LANDEN_STEPS] = [0.0; LANDEN_STEPS];
+    let mut a: f64 = 1.0;
+    let mut c: f64 = 1.0;
+    let mut dn: f64 = 1.0;
+    let mut l: usize = 0;
+
+    'convergence: while l < LANDEN_STEPS {
+        em[l] = a;
+        emc = emc.sqrt();
+        en[l] = emc;
+        c = 0.5 * (a + emc);
+        l += 1;
 
-        if (term / res).modulus() <= 1.0e-8 {
+        // We exit when convergence reaches the precision
+        if (a - emc).abs() <= LANDEN_TOL * a {
             break 'convergence;
         }
 
-        n += 1;
-        res_z *= z;
-        div = (n as f64).powf(s).into();
-        term = res_z / div;
+        emc *= a;
+        a = c;
+    }
+
+    u_r *= c;
+    let mut sn: f64 = u_r.sin();
+    let mut cn: f64 = u_r.cos();
+
+    // Ascending back through the transformations
+    if sn != 0.0 {
+        a = cn / sn;
+        c *= a;
+
+        for k in (0..l).rev() {
+            let b: f64 = em[k];
+            a *= c;
+            c *= dn;
+            dn = (en[k] + a) / (b + a);
+            a = c / b;
+        }
+
+        a = 1.0 / (c.powi(2) + 1.0).sqrt();
+        sn = a.copysign(sn);
+        cn = c * sn;
+    }
+
+    if reciprocal {
+        (sn / d, dn, cn)
+    } else {
+        (sn, cn, dn)
+    }
+}
+
+/// # Airy functions
+/// 
+/// ## Definition
+/// The [Airy functions](https://en.wikipedia.org/wiki/Airy_function) $\mathrm{Ai}$ and $\mathrm{Bi}$ are the
+/// two independent solutions of:
+/// $$
+/// y'' - xy = 0
+/// $$
+/// Around zero, we use the power series:
+/// $$
+/// \mathrm{Ai}(x) = c_1f(x) - c_2g(x) \quad \mathrm{Bi}(x) = \sqrt{3}(c_1f(x) + c_2g(x))
+/// $$
+/// With $c_1 = \mathrm{Ai}(0) = 3^{-2/3}/\Gamma(2/3)$ and $c_2 = -\mathrm{Ai}'(0) = 3^{-1/3}/\Gamma(1/3)$, and:
+/// $$
+/// f(x) = \sum_{k=0}^{\infty}3^k\left( \frac{1}{3} \right)_k\frac{x^{3k}}{(3k)!} \quad
+/// g(x) = \sum_{k=0}^{\infty}3^k\left( \frac{2}{3} \right)_k\frac{x^{3k+1}}{(3k+1)!}
+/// $$
+/// For positive values, $\mathrm{Ai}$ is computed from the modified Bessel function, to avoid cancellations:
+/// $$
+/// \mathrm{Ai}(x) = \frac{1}{\pi}\sqrt{\frac{x}{3}}K_{1/3}(\zeta) \quad \text{with} \quad \zeta = \frac{2}{3}x^{3/2}
+/// $$
+/// For large negative values, we use the asymptotic expansions of both functions.
+/// 
+/// ## Inputs
+/// - `x`: the value at which to evaluate the functions ($x$)
+/// 
+/// Returns the tuple $(\mathrm{Ai}(x), \mathrm{Bi}(x))$, the limits at $\pm\infty$, and `NaN` for a `NaN` input.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::{ airy, gamma };
+/// let (ai, bi): (f64, f64) = airy(0.0);
+/// assert!((ai - 3.0_f64.powf(-2.0 / 3.0) / gamma(2.0 / 3.0)).abs() < 1.0e-15);
+/// assert!((bi - 3.0_f64.powf(-1.0 / 6.0) / gamma(2.0 / 3.0)).abs() < 1.0e-15);
+/// 
+/// let (ai, bi): (f64, f64) = airy(1.0);
+/// assert!((ai - 0.1352924163128814).abs() < 1.0e-15);
+/// assert!((bi - 1.207423594952871).abs() < 1.0e-14);
+/// 
+/// let (ai, bi): (f64, f64) = airy(10.0);
+/// assert!((ai / 1.10475325528987e-10 - 1.0).abs() < 1.0e-13);
+/// assert!((bi / 455641153.548225 - 1.0).abs() < 1.0e-13);
+/// 
+/// let (ai, bi): (f64, f64) = airy(-4.0);
+/// assert!((ai - -0.0702655329492895).abs() < 1.0e-13);
+/// assert!((bi - 0.392234705706999).abs() < 1.0e-13);
+/// 
+/// let (ai, bi): (f64, f64) = airy(-20.0);
+/// assert!((ai - -0.176406127077985).abs() < 1.0e-13);
+/// assert!((bi - -0.200139309322651).abs() < 1.0e-13);
+/// 
+/// assert_eq!(airy(f64::INFINITY), (0.0, f64::INFINITY));
+/// assert_eq!(airy(f64::NEG_INFINITY), (0.0, 0.0));
+/// assert!(airy(f64::NAN).0.is_nan() && airy(f64::NAN).1.is_nan());
+/// ```
+pub fn airy(x: f64) -> (f64, f64) {
+
+    if x.is_nan() {
+        return (f64::NAN, f64::NAN);
+    } else if x == f64::INFINITY {
+        return (0.0, f64::INFINITY);
+    } else if x == f64::NEG_INFINITY {
+        return (0.0, 0.0);
+    } else if x < -AIRY_ASYMPTOTIC_LIM {
+        return airy_asymptotic_negative(-x);
+    }
+
+    // Power series of both auxiliary functions
+    let x3: f64 = x.powi(3);
+    let mut f_term: f64 = 1.0;
+    let mut g_term: f64 = x;
+    let mut f: f64 = f_term;
+    let mut g: f64 = g_term;
+
+    for i in 1..=SERIES_MAX_ITER {
+        let k: f64 = i as f64;
+        f_term *= x3 / ((3.0 * k - 1.0) * 3.0 * k);
+        g_term *= x3 / (3.0 * k * (3.0 * k + 1.0));
+        f += f_term;
+        g += g_term;
+
+        // We exit when convergence reaches the precision
+        if f_term.abs() + g_term.abs() <= f64::EPSILON * (f.abs() + g.abs()) {
+            break;
+        }
+    }
+
+    let bi: f64 = 3.0_f64.sqrt() * (AIRY_AI0 * f + AIRY_AIP0 * g);
+
+    if x <= AIRY_SERIES_LIM {
+        return (AIRY_AI0 * f - AIRY_AIP0 * g, bi);
+    }
+
+    // Trapezoidal rule on the integral representation of K_{1/3}
+    let zeta: f64 = 2.0 / 3.0 * x.powf(1.5);
+    let mut sum: f64 = 0.5;
+    let mut term: f64;
+
+    for i in 1..=SERIES_MAX_ITER {
+        let t: f64 = i as f64 * AIRY_STEP;
+        term = (-zeta * (t.cosh() - 1.0)).exp() * (t / 3.0).cosh();
+        sum += term;
+
+        // We exit when convergence reaches the precision
+        if term <= f64::EPSILON * sum {
+            break;
+        }
+    }
+
+    let k_third: f64 = AIRY_STEP * sum * (-zeta).exp();
+    ((x / 3.0).sqrt() * k_third / PI, bi)
+}
+
+/// Computes the Airy functions $\mathrm{Ai}(-x)$ and $\mathrm{Bi}(-x)$ for large positive $x$ with the asymptotic expansions.
+fn airy_asymptotic_negative(x: f64) -> (f64, f64) {
+
+    let zeta: f64 = 2.0 / 3.0 * x.powf(1.5);
+    let mut u: f64 = 1.0;
+    let mut p: f64 = 1.0;
+    let mut q: f64 = 0.0;
+    let mut prev: f64 = f64::INFINITY;
+
+    for i in 1..=SERIES_MAX_ITER {
+        let k: f64 = i as f64;
+        u *= (6.0 * k - 5.0) * (6.0 * k - 3.0) * (6.0 * k - 1.0) / ((2.0 * k - 1.0) * 216.0 * k) / zeta;
+
+        // We exit when convergence reaches the precision, or when the series diverges
+        if u < f64::EPSILON || u > prev {
+            break;
+        }
+        prev = u;
+
+        // Even terms go into P, odd terms go into Q, with alternating signs
+        let sign: f64 = (-1.0_f64).powi(k as i32 / 2);
+        if k % 2.0 == 0.0 {
+            p += sign * u;
+        } else {
+            q += sign * u;
+        }
+    }
+
+    let (sin, cos): (f64, f64) = (zeta + FRAC_PI_4).sin_cos();
+    let fact: f64 = 1.0 / (PI.sqrt() * x.powf(0.25));
+
+    (fact * (sin * p - cos * q), fact * (cos * p + sin * q))
+}
+
+/// # Gauss hypergeometric function
+/// 
+/// ## Definition
+/// The [Gauss hypergeometric function](https://en.wikipedia.org/wiki/Hypergeometric_function) is defined
+/// for $|z| < 1$ by the series:
+/// $$
+/// {}_2F_1(a, b; c; z) = \sum_{k=0}^{\infty}\frac{(a)_k(b)_k}{(c)_k}\frac{z^k}{k!}
+/// $$
+/// Where $(x)_k$ is the rising factorial. When $a$ or $b$ is a non-positive integer, the series terminates
+/// and the polynomial is valid for any $z$.
+/// 
+/// For $-1 \le z < 0$, we use the Pfaff transformation, which maps $z$ to $[0, 1/2]$:
+/// $$
+/// {}_2F_1(a, b; c; z) = (1-z)^{-a}{}_2F_1\left( a, c-b; c; \frac{z}{z-1} \right)
+/// $$
+/// For $z < -1$, when $b - a$ is not an integer, the connection formula to $1/z$:
+/// $$
+/// {}_2F_1(a, b; c; z) = \frac{\Gamma(c)\Gamma(b-a)}{\Gamma(b)\Gamma(c-a)}(-z)^{-a}{}_2F_1\left( a, a-c+1; a-b+1; \frac{1}{z} \right) +
+/// \frac{\Gamma(c)\Gamma(a-b)}{\Gamma(a)\Gamma(c-b)}(-z)^{-b}{}_2F_1\left( b, b-c+1; b-a+1; \frac{1}{z} \right)
+/// $$
+/// For $1/2 < z < 1$, when $c - a - b$ is not an integer, the connection formula to $1 - z$:
+/// $$
+/// {}_2F_1(a, b; c; z) = \frac{\Gamma(c)\Gamma(c-a-b)}{\Gamma(c-a)\Gamma(c-b)}{}_2F_1(a, b; a+b-c+1; 1-z) +
+/// (1-z)^{c-a-b}\frac{\Gamma(c)\Gamma(a+b-c)}{\Gamma(a)\Gamma(b)}{}_2F_1(c-a, c-b; c-a-b+1; 1-z)
+/// $$
+/// And for $z = 1$ with $c - a - b > 0$, Gauss's theorem:
+/// $$
+/// {}_2F_1(a, b; c; 1) = \frac{\Gamma(c)\Gamma(c-a-b)}{\Gamma(c-a)\Gamma(c-b)}
+/// $$
+/// In the remaining cases, where these parameters are integers, the series is summed directly and converges slowly
+/// as $z$ (or $z / (z-1)$ for $z < -1$) gets close to $1$.
+/// 
+/// ## Inputs
+/// - `a`, `b`: the parameters of the numerator ($a$, $b$)
+/// - `c`: the parameter of the denominator ($c$)
+/// - `z`: the value at which to evaluate the function ($z$)
+/// 
+/// Returns the value of ${}_2F_1(a, b; c; z)$, or `NaN` if the function is not defined
+/// (when $c$ is a non-positive integer), if $z$ is out of the domain, if the series does not converge,
+/// or if an input is `NaN`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::hyp2f1;
+/// let z: f64 = 0.45;
+/// assert!((hyp2f1(1.0, 1.0, 2.0, z) - -(1.0 - z).ln() / z).abs() < 1.0e-15);
+/// assert!((hyp2f1(1.0, 1.0, 2.0, -3.0) - 4.0_f64.ln() / 3.0).abs() < 1.0e-15);
+/// assert!((hyp2f1(0.3, 1.2, 2.7, 1.0) - 1.28835908153384).abs() < 1.0e-13);
+/// 
+/// // Connection formulas, close to 1 and for large negative values
+/// assert!((hyp2f1(0.5, 0.6, 1.3, 0.999999) - 2.60146353824917).abs() < 1.0e-12);
+/// assert!((hyp2f1(0.5, 1.3, 2.0, -1.0e6) - 1.46373438662244e-3).abs() < 1.0e-15);
+/// 
+/// // Integer parameters, where the series does not converge close to 1
+/// assert!(hyp2f1(1.0, 1.0, 2.0, -1.0e6).is_nan());
+/// 
+/// // Polynomial case: (1 - z)^3
+/// assert_eq!(hyp2f1(-3.0, 1.0, 1.0, -5.0), 216.0);
+/// 
+/// // Undefined cases
+/// assert!(hyp2f1(1.0, 1.0, -2.0, 0.5).is_nan());
+/// assert!(hyp2f1(1.0, 1.0, 2.0, 1.5).is_nan());
+/// assert!(hyp2f1(f64::NAN, 1.0, 2.0, 0.5).is_nan());
+/// assert!(hyp2f1(-3.0, 1.0, 1.0, f64::NAN).is_nan());
+/// ```
+pub fn hyp2f1(a: f64, b: f64, c: f64, z: f64) -> f64 {
+
+    if a.is_nan() || b.is_nan() || c.is_nan() || z.is_nan() {
+        return f64::NAN;
+    }
+
+    let non_positive_int = |x: f64| x <= 0.0 && x.fract() == 0.0;
+
+    // Terminating series, before reaching the poles of c
+    let polynomial: bool = (non_positive_int(a) && (!non_positive_int(c) || a >= c))
+        || (non_positive_int(b) && (!non_positive_int(c) || b >= c));
+
+    if non_positive_int(c) && !polynomial {
+        return f64::NAN;
+    }
+
+    if !polynomial {
+        if z == 1.0 {
+            if c - a - b <= 0.0 {
+                return f64::NAN;
+            }
+            return gamma_ratio(c, c - a - b, c - a, c - b);
+        } else if z > 1.0 {
+            return f64::NAN;
+        } else if z < -1.0 && (b - a).fract() != 0.0 {
+            return gamma_ratio(c, b - a, b, c - a) * (-z).powf(-a) * hyp2f1(a, a - c + 1.0, a - b + 1.0, 1.0 / z)
+                + gamma_ratio(c, a - b, a, c - b) * (-z).powf(-b) * hyp2f1(b, b - c + 1.0, b - a + 1.0, 1.0 / z);
+        } else if z < 0.0 {
+            return (1.0 - z).powf(-a) * hyp2f1(a, c - b, c, z / (z - 1.0));
+        } else if z > 0.5 && (c - a - b).fract() != 0.0 {
+            return gamma_ratio(c, c - a - b, c - a, c - b) * hyp2f1(a, b, a + b - c + 1.0, 1.0 - z)
+                + (1.0 - z).powf(c - a - b) * gamma_ratio(c, a + b - c, a, b) * hyp2f1(c - a, c - b, c - a - b + 1.0, 1.0 - z);
+        }
+    }
+
+    let mut term: f64 = 1.0;
+    let mut sum: f64 = term;
+
+    for i in 0..HYPERGEOMETRIC_MAX_ITER {
+        let k: f64 = i as f64;
+        term *= (a + k) * (b + k) / ((c + k) * (k + 1.0)) * z;
+        sum += term;
+
+        // We exit when convergence reaches the precision, or when the series terminates
+        if term.abs() <= f64::EPSILON * sum.abs() {
+            return sum;
+        }
+    }
+
+    f64::NAN
+}
+
+/// Computes $\Gamma(x_1)\Gamma(x_2) / (\Gamma(y_1)\Gamma(y_2))$ through the logarithms, which vanishes at the poles of the denominator.
+fn gamma_ratio(x1: f64, x2: f64, y1: f64, y2: f64) -> f64 {
+
+    if (y1 <= 0.0 && y1.fract() == 0.0) || (y2 <= 0.0 && y2.fract() == 0.0) {
+        return 0.0;
+    }
+
+    (ln_gamma(x1) + ln_gamma(x2) - ln_gamma(y1) - ln_gamma(y2)).exp()
+        * gamma_sign(x1) * gamma_sign(x2) * gamma_sign(y1) * gamma_sign(y2)
+}
+
+/// # Confluent hypergeometric function
+/// 
+/// ## Definition
+/// The [confluent hypergeometric function](https://en.wikipedia.org/wiki/Confluent_hypergeometric_function)
+/// of the first kind, or Kummer's function, is defined by the series:
+/// $$
+/// M(a, b, z) = {}_1F_1(a; b; z) = \sum_{k=0}^{\infty}\frac{(a)_k}{(b)_k}\frac{z^k}{k!}
+/// $$
+/// Where $(x)_k$ is the rising factorial. For $z < 0$, we use Kummer's transformation to avoid cancellations:
+/// $$
+/// M(a, b, z) = \exp(z)M(b-a, b, -z)
+/// $$
+/// The series is summed with a separate logarithmic scale, so that the exponential and the series are combined
+/// without overflowing for large $|z|$.
+/// 
+/// ## Inputs
+/// - `a`: the parameter of the numerator ($a$)
+/// - `b`: the parameter of the denominator ($b$)
+/// - `z`: the value at which to evaluate the function ($z$)
+/// 
+/// Returns the value of $M(a, b, z)$, or `NaN` if the function is not defined
+/// (when $b$ is a non-positive integer), if the series does not converge, or if an input is `NaN`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::hyp1f1;
+/// let z: f64 = 2.5;
+/// assert!((hyp1f1(1.0, 2.0, z) - (z.exp() - 1.0) / z).abs() < 1.0e-14);
+/// assert!((hyp1f1(1.0, 2.0, -30.0) - (1.0 - (-30.0_f64).exp()) / 30.0).abs() < 1.0e-15);
+/// assert!((hyp1f1(0.7, 0.7, -4.0) - (-4.0_f64).exp()).abs() < 1.0e-15);
+/// assert!((hyp1f1(0.5, 1.5, -9.0) - 0.29540244941984).abs() < 1.0e-15);
+/// assert!((hyp1f1(1.0, 2.0, -800.0) - 0.00125).abs() < 1.0e-16);
+/// 
+/// // Polynomial case: Laguerre polynomial L_2(z)
+/// assert_eq!(hyp1f1(-2.0, 1.0, 3.0), 1.0 - 2.0 * 3.0 + 9.0 / 2.0);
+/// assert!(hyp1f1(1.0, -2.0, 0.5).is_nan());
+/// assert!(hyp1f1(f64::NAN, 1.0, 2.0).is_nan());
+/// assert!(hyp1f1(1.0, 1.0, f64::NAN).is_nan());
+/// ```
+pub fn hyp1f1(a: f64, b: f64, z: f64) -> f64 {
+
+    if a.is_nan() || b.is_nan() || z.is_nan() {
+        return f64::NAN;
+    }
+
+    let non_positive_int = |x: f64| x <= 0.0 && x.fract() == 0.0;
+
+    // Terminating series, before reaching the poles of b
+    let polynomial: bool = non_positive_int(a) && (!non_positive_int(b) || a >= b);
+
+    if non_positive_int(b) && !polynomial {
+        return f64::NAN;
+    }
+
+    if z < 0.0 && !polynomial {
+        let (sum, scale): (f64, f64) = hyp1f1_series(b - a, b, -z);
+        return sum * (z + scale).exp();
+    }
+
+    let (sum, scale): (f64, f64) = hyp1f1_series(a, b, z);
+    sum * scale.exp()
+}
+
+/// Sums the confluent hypergeometric series, returning the sum and the logarithm of the scale it is divided by.
+fn hyp1f1_series(a: f64, b: f64, z: f64) -> (f64, f64) {
+
+    let mut term: f64 = 1.0;
+    let mut sum: f64 = term;
+    let mut scale: f64 = 0.0;
+
+    for i in 0..HYPERGEOMETRIC_MAX_ITER {
+        let k: f64 = i as f64;
+        term *= (a + k) / ((b + k) * (k + 1.0)) * z;
+        sum += term;
+
+        // We exit when convergence reaches the precision, or when the series terminates
+        if term.abs() <= f64::EPSILON * sum.abs() {
+            return (sum, scale);
+        }
+
+        if sum.abs() > HYPERGEOMETRIC_RESCALE {
+            term /= HYPERGEOMETRIC_RESCALE;
+            sum /= HYPERGEOMETRIC_RESCALE;
+            scale += HYPERGEOMETRIC_RESCALE.ln();
+        }
+    }
+
+    (f64::NAN, 0.0)
+}
+
+/// # Builds Pascal's triangle line
+/// 
+/// ## Definition
+/// We can obtain any given line of the [pascal triangle](https://en.wikipedia.org/wiki/Pascal%27s_triangle) by using
+/// the formula defined here:
+/// $$
+/// e_n^k = \binom{n}{k}
+/// $$
+/// Where $e_n^k$ is the element of the $n^{th}$ line and $i^{th}$ position.
+/// 
+/// The first position of the triangle is number as line 0, the rest follows from there.
+/// 
+/// ## Inputs
+/// - `n`: the index of the line in the triangle.
+/// 
+/// Returns the line at index `n`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::pascal_triangle;
+/// let l5: Vec<usize> = pascal_triangle(5);
+/// assert_eq!(l5, vec![1, 5, 10, 10, 5, 1]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn pascal_triangle(n: usize) -> Vec<usize> {
+    let mut res: Vec<usize> = Vec::with_capacity(n);
+
+    for k in 0..=n {
+        res.push(binomial(n, k));
     }
 
     res
 }
 
-/// # Gamma function
+/// # Iterates over Pascal's triangle
 /// 
 /// ## Definition
-/// The [gamma function](https://en.wikipedia.org/wiki/Gamma_function) is a generalization of the factorial, and is defined as:
+/// Generates the successive lines of the [pascal triangle](https://en.wikipedia.org/wiki/Pascal%27s_triangle),
+/// each one being built additively from the previous one:
 /// $$
-/// \Gamma(z) = \int_{0}^{\infty}x^{z-1}\exp(-x)dx
+/// e_n^k = e_{n-1}^{k-1} + e_{n-1}^k
+/// $$
+/// This avoids recomputing every binomial coefficient, and only overflows when the values themselves do.
+/// 
+/// ## Inputs
+/// - `count`: the number of lines to generate, starting from line 0.
+/// 
+/// Returns an iterator over the first `count` lines of the triangle.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::{ pascal_rows, pascal_triangle };
+/// let rows: Vec<Vec<usize>> = pascal_rows(6).collect();
+/// assert_eq!(rows.len(), 6);
+/// assert_eq!(rows[0], vec![1]);
+/// assert_eq!(rows[3], vec![1, 3, 3, 1]);
+/// 
+/// for (n, row) in rows.iter().enumerate() {
+///     assert_eq!(*row, pascal_triangle(n));
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+pub fn pascal_rows(count: usize) -> impl Iterator<Item = Vec<usize>> {
+    let mut row: Vec<usize> = Vec::new();
+
+    (0..count).map(move |_| {
+        // Each element is updated in reverse, so that the previous values are still available
+        for k in (1..row.len()).rev() {
+            row[k] += row[k - 1];
+        }
+        row.push(1);
+        row.clone()
+    })
+}
+
+/// # Catalan numbers
+/// 
+/// ## Definition
+/// The [Catalan numbers](https://en.wikipedia.org/wiki/Catalan_number) are defined as:
+/// $$
+/// C_n = \frac{1}{n+1}\binom{2n}{n} = \prod_{k=2}^{n}\frac{n+k}{k}
 /// $$
+/// They are computed with the recurrence $C_{k+1} = \frac{2(2k+1)}{k+2}C_k$.
+/// 
+/// The intermediate products of the recurrence overflow `u128` for `n` greater than 65, which panics.
+/// 
+/// ## Inputs
+/// - `n`: the index of the number ($n$)
+/// 
+/// Returns the $n^{th}$ Catalan number.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::catalan;
+/// assert_eq!(catalan(0), 1);
+/// assert_eq!(catalan(4), 14);
+/// assert_eq!(catalan(40), 2_622_127_042_276_492_108_820);
+/// ```
+pub fn catalan(n: usize) -> u128 {
+
+    let mut res: u128 = 1;
+
+    for k in 0..n as u128 {
+        res = res.checked_mul(2 * (2 * k + 1)).expect("catalan: overflow of u128 for n > 65") / (k + 2);
+    }
+
+    res
+}
+
+/// # Fibonacci numbers
 /// 
-/// This function provides result for any real number, and returns the same result for integer as a factorial:
+/// ## Definition
+/// The [Fibonacci numbers](https://en.wikipedia.org/wiki/Fibonacci_sequence) are defined by the recurrence:
 /// $$
-/// \Gamma(n) = (n-1)!
+/// F_0 = 0 \quad F_1 = 1 \quad F_{n} = F_{n-1} + F_{n-2}
 /// $$
 /// 
-/// With the current computation scheme, we limit the precision of the computation in exchange for speed.
-/// Typical values are achieve within a `1.0e-5` margin of error. Changing the method to another one
-/// might grant some more speed and lower the error on the results.
+/// The result overflows `u128` for `n` greater than 186, which panics.
 /// 
 /// ## Inputs
-/// - `x`: the value to evaluate ($x$).
+/// - `n`: the index of the number ($n$)
 /// 
-/// Returns the value of the gamma function.
+/// Returns the $n^{th}$ Fibonacci number.
 /// 
 /// ## Example
 /// ```
-/// # use scilib::math::basic::gamma;
-/// let res_1: f64 = gamma(2.3);
-/// let res_2: f64 = gamma(-0.45);
-/// assert!((res_1 - 1.16671190).abs() < 1.0e-5);
-/// assert!((res_2 - -3.591387).abs() < 1.0e-5);
+/// # use scilib::math::basic::fibonacci;
+/// assert_eq!(fibonacci(0), 0);
+/// assert_eq!(fibonacci(1), 1);
+/// assert_eq!(fibonacci(10), 55);
+/// assert_eq!(fibonacci(186), 332_825_110_087_067_562_321_196_029_789_634_457_848);
 /// ```
-pub fn gamma<T>(value: T) -> f64
-where T: Into<f64> {
-
-    let x: f64 = value.into();
-
-    let mut n: f64 = 1.0;      // Order counter
+pub fn fibonacci(n: usize) -> u128 {
 
-    // The values of each term and the result
-    let mut term: f64 = x.exp() / (1.0 + x);
-    let mut res: f64 = 1.0;
-
-    // If the first term is already too small we exit directly
-    if (term - 1.0).abs() < PRECISION {
-        return res;
+    if n == 0 {
+        return 0;
     }
 
-    // Computing the terms of the infinite series
-    'convergence: loop {
-        res *= term;
-
-        //If the changes become too small, we stop
-        if (term - 1.0).abs() < PRECISION {
-            break 'convergence;
-        }
+    let mut res: (u128, u128) = (0, 1);
 
-        // Updating the values
-        n += 1.0;
-        term = (x / n).exp() / (1.0 + x / n);
+    // Stopping at the last pair to avoid computing the next number
+    for _ in 1..n {
+        res = (res.1, res.0.checked_add(res.1).expect("fibonacci: overflow of u128 for n > 186"));
     }
 
-    res * (-x * constant::EULER_MASCHERONI).exp() / x
+    res.1
 }
 
-/// # Euler Beta function
+/// # Bell numbers
 /// 
 /// ## Definition
-/// The [beta function](https://en.wikipedia.org/wiki/Beta_function) is an integral similar to the gamma function,
-/// and it is defined as:
-/// $$
-/// B(x,y) = B(y,x) = \int_{0}^{1}t^{x-1}(1-t)^{y-1}dt
-/// $$
+/// The [Bell numbers](https://en.wikipedia.org/wiki/Bell_number) count the partitions of a set of $n$ elements.
+/// They are computed with the [Bell triangle](https://en.wikipedia.org/wiki/Bell_triangle), where each line starts
+/// with the last element of the previous one, and each element is the sum of its left neighbour and of the element
+/// above it. The Bell number $B_n$ is the first element of the $n^{th}$ line.
 /// 
-/// The current implementation relies on the relation:
-/// $$
-/// B(x,y) = \frac{\Gamma(x)\Gamma(y)}{\Gamma(x+y)}
-/// $$
-/// Which is easier to manage, but could be slower and slightly less precise.
-/// Future updates will improve this function.
+/// The result overflows `u128` for `n` greater than 42, which panics.
 /// 
 /// ## Inputs
-/// - `x` and `y` are the points at which to evaluate the function ($x$, $y$).
+/// - `n`: the index of the number ($n$)
 /// 
+/// Returns the $n^{th}$ Bell number.
 /// 
 /// ## Example
 /// ```
-/// # use scilib::math::basic::beta;
-/// let res: f64 = beta(1, 1.1);
-/// let comp1: f64 = beta(3, 2);
-/// let comp2: f64 = beta(2, 3);
-/// assert!((res - 0.909090).abs() < 1.0e-5);
-/// assert_eq!(comp1, comp2);
+/// # use scilib::math::basic::bell;
+/// assert_eq!(bell(0), 1);
+/// assert_eq!(bell(1), 1);
+/// assert_eq!(bell(4), 15);
+/// assert_eq!(bell(10), 115_975);
 /// ```
-pub fn beta<T, U>(x: T, y: U) -> f64
-where T: Into<f64> + Copy, U: Into<f64> + Copy {
+#[cfg(feature = "alloc")]
+pub fn bell(n: usize) -> u128 {
 
-    let t1: f64 = gamma(x);
-    let t2: f64 = gamma(y);
-    let b: f64 = gamma(x.into() + y.into());
-    
-    t1 * t2 / b
+    let mut line: Vec<u128> = vec![1];
+
+    // Each line ends with the first element of the next one, so we stop one line early
+    for _ in 1..n {
+        let mut next: Vec<u128> = Vec::with_capacity(line.len() + 1);
+        next.push(line[line.len() - 1]);
+
+        for val in &line {
+            next.push(next[next.len() - 1].checked_add(*val).expect("bell: overflow of u128 for n > 42"));
+        }
+
+        line = next;
+    }
+
+    if n == 0 {
+        1
+    } else {
+        line[line.len() - 1]
+    }
 }
 
-/// # Sigmoid function
+/// # Stirling numbers of the first kind
 /// 
 /// ## Definition
-/// The [sigmoid function](https://en.wikipedia.org/wiki/Sigmoid_function) is defined as:
+/// The signed [Stirling numbers of the first kind](https://en.wikipedia.org/wiki/Stirling_numbers_of_the_first_kind)
+/// are the coefficients of the falling factorial, $(x)_n = \sum_k s(n, k)x^k$. They follow the recurrence:
 /// $$
-/// \sigma(x) = \frac{1}{1 + \exp(-x)} = 1 - \sigma(-x)
+/// s(n+1, k) = s(n, k-1) - n~s(n, k)
 /// $$
+/// With $s(0, 0) = 1$ and $s(n, 0) = s(0, k) = 0$ otherwise.
 /// 
 /// ## Inputs
-/// - `x`: the value at which to evaluate the function ($x$).
+/// - `n`: the number of elements ($n$)
+/// - `k`: the number of cycles ($k$)
 /// 
-/// Returns the value of the sigmoid function.
+/// Returns $s(n, k)$, whose magnitude counts the permutations of $n$ elements with $k$ cycles.
+/// 
+/// All the values fit in an `i64` for $n \le 20$. Beyond, the function panics when $s(n, k)$ overflows,
+/// as for $s(21, 3)$, while the values far enough from the first column remain available.
 /// 
 /// ## Example
 /// ```
-/// # use scilib::math::basic::sigmoid;
-/// let res1: f64 = sigmoid(-1.0);
-/// let res2: f64 = sigmoid(0.0);
-/// let res_comp: f64 = sigmoid(1.0);
-/// assert!((res1 - 0.26894142136999).abs() < 1.0e-12);
-/// assert_eq!(res2, 0.5);
-/// assert_eq!(res1, 1.0 - res_comp);
+/// # use scilib::math::basic::{ stirling_first, factorial };
+/// assert_eq!(stirling_first(0, 0), 1);
+/// assert_eq!(stirling_first(3, 0), 0);
+/// assert_eq!(stirling_first(4, 2), 11);
+/// assert_eq!(stirling_first(5, 2), -50);
+/// assert_eq!(stirling_first(3, 5), 0);
+/// assert_eq!(stirling_first(40, 39), -780);
+/// 
+/// // The magnitudes of a line sum to n!
+/// let sum: i64 = (0..=6).map(|k| stirling_first(6, k).abs()).sum();
+/// assert_eq!(sum as usize, factorial(6_usize));
 /// ```
-pub fn sigmoid(x: f64) -> f64 {
-    1.0 / (1.0 + (-x).exp())
+#[cfg(feature = "alloc")]
+pub fn stirling_first(n: usize, k: usize) -> i64 {
+
+    if k > n {
+        return 0;
+    }
+
+    // Only the first k+1 elements of each line are needed
+    let mut line: Vec<i64> = vec![0; k + 1];
+    line[0] = 1;
+
+    for m in 0..n {
+        // The elements that do not lead to s(n, k) are skipped, so that only its overflow panics
+        for j in ((k + m + 1).saturating_sub(n).max(1)..=k.min(m + 1)).rev() {
+            line[j] = (m as i64).checked_mul(line[j])
+                .and_then(|val| line[j - 1].checked_sub(val))
+                .expect("stirling_first: overflow of i64");
+        }
+        line[0] = 0;
+    }
+
+    line[k]
 }
 
-/// # Gaussian function
+/// # Stirling numbers of the second kind
 /// 
 /// ## Definition
-/// The [gaussian function](https://en.wikipedia.org/wiki/Gaussian_function) is defined as:
+/// The [Stirling numbers of the second kind](https://en.wikipedia.org/wiki/Stirling_numbers_of_the_second_kind)
+/// count the partitions of a set of $n$ elements into $k$ non-empty subsets. They follow the recurrence:
 /// $$
-/// g(x) = a\cdot\exp\left(-\frac{(b - x)^2}{2c^2}\right)
+/// S(n+1, k) = k~S(n, k) + S(n, k-1)
 /// $$
+/// With $S(0, 0) = 1$ and $S(n, 0) = S(0, k) = 0$ otherwise.
 /// 
 /// ## Inputs
-/// - `a`: the amplitude ($a$)
-/// - `b`: the center ($b$)
-/// - `c`: the standard deviation ($c$)
-/// - `x`: the value to evaluate ($x$).
+/// - `n`: the number of elements ($n$)
+/// - `k`: the number of subsets ($k$)
 /// 
-/// Returns the value of the gaussian function with parameters $a$, $b$, $c$ at $x$.
+/// Returns $S(n, k)$.
+/// 
+/// All the values fit in a `u64` for $n \le 26$. Beyond, the function panics when $S(n, k)$ overflows,
+/// as for $S(27, 8)$, while the values close to the first and last columns remain available.
 /// 
 /// ## Example
 /// ```
-/// # use scilib::math::basic::gaussian;
-/// let res1: f64 = gaussian(1.0, 2.0, 3.0, 0.0);
-/// assert_eq!(res1, 0.8007374029168081);
+/// # use scilib::math::basic::{ stirling_second, bell };
+/// assert_eq!(stirling_second(0, 0), 1);
+/// assert_eq!(stirling_second(3, 0), 0);
+/// assert_eq!(stirling_second(4, 2), 7);
+/// assert_eq!(stirling_second(10, 4), 34_105);
+/// assert_eq!(stirling_second(3, 5), 0);
+/// assert_eq!(stirling_second(40, 39), 780);
+/// 
+/// // A line sums to the Bell number
+/// let sum: u64 = (0..=8).map(|k| stirling_second(8, k)).sum();
+/// assert_eq!(sum as u128, bell(8));
 /// ```
-pub fn gaussian(a: f64, b: f64, c: f64, x: f64) -> f64 {
-    a * (-(x - b).powi(2) / (2.0 * c.powi(2))).exp()
+#[cfg(feature = "alloc")]
+pub fn stirling_second(n: usize, k: usize) -> u64 {
+
+    if k > n {
+        return 0;
+    }
+
+    // Only the first k+1 elements of each line are needed
+    let mut line: Vec<u64> = vec![0; k + 1];
+    line[0] = 1;
+
+    for m in 0..n {
+        // The elements that do not lead to S(n, k) are skipped, so that only its overflow panics
+        for j in ((k + m + 1).saturating_sub(n).max(1)..=k.min(m + 1)).rev() {
+            line[j] = (j as u64).checked_mul(line[j])
+                .and_then(|val| line[j - 1].checked_add(val))
+                .expect("stirling_second: overflow of u64");
+        }
+        line[0] = 0;
+    }
+
+    line[k]
 }
 
-/// # Normalized gaussian function
+/// # Bernoulli numbers
 /// 
 /// ## Definition
-/// The [normalized gaussian function](https://en.wikipedia.org/wiki/Gaussian_function) is defined as:
+/// The [Bernoulli numbers](https://en.wikipedia.org/wiki/Bernoulli_number) are defined by the generating function:
 /// $$
-/// g(x) = \frac{1}{\sigma\sqrt{2\pi}}\cdot\exp\left( -\frac{(x - \mu)^2}{2\sigma^2} \right)
+/// \frac{t}{\exp(t) - 1} = \sum_{n=0}^{\infty}B_n\frac{t^n}{n!}
+/// $$
+/// Following the $B_n^{-}$ convention, $B_1 = -1/2$, and all the other odd numbers are null.
+/// The even numbers are computed from the tangent numbers $T_{2n-1}$, obtained with the
+/// [boustrophedon transform](https://en.wikipedia.org/wiki/Alternating_permutation), which only involves additions:
+/// $$
+/// B_{2n} = (-1)^{n-1}\frac{2n\,T_{2n-1}}{2^{2n}(2^{2n}-1)}
+/// $$
+/// Beyond $n = 186$, where the tangent numbers overflow, they are computed from the zeta function instead:
+/// $$
+/// B_{2n} = (-1)^{n-1}\frac{2(2n)!}{(2\pi)^{2n}}\zeta(2n)
 /// $$
 /// 
 /// ## Inputs
-/// - `mu`: the expected value ($\mu$)
-/// - `sigma`: the variance ($\sigma$)
-/// - `x`: the value to evaluate ($x$)
+/// - `n`: the index of the number ($n$)
 /// 
-/// Returns the value of the normalized gaussian function with parameters
+/// Returns the $n^{th}$ Bernoulli number, which overflows to $\pm\infty$ beyond $n = 258$.
 /// 
 /// ## Example
 /// ```
-/// # use scilib::math::basic::gaussian_normed;
-/// let res: f64 = gaussian_normed(1.0, 2.0, 3.0);
-/// assert!((res - 0.120985362259).abs() < 1e-12);
+/// # use scilib::math::basic::bernoulli_number;
+/// assert_eq!(bernoulli_number(0), 1.0);
+/// assert_eq!(bernoulli_number(1), -0.5);
+/// assert_eq!(bernoulli_number(2), 1.0 / 6.0);
+/// assert_eq!(bernoulli_number(7), 0.0);
+/// assert_eq!(bernoulli_number(12), -691.0 / 2730.0);
+/// assert!((bernoulli_number(30) / 8615841276005.0 * 14322.0 - 1.0).abs() < 1.0e-14);
+/// 
+/// // Large numbers, up to the overflow
+/// assert!((bernoulli_number(200) / -3.6470772645191354e215 - 1.0).abs() < 1.0e-13);
+/// assert!((bernoulli_number(258) / 1.3352784187354634e306 - 1.0).abs() < 1.0e-13);
+/// assert_eq!(bernoulli_number(260), f64::NEG_INFINITY);
 /// ```
-pub fn gaussian_normed(mu: f64, sigma: f64, x: f64) -> f64 {
-    (1.0 / (sigma * TAU.sqrt())) * (-(x - mu).powi(2) / (2.0 * sigma.powi(2))).exp()
+pub fn bernoulli_number(n: usize) -> f64 {
+
+    match n {
+        0 => 1.0,
+        1 => -0.5,
+        _ if n % 2 == 1 => 0.0,
+        _ if n > BERNOULLI_TANGENT_LIM => {
+            // The factorial and the power are interleaved, so that only the result can overflow
+            let mut res: f64 = 2.0 * zeta(n as f64, 1.0).re;
+            for k in 1..=n {
+                res *= k as f64 / TAU;
+                if res.is_infinite() {
+                    break;
+                }
+            }
+            if n % 4 == 0 { -res } else { res }
+        },
+        _ => {
+            let tangent: f64 = zigzag_number(n - 1);
+            let pow: f64 = 2.0_f64.powi(n as i32);
+            (-1.0_f64).powi(n as i32 / 2 - 1) * n as f64 * tangent / (pow * (pow - 1.0))
+        }
+    }
 }
 
-/// # Error function
+/// # Euler numbers
 /// 
 /// ## Definition
-/// The [error function](https://en.wikipedia.org/wiki/Error_function) is defined as:
+/// The [Euler numbers](https://en.wikipedia.org/wiki/Euler_numbers) are defined by the generating function:
 /// $$
-/// \mathrm{erf}(z) = \frac{2}{\sqrt{\pi}}\int_{0}^{z}\exp(-t^2)dt
+/// \frac{1}{\cosh(t)} = \sum_{n=0}^{\infty}E_n\frac{t^n}{n!}
+/// $$
+/// The odd numbers are null, and the even numbers are obtained from the secant numbers $S_{2n}$, computed with the
+/// [boustrophedon transform](https://en.wikipedia.org/wiki/Alternating_permutation):
+/// $$
+/// E_{2n} = (-1)^nS_{2n}
 /// $$
-/// 
-/// We define the error function for complex number.
-/// 
-/// WARNING: the erf function will soon become f64 only, moving the erf function for complex as a
-/// complex function directly.
 /// 
 /// ## Inputs
-/// - `val`: the point at which to evaluate the function ($z$)
+/// - `n`: the index of the number ($n$)
 /// 
-/// Returns the error function value at $z$.
+/// Returns the $n^{th}$ Euler number.
 /// 
 /// ## Example
 /// ```
-/// # use scilib::math::complex::Complex;
-/// # use scilib::math::basic::erf;
-/// let r = erf(2.1);
-/// let c = erf(Complex::from(-0.1, 0.7));
-/// assert!((r.re - 0.997021).abs() < 1.0e-5);
-/// assert!((c.re - -0.18297754).abs() < 1.0e-5 && (c.im - 0.92747498).abs() < 1.0e-5);
+/// # use scilib::math::basic::euler_number;
+/// assert_eq!(euler_number(0), 1.0);
+/// assert_eq!(euler_number(2), -1.0);
+/// assert_eq!(euler_number(3), 0.0);
+/// assert_eq!(euler_number(4), 5.0);
+/// assert_eq!(euler_number(10), -50521.0);
 /// ```
-pub fn erf<T>(val: T) -> Complex
-where T: Into<Complex> {
+pub fn euler_number(n: usize) -> f64 {
 
-    let x: Complex = val.into();
+    if n % 2 == 1 {
+        return 0.0;
+    }
 
-    let mut n: f64 = 0.0;               // Index of iteration
-    let mut d1: f64 = 1.0;              // First div
-    let mut d2: f64;                    // Second div
-    let mut sg: f64 = 1.0;              // Sign of the term
-    
-    let mut term: Complex = x;          // Term at each iter
-    let mut res: Complex = 0.0.into();  // Result
+    (-1.0_f64).powi(n as i32 / 2) * zigzag_number(n)
+}
 
-    // If the term is too small we exit
-    if term.modulus() < PRECISION {
-        return res;
+/// Computes the zigzag number of index `n` with the Seidel-Entringer-Arnold triangle.
+/// The even indexes give the secant numbers, and the odd indexes the tangent numbers.
+fn zigzag_number(n: usize) -> f64 {
+
+    if n >= ZIGZAG_LEN {
+        return f64::INFINITY;
     }
 
-    'convergence: loop {
-        res += term;
+    let mut line: [f64; ZIGZAG_LEN] = [0.0; ZIGZAG_LEN];
+    let mut next: [f64; ZIGZAG_LEN] = [0.0; ZIGZAG_LEN];
+    line[0] = 1.0;
 
-        // We exit when convergence reaches the precision
-        if (term / res).modulus() < PRECISION {
-            break 'convergence;
+    for k in 1..=n {
+        next[0] = 0.0;
+
+        for j in 0..k {
+            next[j + 1] = next[j] + line[k - 1 - j];
         }
 
-        n += 1.0;
-        sg *= -1.0;
-        d1 *= n;
-        d2 = 2.0 * n + 1.0;
-        term = sg * x.powf(d2) / (d1 * d2);
+        core::mem::swap(&mut line, &mut next);
     }
 
-    FRAC_2_SQRT_PI * res
+    line[n]
 }
 
-/// # Complementary error function
-/// 
-/// ## Definition
-/// The [complementary error function](https://en.wikipedia.org/wiki/Error_function) is defined as:
-/// $$
-/// \mathrm{erfc}(z) = 1 - \mathrm{erf}(z)
-/// $$
-/// 
-/// ## Inputs
-/// - `val`: the point at which to evaluate the function ($z$)
-/// 
-/// Returns the complement of the error function, `1 - erf(z)`.
-/// 
-/// ## Example
-/// ```
-/// # use scilib::math::complex::Complex;
-/// # use scilib::math::basic::erfc;
-/// let c = Complex::from(1.25, 0.3);
-/// let res = erfc(c);
-/// assert!((res.re - 0.0505570).abs() < 1.0e-5 && (res.im - -0.0663174).abs() < 1.0e-5);
-/// ```
-pub fn erfc<T>(val: T) -> Complex
-where T: Into<Complex> {
-    Complex::unity() - erf(val)
+/// Computes the Bernoulli polynomial $B_n(z) = \sum_{k=0}^{n}\binom{n}{k}B_{n-k}z^k$ from the Bernoulli numbers.
+fn bernoulli_polynomial(n: usize, z: Complex) -> Complex {
+
+    let mut res: Complex = Complex::new();
+    let mut binom: f64 = 1.0;
+
+    for k in 0..=n {
+        res += binom * bernoulli_number(n - k) * z.powi(k as i32);
+        binom *= (n - k) as f64 / (k + 1) as f64;
+    }
+
+    res
 }
 
-/// # Imaginary error function
+/// # Harmonic numbers
 /// 
 /// ## Definition
-/// The [imaginary error function](https://en.wikipedia.org/wiki/Error_function) is defined as:
+/// The [harmonic numbers](https://en.wikipedia.org/wiki/Harmonic_number) are defined as:
 /// $$
-/// \mathrm{erfi}(z) = -i\cdot\mathrm{erf}(iz)
+/// H_n = \sum_{k=1}^{n}\frac{1}{k} = \psi(n+1) + \gamma
 /// $$
+/// Where $\psi$ is the digamma function, and $\gamma$ the Euler-Mascheroni constant.
 /// 
 /// ## Inputs
-/// - `val`: the point at which to evaluate the function ($z$)
+/// - `n`: the index of the number ($n$)
 /// 
-/// Returns the imaginary error function, $-i\mathrm{erf}(iz)$.
+/// Returns the $n^{th}$ harmonic number.
 /// 
 /// ## Example
 /// ```
-/// # use scilib::math::complex::Complex;
-/// # use scilib::math::basic::erfi;
-/// let c = Complex::from(0.07, -1.1);
-/// let res = erfi(c);
-/// assert!((res.re - 0.02349883).abs() < 1.0e-5 && (res.im - -0.88201955).abs() < 1.0e-5);
+/// # use scilib::math::basic::{ harmonic, digamma };
+/// # use scilib::constant::EULER_MASCHERONI;
+/// assert_eq!(harmonic(0), 0.0);
+/// assert!((harmonic(4) - 25.0 / 12.0).abs() < 1.0e-15);
+/// assert!((harmonic(1000) - (digamma(1001.0) + EULER_MASCHERONI)).abs() < 1.0e-13);
 /// ```
-pub fn erfi<T>(val: T) -> Complex
-where T: Into<Complex> {
-    -Complex::i() * erf(Complex::i() * val)
+pub fn harmonic(n: usize) -> f64 {
+    harmonic_gen(n, 1.0)
 }
 
-/// # Builds Pascal's triangle line
+/// # Generalized harmonic numbers
 /// 
 /// ## Definition
-/// We can obtain any given line of the [pascal triangle](https://en.wikipedia.org/wiki/Pascal%27s_triangle) by using
-/// the formula defined here:
+/// The [generalized harmonic numbers](https://en.wikipedia.org/wiki/Harmonic_number#Generalized_harmonic_numbers)
+/// of order $r$ are defined as:
 /// $$
-/// e_n^k = \binom{n}{k}
+/// H_n^{(r)} = \sum_{k=1}^{n}\frac{1}{k^r}
 /// $$
-/// Where $e_n^k$ is the element of the $n^{th}$ line and $i^{th}$ position.
-/// 
-/// The first position of the triangle is number as line 0, the rest follows from there.
+/// The sum starts with the smallest terms to limit the rounding errors.
 /// 
 /// ## Inputs
-/// - `n`: the index of the line in the triangle.
+/// - `n`: the index of the number ($n$)
+/// - `r`: the order of the number ($r$)
 /// 
-/// Returns the line at index `n`.
+/// Returns the $n^{th}$ generalized harmonic number of order $r$.
 /// 
 /// ## Example
 /// ```
-/// # use scilib::math::basic::pascal_triangle;
-/// let l5: Vec<usize> = pascal_triangle(5);
-/// assert_eq!(l5, vec![1, 5, 10, 10, 5, 1]);
+/// # use scilib::math::basic::harmonic_gen;
+/// # use std::f64::consts::PI;
+/// assert_eq!(harmonic_gen(3, 2.0), 49.0 / 36.0);
+/// assert_eq!(harmonic_gen(5, 0.0), 5.0);
+/// assert!((harmonic_gen(100_000, 2.0) - PI.powi(2) / 6.0).abs() < 1.0e-5);
 /// ```
-pub fn pascal_triangle(n: usize) -> Vec<usize> {
-    let mut res: Vec<usize> = Vec::with_capacity(n);
-
-    for k in 0..=n {
-        res.push(binomial(n, k));
-    }
-
-    res
+pub fn harmonic_gen(n: usize, r: f64) -> f64 {
+    (1..=n).rev().map(|k| (k as f64).powf(-r)).sum()
 }
 
 /// # Levi-Civita symbol
@@ -595,7 +5056,8 @@ pub fn pascal_triangle(n: usize) -> Vec<usize> {
 /// Computes the result of the $n$ dimensional Levi-Civita symbol. Returns 1 if the indexes are an even permutation
 /// and -1 if odd permutation. If there is any repetition it returns 0.
 /// 
-/// The function does not perform a check to ensure that all numbers are account for.
+/// The function does not perform a check to ensure that all numbers are account for, see `permutation_sign`
+/// for a checked version.
 /// 
 /// ## Inputs
 /// - `val`: the list of the indexes to evaluate
@@ -612,6 +5074,7 @@ pub fn pascal_triangle(n: usize) -> Vec<usize> {
 /// assert_eq!(neg, -1);
 /// assert_eq!(z, 0);
 /// ```
+#[cfg(feature = "alloc")]
 pub fn levi_civita(val: Vec<isize>) -> isize {
 
     let mut res: isize = 1;
@@ -625,4 +5088,83 @@ pub fn levi_civita(val: Vec<isize>) -> isize {
     res
 }
 
+/// # Kronecker delta
+/// 
+/// ## Definition
+/// The [Kronecker delta](https://en.wikipedia.org/wiki/Kronecker_delta) is defined as:
+/// $$
+/// \delta_{ij} = 1 \text{ if } i = j, \quad \delta_{ij} = 0 \text{ otherwise}
+/// $$
+/// 
+/// ## Inputs
+/// - `i`, `j`: the indexes to compare
+/// 
+/// Returns 1 if both indexes are equal, 0 otherwise.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::kronecker_delta;
+/// assert_eq!(kronecker_delta(3, 3), 1);
+/// assert_eq!(kronecker_delta(-1, 2), 0);
+/// ```
+pub fn kronecker_delta(i: isize, j: isize) -> isize {
+    (i == j) as isize
+}
+
+/// # Sign of a permutation
+/// 
+/// ## Definition
+/// The [sign of a permutation](https://en.wikipedia.org/wiki/Parity_of_a_permutation) $\sigma$ of $\{0, ..., n-1\}$
+/// is $(-1)^{n-c}$, where $c$ is the number of cycles of the permutation. It matches the Levi-Civita symbol
+/// $\epsilon_{\sigma(0)...\sigma(n-1)}$.
+/// 
+/// Unlike `levi_civita`, the input is checked to be a genuine permutation of `0..n`.
+/// 
+/// ## Inputs
+/// - `perm`: the permutation, as the list of the images of `0..n`
+/// 
+/// Returns 1 for an even permutation, -1 for an odd permutation, and 0 if the input is not a permutation.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::basic::permutation_sign;
+/// assert_eq!(permutation_sign(&[1, 2, 3, 4, 0]), 1);
+/// assert_eq!(permutation_sign(&[2, 1, 0]), -1);
+/// assert_eq!(permutation_sign(&[]), 1);
+/// assert_eq!(permutation_sign(&[0, 0, 1]), 0);
+/// assert_eq!(permutation_sign(&[1, 2, 3]), 0);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn permutation_sign(perm: &[usize]) -> isize {
+
+    let n: usize = perm.len();
+    let mut visited: Vec<bool> = vec![false; n];
+
+    // Checking that every index appears exactly once
+    for &p in perm {
+        if p >= n || visited[p] {
+            return 0;
+        }
+        visited[p] = true;
+    }
+
+    // Counting the cycles of the permutation
+    let mut cycles: usize = 0;
+    visited.fill(false);
+
+    for start in 0..n {
+        if !visited[start] {
+            cycles += 1;
+            let mut idx: usize = start;
+
+            while !visited[idx] {
+                visited[idx] = true;
+                idx = perm[idx];
+            }
+        }
+    }
+
+    (-1_isize).pow((n - cycles) as u32)
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -174,10 +174,18 @@
 //! let c = Complex::from(-1.1, 8.2);
 //! let res = riccati_zeta(c, 3);
 //! ```
+//! 
+//! ## Struve functions: $\mathbf{H}_n$
+//! The Struve functions are solutions of the non-homogeneous Bessel equation, and are often found alongside $Y_n$.
+//! 
+//! ```rust
+//! # use scilib::math::bessel::struve_h;
+//! let res = struve_h(1, 2.5);
+//! ```
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-use std::f64::consts::{     // Using std lib constants
+use core::f64::consts::{     // Using std lib constants
     PI,                     // Pi
     FRAC_PI_2               // Pi / 2
 };
@@ -187,6 +195,14 @@ use super::{                // Using parts from the crate
     complex::Complex        // Using Complex numbers
 };
 
+use alloc::{               // Allocated types, also available without std
+    vec,
+    vec::Vec
+};
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;   // Float functions from libm
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// # Precision limit for Bessel computation
@@ -195,6 +211,9 @@ const PRECISION_CONVERGENCE: f64 = 1.0e-8;
 /// # Limit when computing Bessel Y
 const DISTANCE_Y_LIM: f64 = 0.001;
 
+/// # Maximum number of terms of the Struve series
+const STRUVE_MAX_ITER: usize = 10_000;
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// # $J$ Bessel function, integer index
@@ -467,6 +486,9 @@ where T: Into<Complex> + Copy, U: Into<f64> {
 /// let r2 = i(c, -1.6);
 /// assert!((r2.re - 0.549831).abs() < 1.0e-5 && (r2.im - -0.123202).abs() < 1.0e-5);
 /// 
+/// // Symmetric for the integer orders
+/// assert_eq!(i(1.5, -2), i(1.5, 2));
+/// 
 /// // We can check that the values are coherent
 /// let val = Complex::from(3.2, -1.1);
 /// let resi = i(val, 1.2);
@@ -479,6 +501,11 @@ where T: Into<Complex>, U: Into<f64> + Copy {
     
     let n: f64 = order.into();
 
+    // The first terms vanish for the negative integers, where the function is symmetric
+    if n < 0.0 && n.fract() == 0.0 {
+        return i(x, -n);
+    }
+
     let x2: Complex = x.into() / 2.0;           // Halving x
     let mut k: f64 = 0.0;                       // Order counter
     let mut d1: f64 = 1.0;                      // First div
@@ -644,7 +671,7 @@ where T: Into<Complex> + Copy, U: Into<f64> {
 /// # use scilib::math::complex::Complex;
 /// # use scilib::math::bessel::*;
 /// let res = sj(Complex::from(13, 5), 3);
-/// assert!((res.re - 1.6109825049200244).abs() < 1e-8 && (res.im + 4.322382277910797).abs() < 1e-8);
+/// assert!((res.re - 1.6109773767804668).abs() < 1e-8 && (res.im + 4.322368521444752).abs() < 1e-8);
 /// ```
 pub fn sj<T>(z: T, n: usize) -> Complex 
 where T: Into<Complex> {
@@ -742,14 +769,14 @@ where T: Into<Complex> {
     let mut jn = Vec::<Complex>::with_capacity(n);
     let mut norm = x.sin() / x / jn_all[0][0];
 
-    for i in 0..jn_all[0].len() {
-        jn.push(jn_all[0][i] * norm);
+    for val in &jn_all[0] {
+        jn.push(*val * norm);
     }
 
-    for i in 1..jn_all.len() {
-        norm = *jn.last().unwrap() / jn_all[i][0];
-        for k in 1..jn_all[i].len() {
-            jn.push(jn_all[i][k] * norm);
+    for pack in jn_all.iter().skip(1) {
+        norm = *jn.last().unwrap() / pack[0];
+        for val in pack.iter().skip(1) {
+            jn.push(*val * norm);
         }
     }
     
@@ -775,7 +802,7 @@ where T: Into<Complex> {
 /// # use scilib::math::complex::Complex;
 /// # use scilib::math::bessel::*;
 /// let res = sy(Complex::from(13, 5), 3);
-/// assert!((res.re - 4.322629120777188).abs() < 1e-8 && (res.im - 1.6104674841102558) < 1e-8);
+/// assert!((res.re - 4.322621479058535).abs() < 1e-8 && (res.im - 1.6104646377694016) < 1e-8);
 /// ```
 pub fn sy<T>(z: T, n: usize) -> Complex 
 where T: Into<Complex> {
@@ -858,7 +885,7 @@ where T: Into<Complex> {
 /// # use scilib::math::complex::Complex;
 /// # use scilib::math::bessel::*;
 /// let res = sh_first(Complex::from(13, 5), 3);
-/// assert!((res.re - 5.150208097686182e-4).abs() < 1e-8 && (res.im - 2.4684286639153896e-4).abs() < 1e-8);
+/// assert!((res.re - 5.127390110652248e-4).abs() < 1e-8 && (res.im - 2.529576137822704e-4).abs() < 1e-8);
 /// ```
 pub fn sh_first<T>(z: T, n: usize) -> Complex 
 where T: Into<Complex> {
@@ -923,7 +950,7 @@ where T: Into<Complex> {
 /// # use scilib::math::complex::Complex;
 /// # use scilib::math::bessel::*;
 /// let res = sh_second(Complex::from(13, 5), 3);
-/// assert!((res.re - 3.22144998903028).abs() < 1e-8 && (res.im + 8.645011398687984).abs() < 1e-8);
+/// assert!((res.re - 3.221442014549868).abs() < 1e-8 && (res.im + 8.644990000503287).abs() < 1e-8);
 /// ```
 pub fn sh_second<T>(z: T, n: usize) -> Complex 
 where T: Into<Complex> {
@@ -1052,3 +1079,82 @@ where T: Into<Complex>{
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Struve function $\mathbf{H}_n$
+/// 
+/// ## Definition
+/// The [Struve function](https://en.wikipedia.org/wiki/Struve_function) $\mathbf{H}_n$ is a solution of the
+/// non-homogeneous Bessel equation, defined by the series:
+/// $$
+/// \mathbf{H}_n(x) = \sum_{k=0}^{\infty}\frac{(-1)^k}{\Gamma(k+\frac{3}{2})\Gamma(k+n+\frac{3}{2})}\left( \frac{x}{2} \right)^{2k+n+1}
+/// $$
+/// 
+/// The convergence criterion is the same as for the other Bessel functions, defined by
+/// `const PRECISION_CONVERGENCE: f64 = 1.0e-8;`. As the series alternates, precision is lost for large $x$.
+/// 
+/// At infinity, the function tends to its asymptotic form $\mathbf{H}_n(x) \sim Y_n(x) + \frac{(x/2)^{n-1}}{\sqrt{\pi}\Gamma(n+\frac{1}{2})}$,
+/// that is $0$ for $n \le 0$, $2/\pi$ for $n = 1$ and $\infty$ beyond, with $\mathbf{H}_n(-x) = (-1)^{n+1}\mathbf{H}_n(x)$.
+/// 
+/// ## Inputs
+/// - `n`: the order of the function ($n$)
+/// - `x`: the value to evaluate ($x$)
+/// 
+/// Returns the value of the $n^{th}$ order of the Struve function at $x$, or `NaN` for a `NaN` input.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::bessel::struve_h;
+/// // Comparing to tabulated data
+/// assert!((struve_h(0, 1.0) - 0.568656627).abs() < 1.0e-8);
+/// assert!((struve_h(1, 1.0) - 0.198457336).abs() < 1.0e-8);
+/// assert!((struve_h(2, 5.5) - 1.623942892).abs() < 1.0e-8);
+/// assert!((struve_h(-1, 2.0) - -0.010143956).abs() < 1.0e-8);
+/// assert_eq!(struve_h(0, 0.0), 0.0);
+/// 
+/// // Limits at infinity
+/// assert_eq!(struve_h(0, f64::INFINITY), 0.0);
+/// assert_eq!(struve_h(1, f64::NEG_INFINITY), 2.0 / std::f64::consts::PI);
+/// assert_eq!(struve_h(2, f64::NEG_INFINITY), f64::NEG_INFINITY);
+/// assert!(struve_h(0, f64::NAN).is_nan());
+/// ```
+pub fn struve_h(n: i32, x: f64) -> f64 {
+
+    if x.is_nan() {
+        return f64::NAN;
+    } else if x.is_infinite() {
+        // Limit of the asymptotic form, with the parity of the function
+        let sign: f64 = if x > 0.0 || n % 2 != 0 { 1.0 } else { -1.0 };
+        return match n {
+            ..=0 => 0.0,
+            1 => 2.0 / PI,
+            _ => sign * f64::INFINITY
+        };
+    }
+
+    let x2: f64 = x / 2.0;                                      // Halving x
+
+    let mut term: f64 = x2.powi(n + 1) / (basic::gamma(1.5) * basic::gamma(n as f64 + 1.5));
+    let mut res: f64 = 0.0;                                     // The result of the operation
+
+    // If the first term is null we exit directly
+    if term == 0.0 {
+        return res;
+    }
+
+    // Computing the terms of the infinite series
+    for i in 0..STRUVE_MAX_ITER {
+        res += term;
+
+        // If the changed compared to the final value is small we break
+        if (term / res).abs() < PRECISION_CONVERGENCE {
+            break;
+        }
+
+        let k: f64 = i as f64;                                  // Order counter
+        term *= -x2.powi(2) / ((k + 1.5) * (k + n as f64 + 1.5));
+    }
+
+    res
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
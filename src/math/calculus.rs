@@ -0,0 +1,494 @@
+//!
+//! # Numerical calculus
+//! 
+//! This module provides numerical tools to differentiate, integrate and find the roots of any function of a real variable.
+//! 
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "alloc")]
+use alloc::{               // Allocated types, also available without std
+    vec::Vec
+};
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;   // Float functions from libm
+
+/// Default relative step of the central difference, $\epsilon^{1/3}$
+const STEP_CENTRAL: f64 = 6.055_454_452_393_343e-6;
+
+/// Default relative step of the five-point stencil, $\epsilon^{1/5}$
+const STEP_FIVE_POINT: f64 = 7.400_959_797_414_05e-4;
+
+/// Maximum depth of recursion of the adaptive integration
+const INTEGRATE_DEPTH: usize = 50;
+
+/// Maximum number of evaluations of the function during the adaptive integration
+const INTEGRATE_MAX_EVAL: usize = 1_000_000;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Numerical derivative
+/// 
+/// ## Definition
+/// The derivative is approximated with the [central difference](https://en.wikipedia.org/wiki/Finite_difference):
+/// $$
+/// f'(x) = \frac{f(x+h) - f(x-h)}{2h} + O(h^2)
+/// $$
+/// The default step $h = \epsilon^{1/3}\max(|x|, 1)$ balances the truncation error with the rounding error,
+/// for an expected relative error around `1.0e-10` on well-behaved functions.
+/// 
+/// ## Inputs
+/// - `f`: the function to differentiate ($f$)
+/// - `x`: the point at which to evaluate the derivative ($x$)
+/// - `h`: the step of the difference ($h$), or `None` for the default step
+/// 
+/// Returns the approximated value of $f'(x)$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::calculus::derivative;
+/// # use scilib::math::basic::{ gamma, digamma };
+/// let res: f64 = derivative(f64::sin, 0.7, None);
+/// assert!((res - 0.7_f64.cos()).abs() < 1.0e-10);
+/// 
+/// // The derivative of the gamma function is linked to the digamma function
+/// let res_g: f64 = derivative(gamma, 2.5, None);
+/// assert!((res_g - gamma(2.5) * digamma(2.5)).abs() < 1.0e-9);
+/// 
+/// let res_h: f64 = derivative(|x| x.powi(3), 2.0, Some(1.0e-3));
+/// assert!((res_h - 12.0).abs() < 1.0e-5);
+/// ```
+pub fn derivative<F>(f: F, x: f64, h: Option<f64>) -> f64
+where F: Fn(f64) -> f64 {
+
+    let h: f64 = exact_step(x, h.unwrap_or(STEP_CENTRAL * x.abs().max(1.0)));
+    (f(x + h) - f(x - h)) / (2.0 * h)
+}
+
+/// # Numerical derivative, five-point stencil
+/// 
+/// ## Definition
+/// The derivative is approximated with the [five-point stencil](https://en.wikipedia.org/wiki/Five-point_stencil):
+/// $$
+/// f'(x) = \frac{-f(x+2h) + 8f(x+h) - 8f(x-h) + f(x-2h)}{12h} + O(h^4)
+/// $$
+/// The default step $h = \epsilon^{1/5}\max(|x|, 1)$ balances the truncation error with the rounding error,
+/// for an expected relative error around `1.0e-13` on well-behaved functions.
+/// 
+/// ## Inputs
+/// - `f`: the function to differentiate ($f$)
+/// - `x`: the point at which to evaluate the derivative ($x$)
+/// - `h`: the step of the difference ($h$), or `None` for the default step
+/// 
+/// Returns the approximated value of $f'(x)$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::calculus::derivative_five_point;
+/// # use scilib::math::basic::{ gamma, digamma };
+/// let res: f64 = derivative_five_point(f64::sin, 0.7, None);
+/// assert!((res - 0.7_f64.cos()).abs() < 1.0e-13);
+/// 
+/// let res_g: f64 = derivative_five_point(gamma, 2.5, None);
+/// assert!((res_g - gamma(2.5) * digamma(2.5)).abs() < 1.0e-11);
+/// ```
+pub fn derivative_five_point<F>(f: F, x: f64, h: Option<f64>) -> f64
+where F: Fn(f64) -> f64 {
+
+    let h: f64 = exact_step(x, h.unwrap_or(STEP_FIVE_POINT * x.abs().max(1.0)));
+    (-f(x + 2.0 * h) + 8.0 * f(x + h) - 8.0 * f(x - h) + f(x - 2.0 * h)) / (12.0 * h)
+}
+
+/// Adjusts the step so that $x + h$ is exactly representable, removing a source of rounding error.
+fn exact_step(x: f64, h: f64) -> f64 {
+    (x + h) - x
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Adaptive numerical integration
+/// 
+/// ## Definition
+/// The integral is computed with the [adaptive Simpson's method](https://en.wikipedia.org/wiki/Adaptive_Simpson%27s_method).
+/// Each interval is split in two halves, until the difference between the Simpson's rule on the whole interval
+/// and on both halves satisfies:
+/// $$
+/// |S(a, m) + S(m, b) - S(a, b)| \le 15\varepsilon
+/// $$
+/// The tolerance $\varepsilon$ is halved at each split. The recursion depth is limited to 50 levels, and
+/// the function to about a million evaluations in total, beyond which the current estimates are kept.
+/// If $a > b$, the opposite of the integral over $[b, a]$ is returned.
+/// 
+/// ## Inputs
+/// - `f`: the function to integrate ($f$)
+/// - `a`: the lower bound of the integral ($a$)
+/// - `b`: the upper bound of the integral ($b$)
+/// - `tol`: the absolute tolerance of the result ($\varepsilon$)
+/// 
+/// Returns the approximated value of $\int_a^bf(x)dx$, or `NaN` if the function is not finite at an evaluated point.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::calculus::integrate;
+/// # use scilib::math::basic::{ erf_real, sinc, si };
+/// # use std::f64::consts::PI;
+/// let res: f64 = integrate(|x| (-x * x).exp(), 0.0, 1.5, 1.0e-12);
+/// assert!((res - PI.sqrt() / 2.0 * erf_real(1.5)).abs() < 1.0e-12);
+/// 
+/// // The sine integral is the integral of the sinc function
+/// let res_si: f64 = integrate(sinc, 0.0, 4.0, 1.0e-12);
+/// assert!((res_si - si(4.0)).abs() < 1.0e-12);
+/// 
+/// // Reversing the bounds changes the sign
+/// let rev: f64 = integrate(sinc, 4.0, 0.0, 1.0e-12);
+/// assert_eq!(rev, -res_si);
+/// assert_eq!(integrate(sinc, 1.0, 1.0, 1.0e-12), 0.0);
+/// 
+/// // The square root is NaN below 0
+/// assert!(integrate(|x| x.sqrt(), -1.0, 1.0, 1.0e-8).is_nan());
+/// ```
+pub fn integrate<F>(f: F, a: f64, b: f64, tol: f64) -> f64
+where F: Fn(f64) -> f64 {
+
+    if a > b {
+        return -integrate(f, b, a, tol);
+    } else if a == b {
+        return 0.0;
+    }
+
+    let m: f64 = 0.5 * (a + b);
+    let fa: f64 = f(a);
+    let fb: f64 = f(b);
+    let fm: f64 = f(m);
+    let whole: f64 = (b - a) / 6.0 * (fa + 4.0 * fm + fb);
+
+    if !whole.is_finite() {
+        return f64::NAN;
+    }
+
+    let mut evals: usize = INTEGRATE_MAX_EVAL - 3;
+    adaptive_simpson(&f, [(a, fa), (m, fm), (b, fb)], whole, tol, INTEGRATE_DEPTH, &mut evals)
+}
+
+/// Recursive step of the adaptive Simpson's method, with the bounds and the midpoint given along with the values
+/// of the function, and `evals` the remaining number of evaluations.
+fn adaptive_simpson<F>(f: &F, points: [(f64, f64); 3], whole: f64, tol: f64, depth: usize, evals: &mut usize) -> f64
+where F: Fn(f64) -> f64 {
+
+    let [a, m, b]: [(f64, f64); 3] = points;
+    let lm: f64 = 0.5 * (a.0 + m.0);
+    let rm: f64 = 0.5 * (m.0 + b.0);
+    let flm: f64 = f(lm);
+    let frm: f64 = f(rm);
+    *evals = evals.saturating_sub(2);
+
+    let left: f64 = (m.0 - a.0) / 6.0 * (a.1 + 4.0 * flm + m.1);
+    let right: f64 = (b.0 - m.0) / 6.0 * (m.1 + 4.0 * frm + b.1);
+    let delta: f64 = left + right - whole;
+
+    // A non-finite value would never satisfy the precision
+    if !left.is_finite() || !right.is_finite() {
+        return f64::NAN;
+    }
+
+    // We stop when the precision is reached, or when the recursion is too deep or too costly
+    if depth == 0 || *evals == 0 || delta.abs() <= 15.0 * tol {
+        return left + right + delta / 15.0;
+    }
+
+    adaptive_simpson(f, [a, (lm, flm), m], left, 0.5 * tol, depth - 1, evals)
+        + adaptive_simpson(f, [m, (rm, frm), b], right, 0.5 * tol, depth - 1, evals)
+}
+
+/// # Trapezoidal integration of samples
+/// 
+/// ## Definition
+/// Integrates sampled data with the [trapezoidal rule](https://en.wikipedia.org/wiki/Trapezoidal_rule), the
+/// points being possibly unevenly spaced:
+/// $$
+/// \int_{x_0}^{x_n}y(x)dx \approx \sum_{i=1}^{n}\frac{(x_i - x_{i-1})(y_i + y_{i-1})}{2}
+/// $$
+/// 
+/// ## Inputs
+/// - `xs`: the abscissas of the samples ($x_i$), in increasing order
+/// - `ys`: the values of the samples ($y_i$)
+/// 
+/// Returns the approximated integral, `0.0` for less than two points, or `NaN` if the lengths differ.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::calculus::trapezoid;
+/// // Exact for a line
+/// assert_eq!(trapezoid(&[0.0, 0.5, 2.0], &[1.0, 2.0, 5.0]), 6.0);
+/// 
+/// // For x^2 over [0, 1] with a step h, the error is h^2 / 6
+/// let xs: Vec<f64> = (0..=10).map(|i| i as f64 / 10.0).collect();
+/// let ys: Vec<f64> = xs.iter().map(|x| x * x).collect();
+/// assert!((trapezoid(&xs, &ys) - (1.0 / 3.0 + 0.01 / 6.0)).abs() < 1.0e-15);
+/// 
+/// assert_eq!(trapezoid(&[1.0], &[3.0]), 0.0);
+/// assert!(trapezoid(&[0.0, 1.0], &[1.0]).is_nan());
+/// ```
+pub fn trapezoid(xs: &[f64], ys: &[f64]) -> f64 {
+
+    if xs.len() != ys.len() {
+        return f64::NAN;
+    }
+
+    xs.windows(2).zip(ys.windows(2)).fold(0.0, |res, (x, y)| res + 0.5 * (x[1] - x[0]) * (y[1] + y[0]))
+}
+
+/// # Cumulative trapezoidal integration of samples
+/// 
+/// ## Definition
+/// Computes the running integral of sampled data with the trapezoidal rule, as in `trapezoid`:
+/// $$
+/// I_k = \int_{x_0}^{x_k}y(x)dx \approx \sum_{i=1}^{k}\frac{(x_i - x_{i-1})(y_i + y_{i-1})}{2}
+/// $$
+/// 
+/// ## Inputs
+/// - `xs`: the abscissas of the samples ($x_i$), in increasing order
+/// - `ys`: the values of the samples ($y_i$)
+/// 
+/// Returns the running integrals $I_k$, with the same length as the samples and starting at `0.0`,
+/// or an empty vector if the lengths differ.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::calculus::{ cumulative_trapezoid, trapezoid };
+/// # use scilib::math::basic::{ gaussian_normed, erf_real };
+/// # use std::f64::consts::SQRT_2;
+/// // Cumulative distribution function from the sampled density
+/// let xs: Vec<f64> = (0..=800).map(|i| -8.0 + i as f64 / 50.0).collect();
+/// let pdf: Vec<f64> = xs.iter().map(|x| gaussian_normed(0.0, 1.0, *x)).collect();
+/// let cdf: Vec<f64> = cumulative_trapezoid(&xs, &pdf);
+/// 
+/// assert_eq!(cdf.len(), xs.len());
+/// assert_eq!(cdf[0], 0.0);
+/// assert!((cdf[450] - 0.5 * (1.0 + erf_real(1.0 / SQRT_2))).abs() < 1.0e-4);
+/// assert_eq!(cdf[800], trapezoid(&xs, &pdf));
+/// 
+/// assert!(cumulative_trapezoid(&[0.0, 1.0], &[1.0]).is_empty());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn cumulative_trapezoid(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+
+    if xs.len() != ys.len() {
+        return Vec::new();
+    }
+
+    let mut sum: f64 = 0.0;
+    let mut res: Vec<f64> = Vec::with_capacity(xs.len());
+    res.extend(xs.first().map(|_| 0.0));
+
+    for (x, y) in xs.windows(2).zip(ys.windows(2)) {
+        sum += 0.5 * (x[1] - x[0]) * (y[1] + y[0]);
+        res.push(sum);
+    }
+
+    res
+}
+
+/// # Simpson's integration of samples
+/// 
+/// ## Definition
+/// Integrates sampled data with the composite [Simpson's rule](https://en.wikipedia.org/wiki/Simpson%27s_rule),
+/// fitting a parabola on each pair of intervals, for possibly unevenly spaced points. With $h_0$ and $h_1$
+/// the widths of both intervals:
+/// $$
+/// \int_{x_0}^{x_2}y(x)dx \approx \frac{h_0 + h_1}{6}\left( \left( 2 - \frac{h_1}{h_0} \right)y_0 +
+/// \frac{(h_0 + h_1)^2}{h_0h_1}y_1 + \left( 2 - \frac{h_0}{h_1} \right)y_2 \right)
+/// $$
+/// The rule requires an odd number of points. Otherwise, the last interval is integrated separately, with
+/// the parabola through the last three points, so that the result stays exact for quadratics.
+/// 
+/// ## Inputs
+/// - `xs`: the abscissas of the samples ($x_i$), in increasing order
+/// - `ys`: the values of the samples ($y_i$)
+/// 
+/// Returns the approximated integral, `0.0` for less than two points, or `NaN` if the lengths differ.
+/// Two points fall back to the trapezoidal rule.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::calculus::simpson;
+/// let f = |x: f64| 3.0 * x * x - 2.0 * x + 1.0;
+/// 
+/// // Exact for a parabola, on an uneven grid with an odd or even number of points
+/// let xs = [0.0, 0.3, 0.5, 1.2, 1.6, 2.0];
+/// let ys: Vec<f64> = xs.iter().map(|x| f(*x)).collect();
+/// assert!((simpson(&xs[..5], &ys[..5]) - 3.136).abs() < 1.0e-14);
+/// assert!((simpson(&xs, &ys) - 6.0).abs() < 1.0e-14);
+/// 
+/// assert_eq!(simpson(&[0.0, 2.0], &[1.0, 3.0]), 4.0);
+/// assert!(simpson(&[0.0, 1.0, 2.0], &[1.0]).is_nan());
+/// ```
+pub fn simpson(xs: &[f64], ys: &[f64]) -> f64 {
+
+    if xs.len() != ys.len() {
+        return f64::NAN;
+    } else if xs.len() < 3 {
+        return trapezoid(xs, ys);
+    }
+
+    // Number of points covered by the pairs of intervals
+    let n: usize = if xs.len() % 2 == 1 { xs.len() } else { xs.len() - 1 };
+    let mut res: f64 = 0.0;
+
+    for i in (0..n - 2).step_by(2) {
+        let h0: f64 = xs[i + 1] - xs[i];
+        let h1: f64 = xs[i + 2] - xs[i + 1];
+        let h: f64 = h0 + h1;
+        res += h / 6.0 * ((2.0 - h1 / h0) * ys[i] + h * h / (h0 * h1) * ys[i + 1] + (2.0 - h0 / h1) * ys[i + 2]);
+    }
+
+    // Last interval, with the parabola through the last three points
+    if n < xs.len() {
+        let k: usize = xs.len() - 1;
+        let h0: f64 = xs[k - 1] - xs[k - 2];
+        let h1: f64 = xs[k] - xs[k - 1];
+        let alpha: f64 = (2.0 * h1 * h1 + 3.0 * h0 * h1) / (6.0 * (h0 + h1));
+        let beta: f64 = (h1 * h1 + 3.0 * h0 * h1) / (6.0 * h0);
+        let eta: f64 = h1.powi(3) / (6.0 * h0 * (h0 + h1));
+        res += alpha * ys[k] + beta * ys[k - 1] - eta * ys[k - 2];
+    }
+
+    res
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Bisection method
+/// 
+/// ## Definition
+/// The [bisection method](https://en.wikipedia.org/wiki/Bisection_method) finds a root of a continuous function
+/// in an interval $[a, b]$ where it changes sign, by halving the interval at each step, keeping the half
+/// where the sign change occurs.
+/// 
+/// ## Inputs
+/// - `f`: the function of which to find a root ($f$)
+/// - `a`, `b`: the bounds of the interval ($a$, $b$)
+/// - `tol`: the width of the interval under which the search stops
+/// 
+/// Returns the root found, or `None` if $f(a)$ and $f(b)$ have the same sign or if a bound is not finite.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::calculus::bisection;
+/// # use scilib::math::bessel::j;
+/// let res: f64 = bisection(|x| x * x - 2.0, 0.0, 2.0, 1.0e-14).unwrap();
+/// assert!((res - 2.0_f64.sqrt()).abs() < 1.0e-14);
+/// 
+/// // First zero of the J0 Bessel function
+/// let zero: f64 = bisection(|x| j(x, 0).re, 2.0, 3.0, 1.0e-10).unwrap();
+/// assert!((zero - 2.404825557695773).abs() < 1.0e-7);
+/// 
+/// assert_eq!(bisection(|x| x * x + 1.0, -1.0, 1.0, 1.0e-10), None);
+/// assert_eq!(bisection(|x| x, f64::NEG_INFINITY, f64::INFINITY, 1.0e-10), None);
+/// 
+/// // The bounds can span the whole range of the floats
+/// assert!((bisection(|x| x - 1.0, -f64::MAX, f64::MAX, 1.0e-10).unwrap() - 1.0).abs() < 1.0e-10);
+/// ```
+pub fn bisection<F>(f: F, a: f64, b: f64, tol: f64) -> Option<f64>
+where F: Fn(f64) -> f64 {
+
+    if !(a.is_finite() && b.is_finite()) {
+        return None;
+    }
+
+    let (mut lo, mut hi): (f64, f64) = (a, b);
+    let mut f_lo: f64 = f(lo);
+    let f_hi: f64 = f(hi);
+
+    if f_lo == 0.0 {
+        return Some(lo);
+    } else if f_hi == 0.0 {
+        return Some(hi);
+    } else if f_lo.signum() == f_hi.signum() || f_lo.is_nan() || f_hi.is_nan() {
+        return None;
+    }
+
+    'convergence: loop {
+        // Halving each bound first, so that the sum cannot overflow
+        let mid: f64 = 0.5 * lo + 0.5 * hi;
+
+        // We exit when the interval is small enough, or cannot be split anymore
+        if (hi - lo).abs() <= tol || mid == lo || mid == hi {
+            break 'convergence;
+        }
+
+        let f_mid: f64 = f(mid);
+
+        if f_mid == 0.0 {
+            return Some(mid);
+        } else if f_mid.signum() == f_lo.signum() {
+            lo = mid;
+            f_lo = f_mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    Some(0.5 * lo + 0.5 * hi)
+}
+
+/// # Newton-Raphson method
+/// 
+/// ## Definition
+/// The [Newton-Raphson method](https://en.wikipedia.org/wiki/Newton%27s_method) finds a root of a differentiable
+/// function from a starting point $x_0$ with the iteration:
+/// $$
+/// x_{n+1} = x_n - \frac{f(x_n)}{f'(x_n)}
+/// $$
+/// 
+/// ## Inputs
+/// - `f`: the function of which to find a root ($f$)
+/// - `fp`: the derivative of the function ($f'$)
+/// - `x0`: the starting point ($x_0$)
+/// - `tol`: the size of the step under which the search stops
+/// - `max_iter`: the maximum number of iterations
+/// 
+/// Returns the root found, or `None` if the derivative vanishes, if the iteration diverges,
+/// or if the maximum number of iterations is reached.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::calculus::newton;
+/// # use scilib::math::bessel::j;
+/// let res: f64 = newton(|x| x * x - 2.0, |x| 2.0 * x, 1.0, 1.0e-15, 50).unwrap();
+/// assert!((res - 2.0_f64.sqrt()).abs() < 1.0e-15);
+/// 
+/// // First zero of the J0 Bessel function, with J0' = -J1
+/// let zero: f64 = newton(|x| j(x, 0).re, |x| -j(x, 1).re, 2.5, 1.0e-10, 50).unwrap();
+/// assert!((zero - 2.404825557695773).abs() < 1.0e-7);
+/// 
+/// // The derivative vanishes at the starting point
+/// assert_eq!(newton(|x| x * x - 2.0, |x| 2.0 * x, 0.0, 1.0e-15, 50), None);
+/// ```
+pub fn newton<F, Fp>(f: F, fp: Fp, x0: f64, tol: f64, max_iter: usize) -> Option<f64>
+where F: Fn(f64) -> f64, Fp: Fn(f64) -> f64 {
+
+    let mut x: f64 = x0;
+
+    for _ in 0..max_iter {
+        let d: f64 = fp(x);
+
+        if d == 0.0 {
+            return None;
+        }
+
+        let step: f64 = f(x) / d;
+        x -= step;
+
+        if !x.is_finite() {
+            return None;
+        } else if step.abs() <= tol {
+            return Some(x);
+        }
+    }
+
+    None
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
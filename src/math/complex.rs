@@ -7,7 +7,7 @@
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-use std::ops::{     // Implementing basic operations
+use core::ops::{     // Implementing basic operations
     Add,            // Addition
     AddAssign,      // Assigning addition
     Sub,            // Subtraction
@@ -19,11 +19,28 @@ use std::ops::{     // Implementing basic operations
     Neg             // Negation
 };
 
-use std::fmt::{     // Formatter display
+use core::cmp::Ordering;     // Comparison of the moduli
+
+use core::f64::consts::TAU;  // Tau constant
+
+#[cfg(feature = "alloc")]
+use core::fmt::{     // Formatter display
+    Alignment,      // Alignment of the display
     Display,        // The display itself
+    LowerExp,       // The scientific display
     Result as DRes  // The associated result
 };
 
+#[cfg(feature = "alloc")]
+use alloc::{               // Allocated types, also available without std
+    format,
+    string::String,
+    string::ToString
+};
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;   // Float functions from libm
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// # Complex number
@@ -44,15 +61,120 @@ pub struct Complex {
 /// # Display
 /// 
 /// Returns the complex in the for $a + ib$, where the sign of $b$ is always showing.
+/// 
+/// The precision and the sign flag of the formatter apply to both parts, while the width, fill
+/// and alignment apply to the whole rendered string.
+/// 
+/// The alternate flag `#` shows the polar form instead, as $r\angle\theta$, with the argument in degrees.
+/// 
+/// ```
+/// # use scilib::math::complex::Complex;
+/// let c = Complex::from(1, 2);
+/// 
+/// assert_eq!(format!("{}", c), "1 +2i");
+/// assert_eq!(format!("{:.3}", c), "1.000 +2.000i");
+/// assert_eq!(format!("{:+.1}", -c), "-1.0 -2.0i");
+/// assert_eq!(format!("{:>12.1}", c), "   1.0 +2.0i");
+/// assert_eq!(format!("{:*^12}", c), "***1 +2i****");
+/// 
+/// // Polar form
+/// assert_eq!(format!("{:#.3}", Complex::from(1, 1)), "1.414∠45.000°");
+/// assert_eq!(format!("{:#}", Complex::from(0, -2)), "2∠-90°");
+/// ```
+#[cfg(feature = "alloc")]
 impl Display for Complex {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> DRes {
-        write!(f, "{}", format!("{} {:+}i", self.re, self.im))?;
-        Ok(())
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> DRes {
+        if f.alternate() {
+            let deg: f64 = self.arg().to_degrees();
+            let s: String = match f.precision() {
+                Some(p) => format!("{:.*}∠{:.*}°", p, self.modulus(), p, deg),
+                None => format!("{}∠{}°", self.modulus(), deg)
+            };
+            return pad_complex(f, &s);
+        }
+
+        let s: String = match (f.precision(), f.sign_plus()) {
+            (Some(p), false) => format!("{:.*} {:+.*}i", p, self.re, p, self.im),
+            (Some(p), true) => format!("{:+.*} {:+.*}i", p, self.re, p, self.im),
+            (None, false) => format!("{} {:+}i", self.re, self.im),
+            (None, true) => format!("{:+} {:+}i", self.re, self.im)
+        };
+        pad_complex(f, &s)
+    }
+}
+
+/// # Scientific display
+/// 
+/// Returns the complex in the for $a + ib$, with both parts in scientific notation.
+/// 
+/// The precision and the sign flag of the formatter apply to both parts, while the width, fill
+/// and alignment apply to the whole rendered string.
+/// 
+/// ```
+/// # use scilib::math::complex::Complex;
+/// let c = Complex::from(1500, -0.025);
+/// 
+/// assert_eq!(format!("{:e}", c), "1.5e3 -2.5e-2i");
+/// assert_eq!(format!("{:.2e}", c), "1.50e3 -2.50e-2i");
+/// ```
+#[cfg(feature = "alloc")]
+impl LowerExp for Complex {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> DRes {
+        let s: String = match (f.precision(), f.sign_plus()) {
+            (Some(p), false) => format!("{:.*e} {:+.*e}i", p, self.re, p, self.im),
+            (Some(p), true) => format!("{:+.*e} {:+.*e}i", p, self.re, p, self.im),
+            (None, false) => format!("{:e} {:+e}i", self.re, self.im),
+            (None, true) => format!("{:+e} {:+e}i", self.re, self.im)
+        };
+        pad_complex(f, &s)
     }
 }
 
+/// Writes the rendered complex, applying the width, fill and alignment of the formatter.
+/// The string is right-aligned by default, like other numbers.
+#[cfg(feature = "alloc")]
+fn pad_complex(f: &mut core::fmt::Formatter, s: &str) -> DRes {
+
+    let len: usize = s.chars().count();
+    let pad: usize = f.width().unwrap_or(0).saturating_sub(len);
+
+    let (left, right): (usize, usize) = match f.align() {
+        Some(Alignment::Left) => (0, pad),
+        Some(Alignment::Center) => (pad / 2, pad - pad / 2),
+        _ => (pad, 0)
+    };
+
+    let fill: String = f.fill().to_string();
+    write!(f, "{}{}{}", fill.repeat(left), s, fill.repeat(right))
+}
+
 /// Implementing required methods
 impl Complex {
+    /// # Zero
+    /// 
+    /// The constant $0$, usable in const contexts, same as `Complex::new()`.
+    /// 
+    /// ```
+    /// # use scilib::math::complex::Complex;
+    /// const ORIGIN: Complex = Complex::ZERO;
+    /// 
+    /// assert_eq!(ORIGIN, Complex::new());
+    /// assert_eq!(Complex::ONE, Complex::unity());
+    /// assert_eq!(Complex::I, Complex::i());
+    /// assert_eq!(Complex::I * Complex::I, -Complex::ONE);
+    /// ```
+    pub const ZERO: Self = Self::new();
+
+    /// # One
+    /// 
+    /// The constant $1$, same as `Complex::unity()`.
+    pub const ONE: Self = Self::unity();
+
+    /// # Imaginary unit
+    /// 
+    /// The constant $i$, same as `Complex::i()`.
+    pub const I: Self = Self::i();
+
     /// # New Complex
     /// 
     /// Simply returns $0$.
@@ -126,6 +248,45 @@ impl Complex {
         }
     }
 
+    /// # From a real number
+    /// 
+    /// Creates the complex $x + 0i$.
+    /// 
+    /// ```
+    /// # use scilib::math::complex::Complex;
+    /// let c = Complex::from_real(2.5);
+    /// 
+    /// assert!(c.re == 2.5 && c.im == 0.0);
+    /// assert_eq!(Complex::from_real(-3), Complex::from(-3, 0));
+    /// ```
+    pub fn from_real<T>(x: T) -> Self
+    where T: Into<f64> {
+        Self {
+            re: x.into(),
+            im: 0.0
+        }
+    }
+
+    /// # From an imaginary number
+    /// 
+    /// Creates the complex $0 + iy$.
+    /// 
+    /// ```
+    /// # use std::f64::consts::TAU;
+    /// # use scilib::math::complex::Complex;
+    /// let c = Complex::from_imag(TAU);
+    /// 
+    /// assert!(c.re == 0.0 && c.im == TAU);
+    /// assert!((c.exp() - Complex::ONE).modulus() < 1.0e-15);
+    /// ```
+    pub fn from_imag<T>(y: T) -> Self
+    where T: Into<f64> {
+        Self {
+            re: 0.0,
+            im: y.into()
+        }
+    }
+
     /// # From polar coordinates
     /// Creates the complex number based on polar coordinates values.
     /// To do so, we follow the formula:
@@ -194,6 +355,28 @@ impl Complex {
         }
     }
 
+    /// # Logarithm on a given branch
+    /// 
+    /// Computes the logarithm on the sheet `k` of its Riemann surface, the principal branch being $k = 0$:
+    /// $$
+    /// \ln_k(z) = \ln(|z|) + i(\arg(z) + 2\pi k)
+    /// $$
+    /// 
+    /// ```
+    /// # use std::f64::consts::TAU;
+    /// # use scilib::math::complex::Complex;
+    /// let c = Complex::from(-1.5, 0.4);
+    /// 
+    /// assert_eq!(c.ln_branch(0), c.ln());
+    /// assert_eq!(c.ln_branch(1) - c.ln_branch(0), Complex::from(0, TAU));
+    /// assert!((c.ln_branch(-2).exp() - c).modulus() < 1.0e-14);
+    /// ```
+    pub fn ln_branch(&self, k: i32) -> Self {
+        let mut res: Self = self.ln();
+        res.im += TAU * k as f64;
+        res
+    }
+
     /// # Cosine function
     /// Computes the cosine value of the given complex number, using the formula:
     /// $$
@@ -350,9 +533,96 @@ impl Complex {
     /// 
     /// assert_eq!(c1.modulus(), 2.5);
     /// assert!((c2.modulus() - 17.7485210651).abs() < 1.0e-8);
+    /// 
+    /// // No overflow of the squares
+    /// let c3 = Complex::from(3.0e200, -4.0e200);
+    /// assert!((c3.modulus() / 5.0e200 - 1.0).abs() < 1.0e-15);
+    /// assert!((Complex::from(3.0e-200, 4.0e-200).modulus() / 5.0e-200 - 1.0).abs() < 1.0e-15);
+    /// assert!(Complex::from(1.0e200, 1.0e200).modulus().is_finite());
     /// ```
     pub fn modulus(&self) -> f64 {
-        (self.re.powi(2) + self.im.powi(2)).sqrt()
+        self.re.hypot(self.im)
+    }
+
+    /// # Comparison of the moduli
+    /// 
+    /// Compares the moduli of two complex numbers. This is not an ordering of the complex numbers, which is why
+    /// `PartialOrd` is not implemented: different numbers can compare as equal.
+    /// 
+    /// Returns `None` if either modulus is `NaN`.
+    /// 
+    /// ```
+    /// # use std::cmp::Ordering;
+    /// # use scilib::math::complex::Complex;
+    /// let c1 = Complex::from(3, 4);
+    /// 
+    /// assert_eq!(c1.cmp_modulus(&Complex::from(-1, 1)), Some(Ordering::Greater));
+    /// assert_eq!(c1.cmp_modulus(&Complex::from(0, -5)), Some(Ordering::Equal));
+    /// assert_eq!(c1.cmp_modulus(&Complex::from(f64::NAN, 0)), None);
+    /// ```
+    pub fn cmp_modulus(&self, other: &Self) -> Option<Ordering> {
+        self.modulus().partial_cmp(&other.modulus())
+    }
+
+    /// # Absolute value
+    /// Alias of the modulus, $|z|$, for compatibility with other complex number libraries.
+    /// 
+    /// ```
+    /// # use scilib::math::complex::Complex;
+    /// let c = Complex::from(-3, 4);
+    /// 
+    /// assert_eq!(c.abs(), 5.0);
+    /// assert_eq!(c.abs(), c.modulus());
+    /// ```
+    pub fn abs(&self) -> f64 {
+        self.modulus()
+    }
+
+    /// # Normalization
+    /// Scales the number to a unit modulus, which gives its phase factor:
+    /// $$
+    /// \frac{z}{|z|} = \exp(i\arg(z))
+    /// $$
+    /// As the phase of zero is not defined, $0$ is returned in that case.
+    /// 
+    /// ```
+    /// # use scilib::math::complex::Complex;
+    /// let c = Complex::from(-2.5, 1.2);
+    /// let res = c.normalize();
+    /// 
+    /// assert!((res.modulus() - 1.0).abs() < 1.0e-15);
+    /// assert!((res.arg() - c.arg()).abs() < 1.0e-15);
+    /// assert_eq!(Complex::new().normalize(), Complex::new());
+    /// ```
+    pub fn normalize(&self) -> Self {
+
+        let modulus: f64 = self.modulus();
+
+        if modulus == 0.0 {
+            return Self::new();
+        }
+
+        *self / modulus
+    }
+
+    /// # Complex sign
+    /// The [sign function](https://en.wikipedia.org/wiki/Sign_function#Complex_signum) extended to the
+    /// complex numbers, giving the point of the unit circle in the direction of the number:
+    /// $$
+    /// \mathrm{sgn}(z) = \frac{z}{|z|}
+    /// $$
+    /// By convention, $\mathrm{sgn}(0) = 0$. The result is the same as `normalize`, under the name
+    /// used in signal processing.
+    /// 
+    /// ```
+    /// # use scilib::math::complex::Complex;
+    /// assert_eq!(Complex::from(4.2, 0.0).signum(), Complex::unity());
+    /// assert_eq!(Complex::from(-0.5, 0.0).signum(), -Complex::unity());
+    /// assert_eq!(Complex::i().signum(), Complex::i());
+    /// assert_eq!(Complex::new().signum(), Complex::new());
+    /// ```
+    pub fn signum(&self) -> Self {
+        self.normalize()
     }
 
     /// # The polar coordinates of the number
@@ -373,7 +643,43 @@ impl Complex {
         (self.arg(), self.modulus())
     }
 
+    /// # Polar representation
+    /// 
+    /// Renders the complex in polar form, as $r\angle\theta$ with the argument in degrees, using the given
+    /// number of decimals for both values. This is the same as the alternate display `{:#.precision}`.
+    /// 
+    /// ```
+    /// # use scilib::math::complex::Complex;
+    /// let c = Complex::from(1, 1);
+    /// 
+    /// assert_eq!(c.to_polar_string(4), "1.4142∠45.0000°");
+    /// assert_eq!(Complex::from(-3, 0).to_polar_string(1), "3.0∠180.0°");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_polar_string(&self, precision: usize) -> String {
+        format!("{:#.*}", precision, self)
+    }
+
+    /// # Reciprocal
+    /// 
+    /// Returns the multiplicative inverse $1/z$.
+    /// 
+    /// ```
+    /// # use scilib::math::complex::Complex;
+    /// let c = Complex::from(2.0, 4.0);
+    /// 
+    /// assert_eq!(c.recip(), Complex::from(0.1, -0.2));
+    /// assert_eq!(c.recip(), 1.0 / c);
+    /// ```
+    pub fn recip(&self) -> Self {
+        1.0 / *self
+    }
+
     /// # Raising to an integer power
+    /// 
+    /// Computed by repeated squaring, which keeps integer results exact when possible, and is more precise
+    /// than `powf` which goes through the polar form. A negative power is the reciprocal of the positive one.
+    /// By convention, $z^0 = 1$ for any $z$, including $0$.
     ///
     /// ```
     /// # use scilib::math::complex::Complex;
@@ -381,34 +687,107 @@ impl Complex {
     /// let res1 = c.powi(4);
     /// let res2 = c.powi(-1);
     /// 
-    /// assert!((res1.re - 28.0).abs() < 1.0e-12);
-    /// assert!((res1.im - 96.0).abs() < 1.0e-12);
+    /// assert_eq!(res1, Complex::from(28, 96));
     /// assert_eq!(res2.re, 0.3);
     /// assert_eq!(res2.im, -0.1);
+    /// 
+    /// assert_eq!(Complex::from(1, 1).powi(2), Complex::from(0, 2));
+    /// assert_eq!(Complex::from(1, 1).powi(8), Complex::from(16, 0));
+    /// assert_eq!(Complex::i().powi(0), Complex::unity());
+    /// assert_eq!(Complex::new().powi(0), Complex::unity());
+    /// 
+    /// // The polar form loses the exactness
+    /// let exact = Complex::from(16, 0);
+    /// let polar = Complex::from(1, 1).powf(8.0);
+    /// assert!(polar != exact && (polar - exact).modulus() < 1.0e-13);
     /// ```
-    pub fn powi(self, e: i32) -> Self {
-        // Using polar coordinates
-        let (arg, norm): (f64, f64) = self.polar();
-        Self::from_polar(arg * e as f64, norm.powi(e))
+    pub fn powi(&self, e: i32) -> Self {
+        let mut base: Self = *self;
+        let mut n: u32 = e.unsigned_abs();
+        let mut res: Self = Self::unity();
+
+        while n > 0 {
+            if n & 1 == 1 {
+                res *= base;
+            }
+            base *= base;
+            n >>= 1;
+        }
+
+        if e < 0 {
+            res.recip()
+        } else {
+            res
+        }
     }
 
     /// # Raising to a real power
-    /// The formula is defined as:
+    /// 
+    /// Uses the principal branch of the logarithm, with the argument in $]-\pi, \pi]$:
     /// $$
-    /// z^{p} = p\arg(z) + |z|^p
+    /// z^{p} = \exp\left( p\ln(z) \right) = |z|^p\exp\left( ip\arg(z) \right)
     /// $$
+    /// Unlike `f64::powf`, which returns `NaN`, a negative real base with a fractional power gives the complex
+    /// principal value. The branch is taken from above the cut, unless the imaginary part is `-0.0`.
+    /// The zero base follows `f64::powf` for the modulus.
     ///
     /// ```
+    /// # use std::f64::consts::FRAC_PI_4;
     /// # use scilib::math::complex::Complex;
     /// let c = Complex::from(2.5, -3.6);
     /// let res = c.powf(1.25);
     /// 
     /// assert!((res.re - 2.2697926495).abs() < 1.0e-8 && (res.im - -5.9215705908).abs() < 1.0e-8);
+    /// 
+    /// // Principal square root of 1 + i
+    /// let res = Complex::from(1, 1).powf(0.5);
+    /// assert!((res - Complex::from_polar(FRAC_PI_4 / 2.0, 2.0_f64.powf(0.25))).modulus() < 1.0e-15);
+    /// 
+    /// // Negative real bases
+    /// assert!(f64::powf(-1.0, 0.5).is_nan());
+    /// let res = Complex::from(-1, 0).powf(0.5);
+    /// assert!((res - Complex::i()).modulus() < 1.0e-15);
+    /// let res = Complex::from(-8, 0).powf(1.0 / 3.0);
+    /// assert!((res - Complex::from(1.0, 3.0_f64.sqrt())).modulus() < 1.0e-15);
+    /// 
+    /// assert_eq!(Complex::new().powf(2.5), Complex::new());
+    /// assert_eq!(Complex::new().powf(0.0), Complex::unity());
     /// ```
     pub fn powf(&self, e: f64) -> Self {
-        // Using polar coordinates
-        let (arg, norm): (f64, f64) = self.polar();
-        Self::from_polar(arg * e, norm.powf(e))
+        if self.re == 0.0 && self.im == 0.0 {
+            return Self::from(0.0_f64.powf(e), 0.0);
+        }
+        (e * self.ln()).exp()
+    }
+
+    /// # Raising to a complex power
+    /// 
+    /// Uses the principal value of the logarithm:
+    /// $$
+    /// z^{w} = \exp\left( w\ln(z) \right)
+    /// $$
+    /// The zero base returns zero for any power with a positive real part.
+    ///
+    /// ```
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// # use scilib::math::complex::Complex;
+    /// let i = Complex::i();
+    /// 
+    /// // i^i is real
+    /// let res = i.powc(i);
+    /// assert!((res.re - (-FRAC_PI_2).exp()).abs() < 1.0e-15 && res.im.abs() < 1.0e-15);
+    /// 
+    /// // Agrees with the real power
+    /// let c = Complex::from(2.5, -3.6);
+    /// assert!((c.powc(Complex::from(1.25, 0)) - c.powf(1.25)).modulus() < 1.0e-13);
+    /// 
+    /// assert_eq!(Complex::new().powc(Complex::from(0.5, 2.0)), Complex::new());
+    /// ```
+    pub fn powc(&self, e: Self) -> Self {
+        if self.re == 0.0 && self.im == 0.0 && e.re > 0.0 {
+            return Self::new();
+        }
+        (e * self.ln()).exp()
     }
 
     /// # Square root
@@ -433,33 +812,237 @@ impl Complex {
             im: self.im.signum() * ((modulus - self.re) / 2.0).sqrt()
         }
     }
+
+    /// # Cube root
+    /// 
+    /// Of the three cube roots, we return the principal one, with an argument in $]-\pi/3, \pi/3]$:
+    /// $$
+    /// z^{1/3} = |z|^{1/3}\exp\left( i\frac{\arg(z)}{3} \right)
+    /// $$
+    /// The cube root of a positive real is thus the real cube root, while the one of a negative real is complex.
+    /// The other roots are obtained with a rotation of $\pm2\pi/3$.
+    /// 
+    /// ```
+    /// # use scilib::math::complex::Complex;
+    /// assert_eq!(Complex::from(27, 0).cbrt(), Complex::from(3, 0));
+    /// 
+    /// let res = Complex::from(-8, 0).cbrt();
+    /// assert!((res.re - 1.0).abs() < 1.0e-15 && (res.im - 3.0_f64.sqrt()).abs() < 1.0e-15);
+    /// 
+    /// let c = Complex::from(1.5, -2.0);
+    /// assert!((c.cbrt().powi(3) - c).modulus() < 1.0e-14);
+    /// ```
+    pub fn cbrt(&self) -> Self {
+        // Using polar coordinates
+        let (arg, norm): (f64, f64) = self.polar();
+        Self::from_polar(arg / 3.0, norm.cbrt())
+    }
+
+    /// # Rotation
+    /// 
+    /// Rotates the number by the given angle around the origin, by multiplying it by $e^{i\theta}$.
+    /// 
+    /// ```
+    /// # use std::f64::consts::{ FRAC_PI_2, PI };
+    /// # use scilib::math::complex::Complex;
+    /// // Quarter turn
+    /// let res = Complex::unity().rotate(FRAC_PI_2);
+    /// assert!((res - Complex::i()).modulus() < 1.0e-15);
+    /// 
+    /// let c = Complex::from(3, -4);
+    /// assert_eq!(c.rotate(0.0), c);
+    /// assert!((c.rotate(PI) + c).modulus() < 1.0e-15);
+    /// assert!((c.rotate(1.2).arg() - (c.arg() + 1.2)).abs() < 1.0e-15);
+    /// ```
+    pub fn rotate(&self, angle: f64) -> Self {
+        *self * Self::from_polar(angle, 1.0)
+    }
+
+    /// # Projection onto the Riemann sphere
+    /// 
+    /// Maps any number with an infinite part to the single point at infinity, $+\infty$ with the sign of the
+    /// imaginary part kept on zero, as in C99. Finite numbers are returned unchanged.
+    /// 
+    /// ```
+    /// # use scilib::math::complex::Complex;
+    /// let p = Complex::from(f64::NEG_INFINITY, -2.5).proj();
+    /// assert!(p.re == f64::INFINITY && p.im == 0.0 && p.im.is_sign_negative());
+    /// 
+    /// let p = Complex::from(1.0, f64::INFINITY).proj();
+    /// assert!(p.re == f64::INFINITY && p.im == 0.0 && p.im.is_sign_positive());
+    /// 
+    /// let c = Complex::from(3, -4);
+    /// assert_eq!(c.proj(), c);
+    /// ```
+    pub fn proj(&self) -> Self {
+        if self.re.is_infinite() || self.im.is_infinite() {
+            return Self::from(f64::INFINITY, 0.0_f64.copysign(self.im));
+        }
+        *self
+    }
+
+    /// # Division
+    /// 
+    /// Divides by $|w|^2$ when it is representable, and otherwise uses the algorithm of Smith, which avoids its
+    /// overflow or underflow.
+    fn divide(&self, rhs: Self) -> Self {
+        let div: f64 = rhs.re.powi(2) + rhs.im.powi(2);
+        if div.is_normal() {
+            return Self {
+                re: (self.re * rhs.re + self.im * rhs.im) / div,
+                im: (self.im * rhs.re - self.re * rhs.im) / div
+            };
+        }
+
+        if rhs.re.abs() >= rhs.im.abs() {
+            let r: f64 = rhs.im / rhs.re;
+            let d: f64 = rhs.re + rhs.im * r;
+            Self {
+                re: (self.re + self.im * r) / d,
+                im: (self.im - self.re * r) / d
+            }
+        } else {
+            let r: f64 = rhs.re / rhs.im;
+            let d: f64 = rhs.re * r + rhs.im;
+            Self {
+                re: (self.re * r + self.im) / d,
+                im: (self.im * r - self.re) / d
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Largest modulus
+/// 
+/// Finds the element with the largest modulus in the slice, the first one in case of a tie.
+/// Elements with a `NaN` modulus are skipped.
+/// 
+/// ## Inputs
+/// - `values`: the complex numbers to search
+/// 
+/// Returns the element, or `None` if the slice is empty or only holds `NaN`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::complex::{ Complex, max_by_modulus };
+/// let values = [Complex::from(1, 1), Complex::from(-3, 0.5), Complex::from(0, 2), Complex::from(f64::NAN, 0)];
+/// 
+/// assert_eq!(max_by_modulus(&values), Some(Complex::from(-3, 0.5)));
+/// assert_eq!(max_by_modulus(&[]), None);
+/// ```
+pub fn max_by_modulus(values: &[Complex]) -> Option<Complex> {
+    values.iter().filter(|c| !c.modulus().is_nan()).fold(None, |res: Option<Complex>, c| match res {
+        Some(m) if m.cmp_modulus(c) != Some(Ordering::Less) => Some(m),
+        _ => Some(*c)
+    })
+}
+
+/// # Smallest modulus
+/// 
+/// Finds the element with the smallest modulus in the slice, the first one in case of a tie.
+/// Elements with a `NaN` modulus are skipped.
+/// 
+/// ## Inputs
+/// - `values`: the complex numbers to search
+/// 
+/// Returns the element, or `None` if the slice is empty or only holds `NaN`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::complex::{ Complex, min_by_modulus };
+/// let values = [Complex::from(1, 1), Complex::from(-3, 0.5), Complex::from(0, -1), Complex::from(1, 0)];
+/// 
+/// assert_eq!(min_by_modulus(&values), Some(Complex::from(0, -1)));
+/// assert_eq!(min_by_modulus(&[]), None);
+/// ```
+pub fn min_by_modulus(values: &[Complex]) -> Option<Complex> {
+    values.iter().filter(|c| !c.modulus().is_nan()).fold(None, |res: Option<Complex>, c| match res {
+        Some(m) if m.cmp_modulus(c) != Some(Ordering::Greater) => Some(m),
+        _ => Some(*c)
+    })
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Here comes a long list of implementations for the operations
 
+/// Implements the conversion from each scalar type that converts losslessly to `f64`.
+/// 
+/// The impls are written per type rather than for any `T: Into<f64>`, which would
+/// forbid the conversion from a pair.
+macro_rules! impl_from_scalar {
+    ($($t:ty),*) => {
+        $(
+            /// # Conversion from a scalar
+            /// 
+            /// Takes a scalar value and assigns it to the real part.
+            impl From<$t> for Complex {
+                fn from(val: $t) -> Self {
+                    Self {
+                        re: val.into(),
+                        im: 0.0
+                    }
+                }
+            }
+        )*
+    };
+}
+
 /// # Conversion from a scalar
 /// 
-/// Takes a scalar value and assigns it to the real part, as long as the type
-/// allows conversion to `f64`.
+/// Takes a scalar value and assigns it to the real part, for the types that
+/// convert losslessly to `f64`: `f64`, `f32`, `i32`, `i16`, `i8`, `u32`, `u16`, `u8` and `bool`.
+/// 
+/// **Breaking change** (0.5.0): versions up to 0.4.3 implemented the conversion for any `T: Into<f64>`.
+/// The compiler rejects that impl alongside any conversion from a pair, since `std` could implement `Into<f64>`
+/// for tuples. A type of another crate implementing `Into<f64>` must now be converted to `f64` first, as in
+/// `let c: Complex = f64::from(val).into();`.
 /// 
 /// ```
 /// # use scilib::math::complex::Complex;
 /// let c1: Complex = 3.5.into();
 /// let c2: Complex = (-5).into();
+/// let c3: Complex = true.into();
 /// 
 /// assert!(c1.re == 3.5 && c1.im == 0.0);
 /// assert!(c2.re == -5.0 && c2.im == 0.0);
+/// assert!(c3.re == 1.0 && c3.im == 0.0);
 /// ```
-impl<T: Into<f64>> From<T> for Complex {
-    fn from(val: T) -> Self {
+impl From<f64> for Complex {
+    fn from(val: f64) -> Self {
         Self {
-            re: val.into(),
+            re: val,
             im: 0.0
         }
     }
 }
 
+impl_from_scalar!(f32, i32, i16, i8, u32, u16, u8, bool);
+
+/// # Conversion from a pair
+/// 
+/// Takes a pair of scalar values, the first being the real part and the second the imaginary part,
+/// as long as both types allow conversion to `f64`.
+/// 
+/// ```
+/// # use scilib::math::complex::Complex;
+/// let c1: Complex = (3, 4.5).into();
+/// let c2: Complex = (-2.5_f32, 1_u8).into();
+/// 
+/// assert!(c1.re == 3.0 && c1.im == 4.5);
+/// assert!(c2.re == -2.5 && c2.im == 1.0);
+/// assert_eq!(c1, Complex::from(3, 4.5));
+/// ```
+impl<T: Into<f64>, U: Into<f64>> From<(T, U)> for Complex {
+    fn from((re, im): (T, U)) -> Self {
+        Self {
+            re: re.into(),
+            im: im.into()
+        }
+    }
+}
+
 /// # Addition of complex numbers
 /// 
 /// ```
@@ -468,9 +1051,11 @@ impl<T: Into<f64>> From<T> for Complex {
 /// let c2 = Complex::from(5.0, 0.5);
 /// let res = c1 + c2;
 /// let res2 = c1 + 5.5;
+/// let res3 = c1 + 2_i32;
 /// 
 /// assert!(res.re == 7.1 && res.im == 3.5);
 /// assert!(res2.re == 7.6 && res2.im == 3.0);
+/// assert!(res3.re == 4.1 && res3.im == 3.0);
 /// ```
 impl<T: Into<Self>> Add<T> for Complex {
     type Output = Self;
@@ -502,6 +1087,22 @@ impl Add<Complex> for f64 {
     }
 }
 
+/// # Addition to i32 (integer): `i32 + c`
+/// 
+/// ```
+/// # use scilib::math::complex::Complex;
+/// let c = Complex::from(7, 2.0);
+/// let res = 3 + c;
+/// 
+/// assert!(res.re == 10.0 && res.im == 2.0);
+/// ```
+impl Add<Complex> for i32 {
+    type Output = Complex;
+    fn add(self, rhs: Complex) -> Self::Output {
+        f64::from(self) + rhs
+    }
+}
+
 /// # Assigning Addition
 /// 
 /// ```
@@ -509,7 +1110,7 @@ impl Add<Complex> for f64 {
 /// let mut c1 = Complex::from(2.1, 3.0);
 /// let mut c2 = Complex::from(5.0, 0.5);
 /// c1 += c2;
-/// c2 += 2;
+/// c2 += 2_i32;
 /// 
 /// assert!(c1.re == 7.1 && c1.im == 3.5);
 /// assert!(c2.re == 7.0 && c2.im == 0.5);
@@ -530,9 +1131,11 @@ impl<T: Into<Self>> AddAssign<T> for Complex {
 /// let c2 = Complex::from(5.0, 0.5);
 /// let res = c1 - c2;
 /// let res2 = c1 - 5;
+/// let res3 = c1 - 3_i32;
 /// 
 /// assert!(res.re == -2.9 && res.im == 2.5);
 /// assert!(res2.re == -2.9 && res2.im == 3.0);
+/// assert!(res3.re == 2.1 - 3.0 && res3.im == 3.0);
 /// ```
 impl<T: Into<Self>> Sub<T> for Complex {
     type Output = Self;
@@ -564,6 +1167,22 @@ impl Sub<Complex> for f64 {
     }
 }
 
+/// # Subtraction to i32 (integer): `i32 - c`
+/// 
+/// ```
+/// # use scilib::math::complex::Complex;
+/// let c = Complex::from(10, 2.0);
+/// let res = 3 - c;
+/// 
+/// assert!(res.re == -7.0 && res.im == -2.0);
+/// ```
+impl Sub<Complex> for i32 {
+    type Output = Complex;
+    fn sub(self, rhs: Complex) -> Self::Output {
+        f64::from(self) - rhs
+    }
+}
+
 /// # Assigning subtraction
 /// 
 /// ```
@@ -572,9 +1191,10 @@ impl Sub<Complex> for f64 {
 /// let mut c2 = Complex::from(5.0, 0.5);
 /// c1 -= c2;
 /// c2 -= 12.0;
+/// c2 -= -7_i32;
 /// 
 /// assert!(c1.re == -2.9 && c1.im == 2.5);
-/// assert!(c2.re == -7.0 && c2.im == 0.5);
+/// assert!(c2.re == 0.0 && c2.im == 0.5);
 /// ```
 impl<T: Into<Self>> SubAssign<T> for Complex {
     fn sub_assign(&mut self, rhs: T) {
@@ -592,9 +1212,11 @@ impl<T: Into<Self>> SubAssign<T> for Complex {
 /// let c2 = Complex::from(5.0, 0.5);
 /// let res = c1 * c2;
 /// let res2 = c1 * 2.0;
+/// let res3 = c1 * -2_i32;
 /// 
 /// assert!(res.re == 9.0 && res.im == 16.05);
 /// assert!(res2.re == 4.2 && res2.im == 6.0);
+/// assert!(res3.re == -4.2 && res3.im == -6.0);
 /// ```
 impl<T: Into<Self>> Mul<T> for Complex {
     type Output = Self;
@@ -626,6 +1248,22 @@ impl Mul<Complex> for f64 {
     }
 }
 
+/// # Multiplication to i32 (integer): `i32 * c`
+/// 
+/// ```
+/// # use scilib::math::complex::Complex;
+/// let c = Complex::from(5, 2.0);
+/// let res = 3 * c;
+/// 
+/// assert!(res.re == 15.0 && res.im == 6.0);
+/// ```
+impl Mul<Complex> for i32 {
+    type Output = Complex;
+    fn mul(self, rhs: Complex) -> Self::Output {
+        f64::from(self) * rhs
+    }
+}
+
 /// # Assigning multiplication
 /// 
 /// ```
@@ -633,7 +1271,7 @@ impl Mul<Complex> for f64 {
 /// let mut c1 = Complex::from(2.1, 3.0);
 /// let mut c2 = Complex::from(5.0, 0.5);
 /// c1 *= c2;
-/// c2 *= 2;
+/// c2 *= 2_i32;
 /// 
 /// assert!(c1.re == 9.0 && c1.im == 16.05);
 /// assert!(c2.re == 10.0 && c2.im == 1.0);
@@ -655,19 +1293,20 @@ impl<T: Into<Self>> MulAssign<T> for Complex {
 /// let c2 = Complex::from(5.0, 0.5);
 /// let res = c1 / c2;
 /// let res2 = c1 / 2.0;
+/// let res3 = c1 / 3_i32;
 /// 
 /// assert!((res.re - 0.47524752475).abs() < 1.0e-9 && (res.im - 0.5524752475).abs() < 1.0e-9);
 /// assert!(res2.re == 1.05 && res2.im == 1.5);
+/// assert!((res3.re - 0.7).abs() < 1.0e-15 && res3.im == 1.0);
+/// 
+/// // Large values do not overflow
+/// let res4 = Complex::from(1.0e300, 1.0e300) / Complex::from(2.0e300, 2.0e300);
+/// assert_eq!(res4, Complex::from(0.5, 0.0));
 /// ```
 impl<T: Into<Self>> Div<T> for Complex {
     type Output = Self;
     fn div(self, rhs: T) -> Self::Output {
-        let rhs: Self = rhs.into();
-        let div: f64 = rhs.re.powi(2) + rhs.im.powi(2);
-        Self {
-            re: (self.re * rhs.re + self.im * rhs.im) / div,
-            im: (self.im * rhs.re - self.re * rhs.im) / div
-        }
+        self.divide(rhs.into())
     }
 }
 
@@ -683,12 +1322,23 @@ impl<T: Into<Self>> Div<T> for Complex {
 impl Div<Complex> for f64 {
     type Output = Complex;
     fn div(self, rhs: Complex) -> Self::Output {
-        // num / (a + ib) == a * num / (a² + b²) - i * b * num / (a² + b²)
-        let modulus_squared = rhs.re.powi(2) + rhs.im.powi(2);
-        Complex {
-            re: self * rhs.re / modulus_squared,
-            im: -self * rhs.im / modulus_squared
-        }
+        Complex::from(self, 0.0).divide(rhs)
+    }
+}
+
+/// # Division to i32 (integer): `i32 / c`
+/// 
+/// ```
+/// # use scilib::math::complex::Complex;
+/// let c = Complex::from(2.0, 4.0);
+/// let res = 2 / c;
+/// 
+/// assert!(res.re == 0.2 && res.im == -0.4);
+/// ```
+impl Div<Complex> for i32 {
+    type Output = Complex;
+    fn div(self, rhs: Complex) -> Self::Output {
+        f64::from(self) / rhs
     }
 }
 
@@ -700,23 +1350,20 @@ impl Div<Complex> for f64 {
 /// let mut c2 = Complex::from(5.0, 0.5);
 /// c1 /= c2;
 /// c2 /= 10.0;
+/// c2 /= -5_i32;
 /// 
 /// assert!((c1.re - 0.47524752475).abs() < 1.0e-9 && (c1.im - 0.5524752475).abs() < 1.0e-9);
-/// assert!(c2.re == 0.5 && c2.im == 0.05);
+/// assert!(c2.re == -0.1 && c2.im == -0.01);
 /// ```
 impl<T: Into<Self>> DivAssign<T> for Complex {
     fn div_assign(&mut self, rhs: T) {
-        let rhs: Self = rhs.into();
-        let div: f64 = rhs.re.powi(2) + rhs.im.powi(2);
-        let old_re: f64 = self.re;
-        self.re = (self.re * rhs.re + self.im * rhs.im) / div;
-        self.im = (self.im * rhs.re - old_re * rhs.im) / div;
+        *self = self.divide(rhs.into());
     }
 }
 
 /// # Negation
 /// 
-/// Returns the opposite of the number
+/// Returns the opposite of the number, so that expressions such as `-Complex::i()` can be written directly.
 /// 
 /// ```
 /// # use scilib::math::complex::Complex;
@@ -724,6 +1371,7 @@ impl<T: Into<Self>> DivAssign<T> for Complex {
 /// let c_neg = -c;
 /// 
 /// assert!(c_neg.re == -1.0 && c_neg.im ==-0.05);
+/// assert_eq!(-Complex::i(), Complex::from(0, -1));
 /// ```
 impl Neg for Complex {
     type Output = Self;
@@ -0,0 +1,186 @@
+//!
+//! # Interpolation
+//! 
+//! This module provides methods to reconstruct a function from a set of sampled points.
+//! 
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use alloc::{               // Allocated types, also available without std
+    vec,
+    vec::Vec
+};
+
+/// # Lagrange interpolation
+/// 
+/// ## Definition
+/// The [Lagrange polynomial](https://en.wikipedia.org/wiki/Lagrange_polynomial) is the polynomial of lowest degree
+/// that goes through all the points $(x_j, y_j)$:
+/// $$
+/// L(x) = \sum_{j=0}^{k}y_j\ell_j(x) \quad \text{with} \quad \ell_j(x) = \prod_{m\neq j}\frac{x - x_m}{x_j - x_m}
+/// $$
+/// The basis polynomials $\ell_j$ are evaluated on the fly.
+/// 
+/// ## Inputs
+/// - `points`: the sampled points ($x_j$, $y_j$)
+/// - `x`: the value at which to interpolate ($x$)
+/// 
+/// Returns the interpolated value $L(x)$, or `NaN` if two points share the same $x$ coordinate.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::interpolation::lagrange_interpolate;
+/// // Sampling the polynomial 2x^2 - 3x + 1
+/// let f = |x: f64| 2.0 * x.powi(2) - 3.0 * x + 1.0;
+/// let points: Vec<(f64, f64)> = [-1.0, 0.5, 2.0].iter().map(|x| (*x, f(*x))).collect();
+/// 
+/// for x in [-3.0, -1.0, 0.25, 1.0, 4.5] {
+///     assert!((lagrange_interpolate(&points, x) - f(x)).abs() < 1.0e-12);
+/// }
+/// assert!(lagrange_interpolate(&[(1.0, 2.0), (1.0, 3.0)], 0.5).is_nan());
+/// ```
+pub fn lagrange_interpolate(points: &[(f64, f64)], x: f64) -> f64 {
+
+    let mut res: f64 = 0.0;
+
+    for (j, (xj, yj)) in points.iter().enumerate() {
+        let mut basis: f64 = 1.0;
+
+        for (m, (xm, _)) in points.iter().enumerate() {
+            if m == j {
+                continue;
+            }
+
+            // Two points with the same abscissa cannot be interpolated
+            if xj == xm {
+                return f64::NAN;
+            }
+
+            basis *= (x - xm) / (xj - xm);
+        }
+
+        res += yj * basis;
+    }
+
+    res
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Natural cubic spline
+/// 
+/// ## Definition
+/// A [cubic spline](https://en.wikipedia.org/wiki/Spline_interpolation) is a piecewise cubic polynomial
+/// going through all the knots $(x_i, y_i)$, and continuous up to its second derivative.
+/// On each interval $[x_i, x_{i+1}]$ of width $h_i$, with $t = x - x_i$ and $M_i$ the second derivatives at the knots:
+/// $$
+/// S(x) = y_i + \left( \frac{y_{i+1} - y_i}{h_i} - \frac{h_i(2M_i + M_{i+1})}{6} \right)t + \frac{M_i}{2}t^2 + \frac{M_{i+1} - M_i}{6h_i}t^3
+/// $$
+/// The natural boundary conditions $M_0 = M_n = 0$ close the tridiagonal system giving the $M_i$.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CubicSpline {
+    /// The abscissa of the knots
+    x: Vec<f64>,
+    /// The ordinates of the knots
+    y: Vec<f64>,
+    /// The second derivatives at the knots
+    m: Vec<f64>
+}
+
+/// Implementing the required methods
+impl CubicSpline {
+    /// # Creates a new natural cubic spline
+    /// ## Inputs
+    /// - `points`: the knots of the spline ($x_i$, $y_i$), sorted by strictly increasing $x$
+    /// 
+    /// The tridiagonal system is solved with the Thomas algorithm.
+    /// 
+    /// Returns a `Self`, the corresponding spline.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::interpolation::CubicSpline;
+    /// let points: Vec<(f64, f64)> = vec![(0.0, 1.0), (1.0, 3.0), (2.5, 2.0), (4.0, -1.0), (5.0, 0.5)];
+    /// let spline = CubicSpline::new(&points);
+    /// 
+    /// // The spline goes through all the knots
+    /// for (x, y) in &points {
+    ///     assert_eq!(spline.eval(*x), *y);
+    /// }
+    /// 
+    /// // The second derivative is continuous at the interior knots
+    /// let h: f64 = 1.0e-4;
+    /// for (x, _) in &points[1..4] {
+    ///     let left: f64 = (spline.eval(x - 2.0 * h) - 2.0 * spline.eval(x - h) + spline.eval(*x)) / h.powi(2);
+    ///     let right: f64 = (spline.eval(x + 2.0 * h) - 2.0 * spline.eval(x + h) + spline.eval(*x)) / h.powi(2);
+    ///     assert!((left - right).abs() < 1.0e-3);
+    /// }
+    /// ```
+    pub fn new(points: &[(f64, f64)]) -> Self {
+
+        // Checking that the knots are valid
+        assert!(points.len() >= 2, "A spline needs at least two knots!");
+        assert!(points.windows(2).all(|w| w[0].0 < w[1].0), "The knots must be sorted by strictly increasing x!");
+
+        let n: usize = points.len() - 1;
+        let x: Vec<f64> = points.iter().map(|p| p.0).collect();
+        let y: Vec<f64> = points.iter().map(|p| p.1).collect();
+        let h: Vec<f64> = x.windows(2).map(|w| w[1] - w[0]).collect();
+
+        // Forward sweep of the Thomas algorithm on the interior knots
+        let mut c_prime: Vec<f64> = vec![0.0; n + 1];
+        let mut d_prime: Vec<f64> = vec![0.0; n + 1];
+
+        for i in 1..n {
+            let diag: f64 = 2.0 * (h[i - 1] + h[i]) - h[i - 1] * c_prime[i - 1];
+            let rhs: f64 = 6.0 * ((y[i + 1] - y[i]) / h[i] - (y[i] - y[i - 1]) / h[i - 1]);
+            c_prime[i] = h[i] / diag;
+            d_prime[i] = (rhs - h[i - 1] * d_prime[i - 1]) / diag;
+        }
+
+        // Back substitution, with the natural boundary conditions
+        let mut m: Vec<f64> = vec![0.0; n + 1];
+
+        for i in (1..n).rev() {
+            m[i] = d_prime[i] - c_prime[i] * m[i + 1];
+        }
+
+        Self {
+            x,
+            y,
+            m
+        }
+    }
+
+    /// # Evaluates the spline
+    /// ## Inputs
+    /// - `x`: the value at which to evaluate the spline ($x$)
+    /// 
+    /// The interval is found with a binary search. Outside of the knots, the spline is extrapolated
+    /// with the cubic polynomial of the first or last interval.
+    /// 
+    /// Returns the value of the spline $S(x)$.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::interpolation::CubicSpline;
+    /// // A straight line is reproduced exactly, including when extrapolating
+    /// let spline = CubicSpline::new(&[(0.0, 1.0), (1.0, 3.0), (3.0, 7.0)]);
+    /// assert_eq!(spline.eval(2.0), 5.0);
+    /// assert_eq!(spline.eval(-1.0), -1.0);
+    /// assert_eq!(spline.eval(4.0), 9.0);
+    /// ```
+    pub fn eval(&self, x: f64) -> f64 {
+
+        // Index of the interval, the end intervals being extended for extrapolation
+        let i: usize = self.x.partition_point(|xi| *xi <= x).clamp(1, self.x.len() - 1) - 1;
+
+        let h: f64 = self.x[i + 1] - self.x[i];
+        let t: f64 = x - self.x[i];
+        let slope: f64 = (self.y[i + 1] - self.y[i]) / h - h * (2.0 * self.m[i] + self.m[i + 1]) / 6.0;
+
+        self.y[i] + t * (slope + t * (self.m[i] / 2.0 + t * (self.m[i + 1] - self.m[i]) / (6.0 * h)))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,108 @@
+//!
+//! # Matrix builders
+//! 
+//! This module provides the construction of some structured matrices, stored as nested vectors in row-major
+//! order, so that they can be handed to external linear algebra solvers.
+//! 
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use alloc::{               // Allocated types, also available without std
+    vec,
+    vec::Vec
+};
+
+/// # Companion matrix
+/// 
+/// ## Definition
+/// The [companion matrix](https://en.wikipedia.org/wiki/Companion_matrix) of the polynomial
+/// $p(x) = c_0 + c_1x + \dots + c_nx^n$ is the $n \times n$ matrix whose characteristic polynomial is $p/c_n$,
+/// so that its eigenvalues are the roots of $p$:
+/// $$
+/// C(p) = \begin{pmatrix} 0 & 0 & \dots & 0 & -c_0/c_n \\\\ 1 & 0 & \dots & 0 & -c_1/c_n \\\\ 0 & 1 & \dots & 0 & -c_2/c_n
+/// \\\\ \vdots & \vdots & \ddots & \vdots & \vdots \\\\ 0 & 0 & \dots & 1 & -c_{n-1}/c_n \end{pmatrix}
+/// $$
+/// 
+/// ## Inputs
+/// - `coeffs`: the coefficients of the polynomial, in increasing order of degree like `Polynomial`
+/// 
+/// Returns the companion matrix. Trailing zero coefficients are ignored, and a constant polynomial gives an
+/// empty matrix.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::matrix::companion_matrix;
+/// // x^2 - 1
+/// let c = companion_matrix(&[-1.0, 0.0, 1.0]);
+/// assert_eq!(c, vec![vec![0.0, 1.0], vec![1.0, 0.0]]);
+/// 
+/// // 2x^3 - 4x^2 + 6x + 8, with a trailing zero
+/// let c = companion_matrix(&[8.0, 6.0, -4.0, 2.0, 0.0]);
+/// assert_eq!(c, vec![
+///     vec![0.0, 0.0, -4.0],
+///     vec![1.0, 0.0, -3.0],
+///     vec![0.0, 1.0, 2.0]
+/// ]);
+/// 
+/// assert!(companion_matrix(&[3.0]).is_empty());
+/// ```
+pub fn companion_matrix(coeffs: &[f64]) -> Vec<Vec<f64>> {
+
+    // Degree of the polynomial, ignoring the trailing zeros
+    let n: usize = match coeffs.iter().rposition(|c| *c != 0.0) {
+        Some(d) => d,
+        None => return Vec::new()
+    };
+
+    let lead: f64 = coeffs[n];
+    let mut res: Vec<Vec<f64>> = vec![vec![0.0; n]; n];
+
+    for (i, row) in res.iter_mut().enumerate() {
+        if i > 0 {
+            row[i - 1] = 1.0;
+        }
+        row[n - 1] = -coeffs[i] / lead;
+    }
+
+    res
+}
+
+/// # Vandermonde matrix
+/// 
+/// ## Definition
+/// The [Vandermonde matrix](https://en.wikipedia.org/wiki/Vandermonde_matrix) of the points $x_0, \dots, x_{n-1}$
+/// holds the successive powers of each point on its rows:
+/// $$
+/// V_{ij} = x_i^j
+/// $$
+/// Solving $Va = y$ gives the coefficients $a$ of the interpolating polynomial in increasing order of degree.
+/// 
+/// ## Inputs
+/// - `xs`: the points ($x_i$)
+/// 
+/// Returns the square Vandermonde matrix.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::matrix::vandermonde;
+/// let v = vandermonde(&[1.0, 2.0, -3.0]);
+/// assert_eq!(v, vec![
+///     vec![1.0, 1.0, 1.0],
+///     vec![1.0, 2.0, 4.0],
+///     vec![1.0, -3.0, 9.0]
+/// ]);
+/// 
+/// assert!(vandermonde(&[]).is_empty());
+/// ```
+pub fn vandermonde(xs: &[f64]) -> Vec<Vec<f64>> {
+    xs.iter().map(|x| {
+        let mut pow: f64 = 1.0;
+        (0..xs.len()).map(|_| {
+            let val: f64 = pow;
+            pow *= x;
+            val
+        }).collect()
+    }).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
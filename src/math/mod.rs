@@ -8,10 +8,25 @@
 
 pub mod basic;
 
+#[cfg(feature = "alloc")]
 pub mod bessel;
 
+pub mod calculus;
+
 pub mod complex;
 
+#[cfg(feature = "alloc")]
+pub mod interpolation;
+
+#[cfg(feature = "alloc")]
+pub mod matrix;
+
+#[cfg(feature = "alloc")]
 pub mod polynomial;
 
+pub mod stats;
+
+#[cfg(feature = "alloc")]
+pub mod window;
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -4,11 +4,34 @@
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+use core::ops::{         // Implementing basic operations
+    Add,                // Addition
+    Mul                 // Multiplication
+};
+
 use super::{            // Using parts from the crate
     basic,              // Basic functions
     complex::Complex    // Using Complex numbers
 };
 
+use alloc::{               // Allocated types, also available without std
+    vec,
+    vec::Vec,
+    format,
+    string::String
+};
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;   // Float functions from libm
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Maximum number of iterations of the Durand-Kerner method
+const ROOTS_MAX_ITER: usize = 1_000;
+
+/// Precision used for the convergence of the Durand-Kerner method
+const ROOTS_PRECISION: f64 = 1.0e-14;
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// # Legendre polynomials
@@ -42,10 +65,10 @@ pub struct Legendre {
 }
 
 /// Display for the Legendre polynomials
-impl std::fmt::Display for Legendre {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Legendre {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         
-        let mut s: String = String::from(format!("{} * ( ", self.pre_f));
+        let mut s: String = format!("{} * ( ", self.pre_f);
 
         for (f, p) in self.factor.iter().zip(&self.power) {
             match p {
@@ -242,8 +265,8 @@ pub struct Laguerre {
 }
 
 /// Display for the Laguerre polynomials
-impl std::fmt::Display for Laguerre {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Laguerre {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         
         let mut s: String = String::from("");
 
@@ -387,8 +410,8 @@ pub struct Bernoulli {
 }
 
 /// Display for the Laguerre polynomials
-impl std::fmt::Display for Bernoulli {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Bernoulli {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         
         let mut s: String = String::from("");
 
@@ -564,8 +587,8 @@ pub struct Euler {
 }
 
 /// Display for the Laguerre polynomials
-impl std::fmt::Display for Euler {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Euler {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         
         let mut s: String = String::from("");
 
@@ -738,3 +761,309 @@ impl Euler {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Polynomial
+/// ## Definition
+/// A general [polynomial](https://en.wikipedia.org/wiki/Polynomial) with real coefficients:
+/// $$
+/// P(x) = \sum_{k=0}^{n}a_kx^k
+/// $$
+/// 
+/// The coefficients are stored by increasing power, so that `coef[k]` is $a_k$.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Polynomial {
+    /// The coefficients of the polynomial, by increasing power
+    pub coef: Vec<f64>
+}
+
+/// Display for the general polynomials
+impl core::fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        
+        let mut s: String = String::from("");
+
+        for (p, f) in self.coef.iter().enumerate() {
+            match p {
+                0 => s += &format!("{:+} ", f),
+                1 => s += &format!("{:+}x ", f),
+                _ => s += &format!("{:+}x^{} ", f, p)
+            }
+            
+        }
+        write!(f, "[n={}] :: {}", self.degree(), s)?;
+        Ok(())
+    }
+}
+
+/// Implementing the required methods
+impl Polynomial {
+    /// # Creates a new polynomial
+    /// ## Inputs
+    /// - `coef`: the coefficients of the polynomial, by increasing power
+    /// 
+    /// The trailing null coefficients are removed.
+    /// 
+    /// Returns a `Self`, the corresponding polynomial.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::polynomial::Polynomial;
+    /// let p = Polynomial::new(vec![1.0, 2.0, 3.0, 0.0]);   // 1 + 2x + 3x^2
+    /// assert_eq!(p.coef, vec![1.0, 2.0, 3.0]);
+    /// assert_eq!(p.degree(), 2);
+    /// ```
+    pub fn new(coef: Vec<f64>) -> Self {
+
+        let mut coef: Vec<f64> = coef;
+
+        // Removing the null coefficients of the highest powers
+        while coef.last() == Some(&0.0) {
+            coef.pop();
+        }
+
+        Self {
+            coef
+        }
+    }
+
+    /// # Degree of the polynomial
+    /// 
+    /// Returns the degree of the polynomial, 0 for constant and null polynomials.
+    pub fn degree(&self) -> usize {
+        self.coef.len().saturating_sub(1)
+    }
+
+    /// # Evaluate the polynomial
+    /// ## Inputs
+    /// - `x`: the value to evaluate ($x$)
+    /// 
+    /// The polynomial is evaluated with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+    /// 
+    /// Returns the result of the polynomial $P(x)$.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::polynomial::Polynomial;
+    /// let p = Polynomial::new(vec![1.0, 2.0, 3.0]);   // 1 + 2x + 3x^2
+    /// assert_eq!(p.eval(2.0), 17.0);
+    /// assert_eq!(p.eval(-0.5), 0.75);
+    /// ```
+    pub fn eval(&self, x: f64) -> f64 {
+        self.coef.iter().rev().fold(0.0, |res, c| res * x + c)
+    }
+
+    /// # Evaluate the polynomial for a complex number
+    /// ## Inputs
+    /// - `z`: the value to evaluate (`z`: complex)
+    /// 
+    /// The polynomial is evaluated with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+    /// 
+    /// Returns the result of the polynomial $P(z)$.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::complex::Complex;
+    /// # use scilib::math::polynomial::Polynomial;
+    /// let p = Polynomial::new(vec![1.0, 0.0, 1.0]);   // 1 + x^2
+    /// assert_eq!(p.eval_complex(Complex::i()), Complex::new());
+    /// assert_eq!(p.eval_complex(Complex::from(1.0, 1.0)), Complex::from(1.0, 2.0));
+    /// ```
+    pub fn eval_complex(&self, z: Complex) -> Complex {
+        self.coef.iter().rev().fold(Complex::new(), |res, c| res * z + *c)
+    }
+
+    /// # Derivative of the polynomial
+    /// 
+    /// Returns the polynomial $P'(x)$.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::polynomial::Polynomial;
+    /// let p = Polynomial::new(vec![1.0, 2.0, 3.0]);   // 1 + 2x + 3x^2
+    /// assert_eq!(p.derivative(), Polynomial::new(vec![2.0, 6.0]));
+    /// assert_eq!(Polynomial::new(vec![5.0]).derivative(), Polynomial::default());
+    /// ```
+    pub fn derivative(&self) -> Self {
+        Self::new(self.coef.iter().enumerate().skip(1).map(|(k, c)| k as f64 * c).collect())
+    }
+
+    /// # Integral of the polynomial
+    /// ## Inputs
+    /// - `constant`: the constant of integration ($C$)
+    /// 
+    /// Returns the polynomial $\int P(x)dx$, with constant term $C$.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::polynomial::Polynomial;
+    /// let p = Polynomial::new(vec![2.0, 6.0]);        // 2 + 6x
+    /// assert_eq!(p.integral(1.0), Polynomial::new(vec![1.0, 2.0, 3.0]));
+    /// assert_eq!(p.integral(1.0).derivative(), p);
+    /// ```
+    pub fn integral(&self, constant: f64) -> Self {
+
+        let mut coef: Vec<f64> = Vec::with_capacity(self.coef.len() + 1);
+        coef.push(constant);
+        coef.extend(self.coef.iter().enumerate().map(|(k, c)| c / (k + 1) as f64));
+
+        Self::new(coef)
+    }
+
+    /// # Roots of the polynomial
+    /// 
+    /// The roots are computed with the [Durand-Kerner method](https://en.wikipedia.org/wiki/Durand%E2%80%93Kerner_method),
+    /// which refines all the roots simultaneously on the monic polynomial:
+    /// $$
+    /// z_i \leftarrow z_i - \frac{P(z_i)}{\prod_{j\neq i}(z_i - z_j)}
+    /// $$
+    /// The null coefficients of the lowest powers are first removed, each of them giving a root at zero.
+    /// 
+    /// Returns the complex roots of the polynomial, with multiplicity, in no particular order.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::complex::Complex;
+    /// # use scilib::math::polynomial::Polynomial;
+    /// let p = Polynomial::new(vec![1.0, 0.0, 1.0]);           // 1 + x^2
+    /// let res = p.roots();
+    /// assert_eq!(res.len(), 2);
+    /// assert!(res.iter().any(|r| (*r - Complex::i()).modulus() < 1.0e-12));
+    /// assert!(res.iter().any(|r| (*r + Complex::i()).modulus() < 1.0e-12));
+    /// 
+    /// let p3 = Polynomial::new(vec![6.0, -7.0, 0.0, 1.0]);    // (x - 1)(x - 2)(x + 3)
+    /// for root in [1.0, 2.0, -3.0] {
+    ///     assert!(p3.roots().iter().any(|r| (*r - root).modulus() < 1.0e-12));
+    /// }
+    /// 
+    /// let p0 = Polynomial::new(vec![0.0, 0.0, -2.0, 1.0]);    // x^2 (x - 2)
+    /// let res0 = p0.roots();
+    /// assert_eq!(res0.iter().filter(|r| r.modulus() == 0.0).count(), 2);
+    /// assert!((res0[2] - 2.0).modulus() < 1.0e-12);
+    /// ```
+    pub fn roots(&self) -> Vec<Complex> {
+
+        // Deflating the roots at zero
+        let zeros: usize = self.coef.iter().take_while(|c| **c == 0.0).count();
+        let mut res: Vec<Complex> = vec![Complex::new(); zeros.min(self.degree())];
+        let coef: &[f64] = &self.coef[zeros.min(self.coef.len())..];
+
+        if coef.len() < 2 {
+            return res;
+        }
+
+        // Normalizing to a monic polynomial
+        let lead: f64 = coef[coef.len() - 1];
+        let monic: Self = Self::new(coef.iter().map(|c| c / lead).collect());
+        let n: usize = monic.degree();
+
+        // Initial guesses, spread on a spiral
+        let seed: Complex = Complex::from(0.4, 0.9);
+        let mut roots: Vec<Complex> = (0..n).map(|k| seed.powi(k as i32)).collect();
+
+        'convergence: for _ in 0..ROOTS_MAX_ITER {
+            let mut change: f64 = 0.0;
+
+            for i in 0..n {
+                let denom: Complex = (0..n).filter(|j| *j != i).fold(Complex::unity(), |d, j| d * (roots[i] - roots[j]));
+                let delta: Complex = monic.eval_complex(roots[i]) / denom;
+                roots[i] -= delta;
+                change = change.max(delta.modulus() / roots[i].modulus().max(1.0));
+            }
+
+            // We exit when convergence reaches the precision
+            if change < ROOTS_PRECISION {
+                break 'convergence;
+            }
+        }
+
+        res.extend(roots);
+        res
+    }
+}
+
+/// # Addition of polynomials
+/// 
+/// ```
+/// # use scilib::math::polynomial::Polynomial;
+/// let p1 = Polynomial::new(vec![1.0, 2.0, 3.0]);
+/// let p2 = Polynomial::new(vec![0.5, -2.0]);
+/// assert_eq!(p1 + p2, Polynomial::new(vec![1.5, 0.0, 3.0]));
+/// ```
+impl Add for Polynomial {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+
+        let (mut long, short): (Vec<f64>, Vec<f64>) = match self.coef.len() < rhs.coef.len() {
+            true => (rhs.coef, self.coef),
+            false => (self.coef, rhs.coef)
+        };
+
+        for (l, s) in long.iter_mut().zip(short) {
+            *l += s;
+        }
+
+        Self::new(long)
+    }
+}
+
+/// # Multiplication of polynomials
+/// 
+/// ```
+/// # use scilib::math::polynomial::Polynomial;
+/// let p1 = Polynomial::new(vec![1.0, 1.0]);       // 1 + x
+/// let p2 = Polynomial::new(vec![-1.0, 0.0, 2.0]); // -1 + 2x^2
+/// assert_eq!(p1.clone() * p2, Polynomial::new(vec![-1.0, -1.0, 2.0, 2.0]));
+/// assert_eq!(p1 * Polynomial::default(), Polynomial::default());
+/// ```
+impl Mul for Polynomial {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+
+        if self.coef.is_empty() || rhs.coef.is_empty() {
+            return Self::default();
+        }
+
+        let mut coef: Vec<f64> = vec![0.0; self.coef.len() + rhs.coef.len() - 1];
+
+        for (i, a) in self.coef.iter().enumerate() {
+            for (j, b) in rhs.coef.iter().enumerate() {
+                coef[i + j] += a * b;
+            }
+        }
+
+        Self::new(coef)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Power series evaluation
+/// 
+/// Evaluates a truncated power series with complex coefficients, with
+/// [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method):
+/// $$
+/// S(z) = \sum_{k=0}^{n}c_kz^k
+/// $$
+/// 
+/// ## Inputs
+/// - `coeffs`: the coefficients of the series ($c_k$), in increasing order of degree
+/// - `z`: the value to evaluate ($z$)
+/// 
+/// Returns the value of the series, zero for an empty slice.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::math::polynomial::eval_series;
+/// let z = Complex::from(0.5, -1.5);
+/// let coeffs = [Complex::from(1, 0), Complex::from(2, 0), Complex::from(3, 0)];
+/// 
+/// assert_eq!(eval_series(&coeffs, z), 1.0 + 2.0 * z + 3.0 * z * z);
+/// assert_eq!(eval_series(&[Complex::i(), Complex::unity()], z), Complex::i() + z);
+/// assert_eq!(eval_series(&[], z), Complex::new());
+/// ```
+pub fn eval_series(coeffs: &[Complex], z: Complex) -> Complex {
+    coeffs.iter().rev().fold(Complex::new(), |res, c| res * z + *c)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,230 @@
+//!
+//! # Statistics
+//! 
+//! This module provides descriptive statistics over samples, both for whole slices and in an online fashion.
+//! 
+//! Empty samples have no statistics, and the functions return `NaN` for them.
+//! 
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;   // Float functions from libm
+
+/// # Mean
+/// 
+/// ## Definition
+/// The [arithmetic mean](https://en.wikipedia.org/wiki/Arithmetic_mean) of the sample is:
+/// $$
+/// \bar{x} = \frac{1}{n}\sum_{i=1}^{n}x_i
+/// $$
+/// 
+/// ## Inputs
+/// - `xs`: the sample ($x_i$)
+/// 
+/// Returns the mean of the sample, or `NaN` if it is empty.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::stats::mean;
+/// assert_eq!(mean(&[1.0, 2.0, 4.5, -0.5]), 1.75);
+/// assert!(mean(&[]).is_nan());
+/// ```
+pub fn mean(xs: &[f64]) -> f64 {
+    xs.iter().sum::<f64>() / xs.len() as f64
+}
+
+/// # Variance
+/// 
+/// ## Definition
+/// The [variance](https://en.wikipedia.org/wiki/Variance) of the sample is computed in two passes, to avoid the
+/// cancellations of the naive formula:
+/// $$
+/// \sigma^2 = \frac{1}{n - \delta}\sum_{i=1}^{n}(x_i - \bar{x})^2
+/// $$
+/// Where $\delta = 0$ for the population variance, and $\delta = 1$ for the unbiased sample variance.
+/// 
+/// ## Inputs
+/// - `xs`: the sample ($x_i$)
+/// - `population`: whether to compute the population variance rather than the sample one
+/// 
+/// Returns the variance, or `NaN` if the sample is empty, or has a single value for the sample variance.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::stats::variance;
+/// let xs = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+/// assert_eq!(variance(&xs, true), 4.0);
+/// assert_eq!(variance(&xs, false), 32.0 / 7.0);
+/// 
+/// assert_eq!(variance(&[3.0], true), 0.0);
+/// assert!(variance(&[3.0], false).is_nan());
+/// assert!(variance(&[], true).is_nan());
+/// ```
+pub fn variance(xs: &[f64], population: bool) -> f64 {
+
+    let n: f64 = xs.len() as f64;
+    let m: f64 = mean(xs);
+    let sum: f64 = xs.iter().map(|x| (x - m).powi(2)).sum();
+
+    if population {
+        sum / n
+    } else {
+        sum / (n - 1.0)
+    }
+}
+
+/// # Standard deviation
+/// 
+/// ## Definition
+/// The [standard deviation](https://en.wikipedia.org/wiki/Standard_deviation) is the square root of the variance.
+/// 
+/// ## Inputs
+/// - `xs`: the sample ($x_i$)
+/// - `population`: whether to use the population variance rather than the sample one
+/// 
+/// Returns the standard deviation, with the same `NaN` cases as `variance`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::stats::std_dev;
+/// let xs = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+/// assert_eq!(std_dev(&xs, true), 2.0);
+/// ```
+pub fn std_dev(xs: &[f64], population: bool) -> f64 {
+    variance(xs, population).sqrt()
+}
+
+/// # Weighted mean
+/// 
+/// ## Definition
+/// The [weighted arithmetic mean](https://en.wikipedia.org/wiki/Weighted_arithmetic_mean) of the sample is:
+/// $$
+/// \bar{x} = \frac{\sum_{i=1}^{n}w_ix_i}{\sum_{i=1}^{n}w_i}
+/// $$
+/// 
+/// ## Inputs
+/// - `xs`: the sample ($x_i$)
+/// - `ws`: the weights ($w_i$), of the same length as the sample
+/// 
+/// Returns the weighted mean, or `NaN` if the sample is empty, the weights sum to zero or the lengths differ.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::stats::{ mean, weighted_mean };
+/// assert_eq!(weighted_mean(&[1.0, 2.0, 4.0], &[1.0, 0.0, 3.0]), 3.25);
+/// 
+/// // Equal weights give the mean
+/// let xs = [0.3, -1.2, 5.5];
+/// assert!((weighted_mean(&xs, &[2.0; 3]) - mean(&xs)).abs() < 1.0e-15);
+/// 
+/// assert!(weighted_mean(&xs, &[1.0, 2.0]).is_nan());
+/// ```
+pub fn weighted_mean(xs: &[f64], ws: &[f64]) -> f64 {
+
+    if xs.len() != ws.len() {
+        return f64::NAN;
+    }
+
+    let (sum, weight): (f64, f64) = xs.iter().zip(ws).fold((0.0, 0.0), |(s, w), (x, wi)| (s + wi * x, w + wi));
+    sum / weight
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Running statistics
+/// 
+/// Accumulates the mean and variance of a sample one value at a time, without storing it, using the
+/// [algorithm of Welford](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm):
+/// $$
+/// \bar{x}_n = \bar{x}_{n-1} + \frac{x_n - \bar{x}_{n-1}}{n} \quad \text{and} \quad
+/// M_n = M_{n-1} + (x_n - \bar{x}_{n-1})(x_n - \bar{x}_n)
+/// $$
+/// Where $M_n$ is the sum of the squared deviations to the mean.
+/// 
+/// ```
+/// # use scilib::math::stats::{ RunningStats, mean, variance };
+/// let xs = [1.0e9 + 4.0, 1.0e9 + 7.0, 1.0e9 + 13.0, 1.0e9 + 16.0];
+/// let mut rs = RunningStats::new();
+/// for x in xs {
+///     rs.push(x);
+/// }
+/// 
+/// assert_eq!(rs.count(), 4);
+/// assert_eq!(rs.mean(), mean(&xs));
+/// assert_eq!(rs.variance(false), variance(&xs, false));
+/// assert_eq!(rs.variance(false), 30.0);
+/// 
+/// assert!(RunningStats::new().mean().is_nan());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RunningStats {
+    /// Number of values pushed
+    n: usize,
+    /// Current mean
+    mean: f64,
+    /// Sum of the squared deviations to the mean
+    m2: f64
+}
+
+impl RunningStats {
+    /// # Creates a new entity
+    /// 
+    /// Starts with an empty sample.
+    pub const fn new() -> Self {
+        Self {
+            n: 0,
+            mean: 0.0,
+            m2: 0.0
+        }
+    }
+
+    /// # Adding a value
+    /// 
+    /// Updates the statistics with a new value of the sample.
+    pub fn push(&mut self, x: f64) {
+        self.n += 1;
+        let delta: f64 = x - self.mean;
+        self.mean += delta / self.n as f64;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    /// # Number of values
+    /// 
+    /// Returns the number of values pushed so far.
+    pub fn count(&self) -> usize {
+        self.n
+    }
+
+    /// # Mean
+    /// 
+    /// Returns the mean of the values pushed so far, or `NaN` if there are none.
+    pub fn mean(&self) -> f64 {
+        if self.n == 0 {
+            f64::NAN
+        } else {
+            self.mean
+        }
+    }
+
+    /// # Variance
+    /// 
+    /// Returns the population or sample variance of the values pushed so far, with the same `NaN` cases as `variance`.
+    pub fn variance(&self, population: bool) -> f64 {
+        let n: f64 = self.n as f64;
+        if population {
+            self.m2 / n
+        } else {
+            self.m2 / (n - 1.0)
+        }
+    }
+
+    /// # Standard deviation
+    /// 
+    /// Returns the square root of the variance.
+    pub fn std_dev(&self, population: bool) -> f64 {
+        self.variance(population).sqrt()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,156 @@
+//!
+//! # Window functions
+//! 
+//! This module provides the usual [window functions](https://en.wikipedia.org/wiki/Window_function) of signal
+//! processing, used to taper a signal before its Fourier transform or to design filters.
+//! 
+//! The windows are symmetric, with $N$ the number of points, and equal to $1$ for a single point.
+//! 
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use core::f64::consts::TAU;  // Tau constant
+
+use alloc::{               // Allocated types, also available without std
+    vec,
+    vec::Vec
+};
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;   // Float functions from libm
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Hann window
+/// 
+/// ## Definition
+/// The [Hann window](https://en.wikipedia.org/wiki/Hann_function) is defined as:
+/// $$
+/// w_k = \frac{1}{2} - \frac{1}{2}\cos\left( \frac{2\pi k}{N-1} \right)
+/// $$
+/// 
+/// ## Inputs
+/// - `n`: the number of points ($N$)
+/// 
+/// Returns the window, of length `n`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::window::hann;
+/// let w = hann(9);
+/// 
+/// // Symmetric, with its peak at the center
+/// for k in 0..9 {
+///     assert!((w[k] - w[8 - k]).abs() < 1.0e-15);
+/// }
+/// assert_eq!(w[4], 1.0);
+/// assert_eq!(w[0], 0.0);
+/// assert!((w[2] - 0.5).abs() < 1.0e-15);
+/// 
+/// assert!(hann(0).is_empty());
+/// assert_eq!(hann(1), vec![1.0]);
+/// ```
+pub fn hann(n: usize) -> Vec<f64> {
+    cosine_sum(n, &[0.5, 0.5])
+}
+
+/// # Hamming window
+/// 
+/// ## Definition
+/// The [Hamming window](https://en.wikipedia.org/wiki/Window_function#Hann_and_Hamming_windows) is defined as:
+/// $$
+/// w_k = 0.54 - 0.46\cos\left( \frac{2\pi k}{N-1} \right)
+/// $$
+/// 
+/// ## Inputs
+/// - `n`: the number of points ($N$)
+/// 
+/// Returns the window, of length `n`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::window::hamming;
+/// let w = hamming(5);
+/// 
+/// assert!((w[0] - 0.08).abs() < 1.0e-15 && (w[4] - 0.08).abs() < 1.0e-15);
+/// assert!((w[1] - 0.54).abs() < 1.0e-15);
+/// assert_eq!(w[2], 1.0);
+/// assert_eq!(hamming(1), vec![1.0]);
+/// ```
+pub fn hamming(n: usize) -> Vec<f64> {
+    cosine_sum(n, &[0.54, 0.46])
+}
+
+/// # Blackman window
+/// 
+/// ## Definition
+/// The [Blackman window](https://en.wikipedia.org/wiki/Window_function#Blackman_window) is defined as:
+/// $$
+/// w_k = 0.42 - 0.5\cos\left( \frac{2\pi k}{N-1} \right) + 0.08\cos\left( \frac{4\pi k}{N-1} \right)
+/// $$
+/// 
+/// ## Inputs
+/// - `n`: the number of points ($N$)
+/// 
+/// Returns the window, of length `n`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::window::blackman;
+/// let w = blackman(5);
+/// 
+/// assert!(w[0].abs() < 1.0e-15 && w[4].abs() < 1.0e-15);
+/// assert!((w[1] - 0.34).abs() < 1.0e-15);
+/// assert!((w[2] - 1.0).abs() < 1.0e-15);
+/// assert!(blackman(0).is_empty());
+/// ```
+pub fn blackman(n: usize) -> Vec<f64> {
+    cosine_sum(n, &[0.42, 0.5, 0.08])
+}
+
+/// # Gaussian window
+/// 
+/// ## Definition
+/// The [Gaussian window](https://en.wikipedia.org/wiki/Window_function#Gaussian_window) is defined as:
+/// $$
+/// w_k = \exp\left( -\frac{1}{2}\left( \frac{k - (N-1)/2}{\sigma} \right)^2 \right)
+/// $$
+/// 
+/// ## Inputs
+/// - `n`: the number of points ($N$)
+/// - `sigma`: the standard deviation, in number of points ($\sigma$)
+/// 
+/// Returns the window, of length `n`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::window::gaussian_window;
+/// let w = gaussian_window(7, 2.0);
+/// 
+/// assert_eq!(w[3], 1.0);
+/// assert_eq!(w[1], w[5]);
+/// assert!((w[1] - (-0.5_f64).exp()).abs() < 1.0e-15);
+/// assert_eq!(gaussian_window(1, 2.0), vec![1.0]);
+/// ```
+pub fn gaussian_window(n: usize, sigma: f64) -> Vec<f64> {
+    let center: f64 = (n as f64 - 1.0) / 2.0;
+    (0..n).map(|k| (-0.5 * ((k as f64 - center) / sigma).powi(2)).exp()).collect()
+}
+
+/// Computes the generalized cosine window $w_k = \sum_j (-1)^ja_j\cos(2\pi jk/(N-1))$.
+fn cosine_sum(n: usize, coeffs: &[f64]) -> Vec<f64> {
+
+    if n == 1 {
+        return vec![1.0];
+    }
+
+    let step: f64 = TAU / (n as f64 - 1.0);
+    (0..n).map(|k| {
+        coeffs.iter().enumerate().fold(0.0, |res, (j, a)| {
+            let sign: f64 = if j % 2 == 0 { 1.0 } else { -1.0 };
+            res + sign * a * (step * (j * k) as f64).cos()
+        })
+    }).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
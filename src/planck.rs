@@ -6,6 +6,14 @@
 
 use super::constant as cst;
 
+#[cfg(feature = "alloc")]
+use alloc::{               // Allocated types, also available without std
+    vec::Vec
+};
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;   // Float functions from libm
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// # Frequency Planck's law
@@ -58,6 +66,7 @@ pub fn frequency(temperature: f64, nu: f64) -> f64 {
 /// let res = planck::frequency_vec(temp, &freq);
 /// assert!((res[8] - 1.458e-9).abs() < 1.0e-10);
 /// ```
+#[cfg(feature = "alloc")]
 pub fn frequency_vec(temperature: f64, nu: &[f64]) -> Vec<f64> {
 
     let factor: f64 = 2.0 * cst::H / cst::C.powi(2);
@@ -115,6 +124,7 @@ pub fn wavelength(temperature: f64, lambda: f64) -> f64 {
 /// let res = planck::wavelength_vec(temp, &wave);
 /// assert!((res[8] - 4.408e11).abs() < 1.0e8);
 /// ```
+#[cfg(feature = "alloc")]
 pub fn wavelength_vec(temperature: f64, lambda: &[f64]) -> Vec<f64> {
 
     let factor: f64 = 2.0 * cst::H * cst::C.powi(2);
@@ -160,6 +170,7 @@ pub fn wavenumber(temperature: f64, number: f64) -> f64 {
 /// Returns the corresponding irradiances.
 /// 
 /// ## Example
+#[cfg(feature = "alloc")]
 pub fn wavenumber_vec(temperature: f64, number: &[f64]) -> Vec<f64> {
 
     let factor: f64 = 2.0 * cst::H * cst::C.powi(2);
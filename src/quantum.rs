@@ -21,8 +21,9 @@
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-use std::f64::consts::{     // Using std lib constants
-    PI                      // Pi
+use core::f64::consts::{     // Using std lib constants
+    PI,                     // Pi
+    SQRT_2                  // Square root of 2
 };
 
 use crate::{                // Calling other modules
@@ -34,6 +35,13 @@ use crate::{                // Calling other modules
     constant as cst         // Calling scilib constants
 };
 
+use alloc::{               // Allocated types, also available without std
+    vec::Vec
+};
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;   // Float functions from libm
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// # Azimuthal quantum number $l$
@@ -165,6 +173,25 @@ pub fn radial_vec(n: usize, l: usize, r: &[f64]) -> Vec<f64> {
     res
 }
 
+/// # Spherical harmonics normalization
+/// 
+/// The [spherical harmonics](https://en.wikipedia.org/wiki/Spherical_harmonics#Conventions) only differ between
+/// domains by their normalization factor $N_l^m$:
+/// $$
+/// Y_l^m(\theta, \phi) = N_l^m P_l^{|m|}(\cos(\theta)) \exp(im\phi)
+/// $$
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SphericalHarmonicConvention {
+    /// Orthonormal, with the Condon-Shortley phase used in quantum mechanics:
+    /// $N_l^m = (-1)^m \sqrt{\frac{(2l+1)}{4\pi}\frac{(l-m)!}{(l+m)!}}$ for $m \ge 0$, without the phase for $m < 0$.
+    Quantum,
+    /// Schmidt semi-normalized, used in geomagnetism (IGRF models):
+    /// $N_l^m = \sqrt{(2 - \delta_{m0})\frac{(l-|m|)!}{(l+|m|)!}}$.
+    Schmidt,
+    /// No normalization: $N_l^m = 1$.
+    Unnormalized
+}
+
 /// # Spherical harmonics
 /// Provides the solution to the angular $Y_l^m(\theta, \phi)$ wave-function:
 /// $$
@@ -175,6 +202,8 @@ pub fn radial_vec(n: usize, l: usize, r: &[f64]) -> Vec<f64> {
 /// For any `l` ($l$) the azimuthal quantum number and `m` ($m$) the magnetic quantum number. The equation
 /// produces the solution for a given set of angles $\theta$ and $\phi$.
 /// 
+/// This is the `Quantum` convention of `spherical_harmonics_convention`.
+/// 
 /// ```
 /// # use scilib::quantum::spherical_harmonics;
 /// // Computing the Ylm for l=2, m=1 at theta = 0.2rad and phi = -0.7rad
@@ -182,27 +211,143 @@ pub fn radial_vec(n: usize, l: usize, r: &[f64]) -> Vec<f64> {
 /// assert!((res.re - -0.11504928).abs() < 1.0e-8 && (res.im - 0.09690468).abs() < 1.0e-8);
 /// ```
 pub fn spherical_harmonics(l: usize, m: i32, theta: f64, phi: f64) -> Complex {
+    spherical_harmonics_convention(l, m, theta, phi, SphericalHarmonicConvention::Quantum)
+}
+
+/// # Spherical harmonics with a given normalization
+/// Computes the spherical harmonics for the chosen normalization convention:
+/// $$
+/// Y_l^m(\theta, \phi) = N_l^m P_l^{|m|}(\cos(\theta)) \exp(im\phi)
+/// $$
+/// With $P_n^m$ the Legendre polynomials, and $N_l^m$ the factor described in `SphericalHarmonicConvention`.
+/// 
+/// ## Inputs
+/// - `l`: the degree ($l$)
+/// - `m`: the order ($m$), with $-l \le m \le l$
+/// - `theta`: the polar angle ($\theta$)
+/// - `phi`: the azimuthal angle ($\phi$)
+/// - `convention`: the normalization to use
+/// 
+/// Returns the value of $Y_l^m(\theta, \phi)$.
+/// 
+/// ## Example
+/// ```
+/// # use std::f64::consts::PI;
+/// # use scilib::quantum::{ spherical_harmonics_convention, SphericalHarmonicConvention };
+/// let (theta, phi) = (0.8, 2.1);
+/// let y = |l, m, c| spherical_harmonics_convention(l, m, theta, phi, c);
+/// 
+/// // Y_1^0 = cos(theta) without normalization
+/// assert_eq!(y(1, 0, SphericalHarmonicConvention::Unnormalized).re, theta.cos());
+/// 
+/// // The ratio between the conventions only depends on l and m
+/// for (l, m) in [(1, 0), (2, 1), (3, -2), (4, 4)] {
+///     let q = y(l, m, SphericalHarmonicConvention::Quantum);
+///     let s = y(l, m, SphericalHarmonicConvention::Schmidt);
+///     let phase = if m > 0 { (-1.0_f64).powi(m) } else { 1.0 };
+///     let delta = if m == 0 { 1.0 } else { 2.0 };
+///     let ratio = phase * ((2 * l + 1) as f64 / (4.0 * PI * delta)).sqrt();
+///     assert!((q - ratio * s).modulus() < 1.0e-14);
+/// }
+/// ```
+pub fn spherical_harmonics_convention(l: usize, m: i32, theta: f64, phi: f64, convention: SphericalHarmonicConvention) -> Complex {
 
     // We do the computation for the positive value
     let mp: i32 = m.abs();
-    let cpx: Complex = Complex::from(0, mp as f64 * phi).exp();
+    let cpx: Complex = Complex::from(0, m as f64 * phi).exp();
     let poly = polynomial::Legendre::new(l, mp);
 
-    // We follow QM norm
-    let norm: f64 = (2 * l + 1) as f64 / (4.0 * PI);
+    // Ratio of the factorials shared by the normalized conventions
     let top: f64 = basic::factorial(l - mp as usize) as f64;
     let bot: f64 = basic::factorial(l + mp as usize) as f64;
 
-    // Computation with Legendre polynomial
-    // (-1.0_f64).powi(m) term for the Condon-Shortley phase
-    let res: Complex = (-1.0_f64).powi(m) * cpx * (norm * top / bot).sqrt() * poly.compute(theta.cos());
+    let norm: f64 = match convention {
+        SphericalHarmonicConvention::Quantum => {
+            // (-1.0_f64).powi(m) term for the Condon-Shortley phase
+            let phase: f64 = if m > 0 { (-1.0_f64).powi(m) } else { 1.0 };
+            phase * ((2 * l + 1) as f64 / (4.0 * PI) * top / bot).sqrt()
+        },
+        SphericalHarmonicConvention::Schmidt => {
+            let delta: f64 = if m == 0 { 1.0 } else { 2.0 };
+            (delta * top / bot).sqrt()
+        },
+        SphericalHarmonicConvention::Unnormalized => 1.0
+    };
+
+    norm * poly.compute(theta.cos()) * cpx
+}
+
+/// # Real spherical harmonics
+/// Provides the [real spherical harmonics](https://en.wikipedia.org/wiki/Spherical_harmonics#Real_form), formed
+/// from the combinations of $Y_l^m$ and $Y_l^{-m}$:
+/// $$
+/// Y_{lm} = \begin{cases} \sqrt{2}N_l^{|m|}P_l^{|m|}(\cos(\theta))\sin(|m|\phi) & m < 0 \\\\
+/// N_l^0P_l(\cos(\theta)) & m = 0 \\\\ \sqrt{2}N_l^mP_l^m(\cos(\theta))\cos(m\phi) & m > 0 \end{cases}
+/// $$
+/// With $N_l^m = \sqrt{\frac{(2l+1)}{4\pi}\frac{(l-m)!}{(l+m)!}}$, the Condon-Shortley phase cancelling out.
+/// They are orthonormal over the sphere.
+/// 
+/// ## Inputs
+/// - `l`: the degree ($l$)
+/// - `m`: the order ($m$), with $-l \le m \le l$
+/// - `theta`: the polar angle ($\theta$)
+/// - `phi`: the azimuthal angle ($\phi$)
+/// 
+/// Returns the value of $Y_{lm}(\theta, \phi)$.
+/// 
+/// ## Example
+/// ```
+/// # use std::f64::consts::PI;
+/// # use scilib::quantum::real_spherical_harmonic;
+/// // The l=1 set are the p_y, p_z and p_x orbitals
+/// let (theta, phi) = (0.8, 2.1);
+/// let n = (3.0 / (4.0 * PI)).sqrt();
+/// assert!((real_spherical_harmonic(1, -1, theta, phi) - n * theta.sin() * phi.sin()).abs() < 1.0e-15);
+/// assert!((real_spherical_harmonic(1, 0, theta, phi) - n * theta.cos()).abs() < 1.0e-15);
+/// assert!((real_spherical_harmonic(1, 1, theta, phi) - n * theta.sin() * phi.cos()).abs() < 1.0e-15);
+/// 
+/// // Orthonormality up to l=2, with the midpoint rule over the sphere
+/// let (nt, np) = (60, 120);
+/// let (dt, dp) = (PI / nt as f64, 2.0 * PI / np as f64);
+/// let lm: Vec<(usize, i32)> = (0..=2).flat_map(|l: i32| (-l..=l).map(move |m| (l as usize, m))).collect();
+/// let mut dot = vec![vec![0.0; lm.len()]; lm.len()];
+/// for i in 0..nt {
+///     let t = (i as f64 + 0.5) * dt;
+///     for j in 0..np {
+///         let p = (j as f64 + 0.5) * dp;
+///         let y: Vec<f64> = lm.iter().map(|&(l, m)| real_spherical_harmonic(l, m, t, p)).collect();
+///         for a in 0..lm.len() {
+///             for b in 0..lm.len() {
+///                 dot[a][b] += y[a] * y[b] * t.sin() * dt * dp;
+///             }
+///         }
+///     }
+/// }
+/// for a in 0..lm.len() {
+///     for b in 0..lm.len() {
+///         let expected = if a == b { 1.0 } else { 0.0 };
+///         assert!((dot[a][b] - expected).abs() < 1.0e-3);
+///     }
+/// }
+/// ```
+pub fn real_spherical_harmonic(l: usize, m: i32, theta: f64, phi: f64) -> f64 {
+
+    let mp: i32 = m.abs();
+    let poly = polynomial::Legendre::new(l, mp);
+
+    // Orthonormal factor, without the Condon-Shortley phase
+    let norm: f64 = (2 * l + 1) as f64 / (4.0 * PI);
+    let top: f64 = basic::factorial(l - mp as usize) as f64;
+    let bot: f64 = basic::factorial(l + mp as usize) as f64;
+    let res: f64 = (norm * top / bot).sqrt() * poly.compute(theta.cos());
 
-    // Modifying the result depending on the sign of m
-    if m.is_negative() {
-        res.conjugate() * (-1.0_f64).powi(mp)
+    if m > 0 {
+        SQRT_2 * res * (m as f64 * phi).cos()
+    } else if m < 0 {
+        SQRT_2 * res * (mp as f64 * phi).sin()
     } else {
         res
     }
 }
 
-////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+////////
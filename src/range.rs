@@ -4,6 +4,14 @@
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+use alloc::{               // Allocated types, also available without std
+    vec,
+    vec::Vec
+};
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;   // Float functions from libm
+
 /// # Creating a range by increment
 /// 
 /// Creating a range with the start, stop, and increment. If the range is invalid, an empty Vec is returned.
@@ -4,20 +4,28 @@
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-use std::f64::consts::{     // Using std lib constants
-    PI                      // Pi
+use core::f64::consts::{     // Using std lib constants
+    PI,                     // Pi
+    TAU                     // Tau
 };
 
 use crate::{                // Calling other modules
     math::complex::Complex  // Using Complex numbers
 };
 
+use alloc::{               // Allocated types, also available without std
+    vec,
+    vec::Vec
+};
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// # Convolution
 /// 
 /// Computes the convolution of two vectors, including the edges.
 /// 
+/// For inputs of lengths $n$ and $m$, the result has length $n+m-1$; it is empty if either input is empty.
+/// 
 /// ```
 /// # use scilib::signal::convolve;
 /// // Creating two vectors to convolve
@@ -27,9 +35,20 @@ use crate::{                // Calling other modules
 /// let expected: Vec<f64> = vec![0.7, 3.425, 4.15, 2.375, 1.8, 3.5125, 1.85, 0.125];
 /// 
 /// assert_eq!(res, expected);
+/// 
+/// // Hand-computed: [1, 2, 3] * [1, 1] = [1, 1+2, 2+3, 3]
+/// assert_eq!(convolve(&[1.0, 2.0, 3.0], &[1.0, 1.0]), vec![1.0, 3.0, 5.0, 3.0]);
+/// 
+/// // Convolving with [1] is the identity
+/// assert_eq!(convolve(&a1, &[1.0]), a1);
+/// assert!(convolve(&a1, &[]).is_empty());
 /// ```
 pub fn convolve<T>(a_i: &[T], b_i: &[T]) -> Vec<T>
-where T: std::ops::Mul<Output = T> + std::ops::AddAssign + Default + Copy {
+where T: core::ops::Mul<Output = T> + core::ops::AddAssign + Default + Copy {
+
+    if a_i.is_empty() || b_i.is_empty() {
+        return vec![];
+    }
 
     // We check which box is the smallest
     let (a, b): (&[T], &[T]) = match a_i.len() < b_i.len() {
@@ -41,7 +60,7 @@ where T: std::ops::Mul<Output = T> + std::ops::AddAssign + Default + Copy {
     let mut sum: T;
     let l_a: usize = a.len();
     let l_b: usize = b.len();
-    let mut res: Vec<T> = Vec::with_capacity(l_a + 2 * l_b - 2);    // Complete convolution length
+    let mut res: Vec<T> = Vec::with_capacity(l_a + l_b - 1);        // Complete convolution length
 
     // Box b starts to slide over a
     for n in 0..(l_b - 1) {
@@ -92,7 +111,7 @@ where T: std::ops::Mul<Output = T> + std::ops::AddAssign + Default + Copy {
 /// }
 /// ```
 pub fn convolve_full<T>(a_i: &[T], b_i: &[T]) -> Vec<T>
-where T: std::ops::Mul<Output = T> + std::ops::AddAssign + Default + Copy {
+where T: core::ops::Mul<Output = T> + core::ops::AddAssign + Default + Copy {
 
     // We check which box is the smallest
     let (a, b): (&[T], &[T]) = match a_i.len() < b_i.len() {
@@ -118,13 +137,147 @@ where T: std::ops::Mul<Output = T> + std::ops::AddAssign + Default + Copy {
     res
 }
 
+/// # Moving average
+/// 
+/// Computes the mean of each run of `window` consecutive samples of the signal, which smooths it.
+/// This is the exact convolution of the signal with a box of `window` points of height `1 / window`.
+/// 
+/// The result has length `signal.len() - window + 1`; it is empty if `window` is zero or larger than the signal.
+/// 
+/// ```
+/// # use scilib::signal::moving_average;
+/// let s: Vec<f64> = vec![1.0, 2.0, 6.0, 3.0, 8.0];
+/// let res = moving_average(&s, 3);
+/// let expected: Vec<f64> = vec![3.0, 11.0 / 3.0, 17.0 / 3.0];
+/// 
+/// assert_eq!(res.len(), 3);
+/// for (e, c) in expected.iter().zip(&res) {
+///     assert!((e - c).abs() < 1.0e-15);
+/// }
+/// 
+/// // A window of one leaves the signal unchanged
+/// assert_eq!(moving_average(&s, 1), s);
+/// assert!(moving_average(&s, 6).is_empty());
+/// ```
+pub fn moving_average(signal: &[f64], window: usize) -> Vec<f64> {
+
+    if window == 0 || window > signal.len() {
+        return vec![];
+    }
+
+    let kernel: Vec<f64> = vec![1.0 / window as f64; window];
+    convolve_full(signal, &kernel)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Discrete Fourier transform
+/// 
+/// ## Definition
+/// The [discrete Fourier transform](https://en.wikipedia.org/wiki/Discrete_Fourier_transform) of $N$ points is:
+/// $$
+/// X_k = \sum_{n=0}^{N-1}x_n\exp\left( -\frac{2i\pi kn}{N} \right)
+/// $$
+/// 
+/// The sum is computed directly, in $O(N^2)$; it serves as a reference for the faster `fft`.
+/// 
+/// ## Inputs
+/// - `data`: the points to transform ($x_n$)
+/// 
+/// Returns the transform, of the same length as the input.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::signal::dft;
+/// // A single nonzero bin gives a complex sinusoid
+/// let mut s: Vec<Complex> = vec![Complex::ZERO; 8];
+/// s[2] = Complex::ONE;
+/// let res = dft(&s);
+/// 
+/// for (k, c) in res.iter().enumerate() {
+///     let expected = Complex::from_polar(-std::f64::consts::TAU * (2 * k) as f64 / 8.0, 1.0);
+///     assert!((*c - expected).modulus() < 1.0e-15);
+/// }
+/// 
+/// // Parseval's theorem: the energy is conserved up to a factor N
+/// let x: Vec<Complex> = vec![Complex::from(1.0, 2.0), Complex::from(-0.5, 0.0), Complex::from(3.0, -1.0), Complex::from(0.25, 4.0)];
+/// let energy: f64 = x.iter().map(|c| c.modulus().powi(2)).sum();
+/// let energy_f: f64 = dft(&x).iter().map(|c| c.modulus().powi(2)).sum();
+/// assert!((energy - energy_f / 4.0).abs() < 1.0e-13);
+/// ```
+pub fn dft<T>(data: &[T]) -> Vec<Complex>
+where T: Into<Complex> + Copy {
+    direct_transform(data, -1.0)
+}
+
+/// # Inverse discrete Fourier transform
+/// 
+/// ## Definition
+/// The [inverse discrete Fourier transform](https://en.wikipedia.org/wiki/Discrete_Fourier_transform#Inverse_transform)
+/// of $N$ points is:
+/// $$
+/// x_n = \frac{1}{N}\sum_{k=0}^{N-1}X_k\exp\left( \frac{2i\pi kn}{N} \right)
+/// $$
+/// 
+/// The sum is computed directly, in $O(N^2)$.
+/// 
+/// ## Inputs
+/// - `data`: the points to transform ($X_k$)
+/// 
+/// Returns the inverse transform, of the same length as the input; `idft(dft(x))` gives back `x`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::signal::{ dft, idft };
+/// let x: Vec<Complex> = (0..13).map(|n| Complex::from(n as f64 * 0.3, (n as f64).sin())).collect();
+/// let res = idft(&dft(&x));
+/// 
+/// for (ori, comp) in x.iter().zip(&res) {
+///     assert!((*ori - *comp).modulus() < 1.0e-13);
+/// }
+/// ```
+pub fn idft<T>(data: &[T]) -> Vec<Complex>
+where T: Into<Complex> + Copy {
+    let norm: f64 = data.len() as f64;
+    direct_transform(data, 1.0).into_iter().map(|c| c / norm).collect()
+}
+
+/// Computes the unnormalized direct Fourier sum, with the sign of the exponent given by `sign`.
+fn direct_transform<T>(data: &[T], sign: f64) -> Vec<Complex>
+where T: Into<Complex> + Copy {
+
+    let length: usize = data.len();
+    let mut res: Vec<Complex> = Vec::with_capacity(length);
+
+    for k in 0..length {
+        let mut sum_k: Complex = Complex::default();
+        for (n, val) in data.iter().enumerate() {
+            // Reducing k * n modulo N keeps the angle small
+            let angle: f64 = sign * TAU * ((k * n) % length) as f64 / length as f64;
+            sum_k += Complex::from_polar(angle, 1.0) * (*val).into();
+        }
+        res.push(sum_k);
+    }
+
+    res
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// # Fast Fourier transform
 /// 
-/// Computes the FFT for a one-dimensional array, based on the discrete fourier transform.
-/// This function accepts complex input. The FFT is computed using
-/// [Bluestein's algorithm](https://en.wikipedia.org/wiki/Chirp_Z-transform#Bluestein.27s_algorithm).
+/// Computes the FFT for a one-dimensional array, giving the same result as `dft`.
+/// This function accepts complex input.
+/// 
+/// When the length is a power of two, the FFT is computed in place with the iterative radix-2
+/// [Cooley-Tukey algorithm](https://en.wikipedia.org/wiki/Cooley%E2%80%93Tukey_FFT_algorithm), in $O(N\log N)$.
+/// Other lengths are not zero-padded: the transform is rewritten as a convolution with
+/// [Bluestein's algorithm](https://en.wikipedia.org/wiki/Chirp_Z-transform#Bluestein.27s_algorithm),
+/// itself computed with radix-2 FFTs, which keeps the $O(N\log N)$ cost.
+/// 
+/// For 1024 points, this is over a hundred times faster than the direct `dft`.
 /// 
 /// ```
 /// # use scilib::range;
@@ -141,42 +294,40 @@ where T: std::ops::Mul<Output = T> + std::ops::AddAssign + Default + Copy {
 /// assert!((res[9].re - -0.02709553).abs() < 1.0e-8 && (res[9].im - 1.02037473).abs() < 1.0e-8);
 /// assert!((res[14].re - 4.77371673).abs() < 1.0e-8 && (res[14].im - -2.58964065).abs() < 1.0e-8);
 /// ```
+/// 
+/// It agrees with the direct transform, for power of two lengths or not:
+/// ```
+/// # use scilib::math::complex::Complex;
+/// # use scilib::signal::{ dft, fft };
+/// for len in [1024, 100] {
+///     let s: Vec<Complex> = (0..len).map(|n| Complex::from((0.1 * n as f64).sin(), (0.37 * n as f64).cos())).collect();
+///     let fast = fft(&s);
+///     let direct = dft(&s);
+/// 
+///     assert_eq!(fast.len(), len);
+///     for (f, d) in fast.iter().zip(&direct) {
+///         assert!((*f - *d).modulus() < 1.0e-10);
+///     }
+/// }
+/// ```
 pub fn fft<T>(data: &[T]) -> Vec<Complex>
 where T: Into<Complex> + Copy {
 
-    let length: isize = data.len() as isize;
-
-    // The pre-factor in the exponential terms
-    let ipi_n: Complex = Complex::i() * PI / length as f64;
-
-    let mut res: Vec<Complex> = Vec::with_capacity(data.len());
-
-    let mut an: Complex;
-    let mut bkn: Complex;
-    let mut bk_star: Complex;
-    let mut sum_k: Complex;
-
-    for k in 0..length {
-        sum_k = Complex::default();
-        bk_star = (k.pow(2) as f64 * ipi_n).exp().conjugate();
+    let mut res: Vec<Complex> = data.iter().map(|val| (*val).into()).collect();
 
-        for (n, val) in data.iter().enumerate() {
-            an = (- (n.pow(2) as f64) * ipi_n).exp() * (*val).into();
-            bkn = ((k - n as isize).pow(2) as f64 * ipi_n).exp();
-            sum_k += an * bkn;
-        }
-
-        res.push(bk_star * sum_k);
+    if res.is_empty() || res.len().is_power_of_two() {
+        radix_2(&mut res);
+        res
+    } else {
+        bluestein(&res)
     }
-
-    res
 }
 
 /// # Inverse fast Fourier transform
 /// 
-/// Computes the IFFT for a one-dimensional array, based on the discrete fourier transform.
-/// This function accepts complex input. The FFT is computed using
-/// [Bluestein's algorithm](https://en.wikipedia.org/wiki/Chirp_Z-transform#Bluestein.27s_algorithm).
+/// Computes the IFFT for a one-dimensional array, giving the same result as `idft`.
+/// This function accepts complex input. It relies on `fft`, through
+/// $\mathrm{ifft}(X) = \overline{\mathrm{fft}(\overline{X})}/N$.
 /// 
 /// This function yields `v = ifft(fft(v))`, within numerical errors.
 /// 
@@ -192,37 +343,89 @@ where T: Into<Complex> + Copy {
 /// for (ori, comp) in s.iter().zip(&res) {
 ///     assert!((ori - comp.re).abs() < 1.0e-14 && comp.im < 1.0e-14);
 /// }
+/// 
+/// // Round trip on a power of two length
+/// let s: Vec<Complex> = (0..256).map(|n| Complex::from(n as f64 / 256.0, (n as f64).cos())).collect();
+/// let res = ifft(&fft(&s));
+/// 
+/// for (ori, comp) in s.iter().zip(&res) {
+///     assert!((*ori - *comp).modulus() < 1.0e-14);
+/// }
 /// ```
 pub fn ifft<T>(data: &[T]) -> Vec<Complex>
 where T: Into<Complex> + Copy {
 
-    let length: isize = data.len() as isize;
-    let norm: f64 = length as f64;
+    let norm: f64 = data.len() as f64;
+    let conj: Vec<Complex> = data.iter().map(|val| (*val).into().conjugate()).collect();
 
-    // The pre-factor in the exponential terms
-    let ipi_n: Complex = -Complex::i() * PI / length as f64;
+    fft(&conj).into_iter().map(|c| c.conjugate() / norm).collect()
+}
 
-    let mut res: Vec<Complex> = Vec::with_capacity(data.len());
+/// Computes the FFT in place with the iterative radix-2 algorithm; the length must be a power of two.
+fn radix_2(data: &mut [Complex]) {
 
-    let mut an: Complex;
-    let mut bkn: Complex;
-    let mut bk_star: Complex;
-    let mut sum_k: Complex;
+    let length: usize = data.len();
 
-    for k in 0..length {
-        sum_k = Complex::default();
-        bk_star = (k.pow(2) as f64 * ipi_n).exp().conjugate();
+    // Bit-reversal permutation
+    let mut j: usize = 0;
+    for i in 1..length {
+        let mut bit: usize = length >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            data.swap(i, j);
+        }
+    }
 
-        for (n, val) in data.iter().enumerate() {
-            an = (- (n.pow(2) as f64) * ipi_n).exp() * (*val).into();
-            bkn = ((k - n as isize).pow(2) as f64 * ipi_n).exp();
-            sum_k += an * bkn;
+    // Butterflies, doubling the size of the sub-transforms
+    let mut size: usize = 2;
+    while size <= length {
+        let half: usize = size / 2;
+        let twiddles: Vec<Complex> = (0..half).map(|k| Complex::from_polar(-TAU * k as f64 / size as f64, 1.0)).collect();
+
+        for start in (0..length).step_by(size) {
+            for (k, w) in twiddles.iter().enumerate() {
+                let even: Complex = data[start + k];
+                let odd: Complex = data[start + k + half] * *w;
+                data[start + k] = even + odd;
+                data[start + k + half] = even - odd;
+            }
         }
+        size *= 2;
+    }
+}
 
-        res.push(bk_star * sum_k / norm);
+/// Computes the FFT of any length as a convolution, using Bluestein's algorithm.
+fn bluestein(data: &[Complex]) -> Vec<Complex> {
+
+    let length: usize = data.len();
+    let padded: usize = (2 * length - 1).next_power_of_two();
+
+    // Chirp exp(-i pi n^2 / N), with n^2 reduced modulo 2N to keep the angle small
+    let chirp: Vec<Complex> = (0..length)
+        .map(|n| Complex::from_polar(-PI * ((n * n) % (2 * length)) as f64 / length as f64, 1.0))
+        .collect();
+
+    let mut a: Vec<Complex> = vec![Complex::default(); padded];
+    let mut b: Vec<Complex> = vec![Complex::default(); padded];
+    for n in 0..length {
+        a[n] = data[n] * chirp[n];
+        b[n] = chirp[n].conjugate();
+        if n > 0 {
+            b[padded - n] = chirp[n].conjugate();
+        }
     }
 
-    res
+    // Circular convolution of a and b
+    radix_2(&mut a);
+    radix_2(&mut b);
+    let mut conv: Vec<Complex> = a.iter().zip(&b).map(|(x, y)| (*x * *y).conjugate()).collect();
+    radix_2(&mut conv);
+
+    (0..length).map(|k| chirp[k] * conv[k].conjugate() / padded as f64).collect()
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////